@@ -1,7 +1,7 @@
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use strum::EnumIter;
 
-#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, EnumIter, Serialize)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, EnumIter, Deserialize, Serialize)]
 pub enum HintGhost {
     // Hyrule Overworld Hint Ghosts (36) -----------------------------------------------------------
     LostWoodsMaze1,
@@ -2,7 +2,7 @@
  * Shuts down the program in a controlled fashion:
  * - Displays an error message (optional)
  * - Pauses execution of the CLI
- * - Terminates with exit code 1.
+ * - Terminates with exit code 1, or the `exit_code` provided.
  */
 #[macro_export]
 macro_rules! fail {
@@ -11,6 +11,11 @@ macro_rules! fail {
         cli::pause();
         std::process::exit(1);
     });
+    (exit_code: $code:expr, $($arg:tt)+) => ({
+        log::error!($($arg)+);
+        cli::pause();
+        std::process::exit($code);
+    });
     ($($arg:tt)+) => ({
         log::error!($($arg)+);
         cli::pause();
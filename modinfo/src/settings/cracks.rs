@@ -1,3 +1,4 @@
+use crate::settings::metadata::{Choice, Choices};
 use serde::{Deserialize, Serialize};
 use std::fmt::{Display, Formatter};
 
@@ -23,6 +24,23 @@ impl TryFrom<u8> for Cracks {
     }
 }
 
+impl Choices for Cracks {
+    const CHOICES: &'static [Choice] = &[
+        Choice {
+            value: "Closed",
+            index: 0,
+            title: "Closed",
+            description: "All cracks except the Hyrule Castle crack (and its pair) remain closed until Quake is found.",
+        },
+        Choice {
+            value: "Open",
+            index: 1,
+            title: "Open",
+            description: "All cracks are open from the start of the game, and Quake is not in the item pool.",
+        },
+    ];
+}
+
 impl Display for Cracks {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
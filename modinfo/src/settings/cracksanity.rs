@@ -1,3 +1,4 @@
+use crate::settings::metadata::{Choice, Choices};
 use serde::{Deserialize, Serialize};
 use std::fmt::{Display, Formatter};
 
@@ -32,6 +33,36 @@ impl TryFrom<u8> for Cracksanity {
     }
 }
 
+impl Choices for Cracksanity {
+    const CHOICES: &'static [Choice] = &[
+        Choice { value: "Off", index: 0, title: "Off", description: "Cracks are not shuffled." },
+        Choice {
+            value: "CrossWorldPairs",
+            index: 1,
+            title: "Cross World Pairs",
+            description: "Cracks are shuffled, but remain in Hyrule/Lorule pairs.",
+        },
+        Choice {
+            value: "AnyWorldPairs",
+            index: 2,
+            title: "Any World Pairs",
+            description: "Cracks are shuffled freely, and can lead to the same or opposite world.",
+        },
+        Choice {
+            value: "MirroredCrossWorldPairs",
+            index: 3,
+            title: "Mirrored Cross World Pairs",
+            description: "Same as Cross World Pairs, but each pair's vanilla counterparts will be in a matching pair.",
+        },
+        Choice {
+            value: "MirroredAnyWorldPairs",
+            index: 4,
+            title: "Mirrored Any World Pairs",
+            description: "Same as Any World Pairs, but each pair's vanilla counterparts will be in a matching pair.",
+        },
+    ];
+}
+
 impl Display for Cracksanity {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
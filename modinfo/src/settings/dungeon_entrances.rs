@@ -0,0 +1,61 @@
+use crate::settings::metadata::{Choice, Choices};
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+/// Dungeon Entrances
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq, Deserialize, Serialize)]
+pub enum DungeonEntrances {
+    /// Dungeon entrances are not shuffled
+    #[default]
+    Off,
+    /// Dungeon entrances are shuffled, but Hyrule dungeons stay in Hyrule and Lorule dungeons stay in Lorule
+    SameWorld,
+    /// Dungeon entrances are shuffled freely, and can lead to dungeons of either world
+    AnyWorld,
+}
+
+impl TryFrom<u8> for DungeonEntrances {
+    type Error = String;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::Off),
+            1 => Ok(Self::SameWorld),
+            2 => Ok(Self::AnyWorld),
+            _ => Err(format!("Invalid DungeonEntrances index: {}", value)),
+        }
+    }
+}
+
+impl Choices for DungeonEntrances {
+    const CHOICES: &'static [Choice] = &[
+        Choice { value: "Off", index: 0, title: "Off", description: "Dungeon entrances are not shuffled." },
+        Choice {
+            value: "SameWorld",
+            index: 1,
+            title: "Same World",
+            description: "Dungeon entrances are shuffled, but Hyrule dungeons stay in Hyrule and Lorule dungeons stay \
+            in Lorule.",
+        },
+        Choice {
+            value: "AnyWorld",
+            index: 2,
+            title: "Any World",
+            description: "Dungeon entrances are shuffled freely, and can lead to dungeons of either world.",
+        },
+    ];
+}
+
+impl Display for DungeonEntrances {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Off => "Off",
+                Self::SameWorld => "Same World",
+                Self::AnyWorld => "Any World",
+            }
+        )
+    }
+}
@@ -0,0 +1,88 @@
+use crate::settings::metadata::{Choice, Choices};
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+/// Where a category of dungeon items (Small Keys or Big Keys) may be placed. Compasses always stay in their own
+/// dungeon, as the game credits one to the dungeon it is found in.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq, Deserialize, Serialize)]
+pub enum DungeonItems {
+    /// Dungeon items are placed in their own dungeon.
+    #[default]
+    OwnDungeon,
+    /// Dungeon items are placed in any dungeon.
+    AnyDungeon,
+    /// Dungeon items are placed anywhere in the same world (Hyrule or Lorule) as their own dungeon.
+    OwnWorld,
+    /// Dungeon items are placed outside of dungeons.
+    Overworld,
+    /// Dungeon items are placed anywhere.
+    Anywhere,
+}
+
+impl DungeonItems {
+    /// Whether dungeon items may be placed outside their own dungeon.
+    pub fn is_shuffled(self) -> bool {
+        self != Self::OwnDungeon
+    }
+}
+
+impl TryFrom<u8> for DungeonItems {
+    type Error = String;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::OwnDungeon),
+            1 => Ok(Self::AnyDungeon),
+            2 => Ok(Self::OwnWorld),
+            3 => Ok(Self::Overworld),
+            4 => Ok(Self::Anywhere),
+            _ => Err(format!("Invalid Dungeon Items Setting: {}", value)),
+        }
+    }
+}
+
+impl Choices for DungeonItems {
+    const CHOICES: &'static [Choice] = &[
+        Choice {
+            value: "OwnDungeon",
+            index: 0,
+            title: "Own Dungeon",
+            description: "Dungeon items are placed in their own dungeon.",
+        },
+        Choice {
+            value: "AnyDungeon",
+            index: 1,
+            title: "Any Dungeon",
+            description: "Dungeon items are placed in any dungeon.",
+        },
+        Choice {
+            value: "OwnWorld",
+            index: 2,
+            title: "Own World",
+            description: "Dungeon items are placed anywhere in the same world (Hyrule or Lorule) as their own dungeon.",
+        },
+        Choice {
+            value: "Overworld",
+            index: 3,
+            title: "Overworld",
+            description: "Dungeon items are placed outside of dungeons.",
+        },
+        Choice { value: "Anywhere", index: 4, title: "Anywhere", description: "Dungeon items are placed anywhere." },
+    ];
+}
+
+impl Display for DungeonItems {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::OwnDungeon => "Own Dungeon",
+                Self::AnyDungeon => "Any Dungeon",
+                Self::OwnWorld => "Own World",
+                Self::Overworld => "Overworld",
+                Self::Anywhere => "Anywhere",
+            }
+        )
+    }
+}
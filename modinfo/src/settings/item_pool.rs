@@ -0,0 +1,70 @@
+use crate::settings::metadata::{Choice, Choices};
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+/// How many copies of items the item pool holds. Items left out of the pool are replaced with junk.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq, Deserialize, Serialize)]
+pub enum ItemPool {
+    /// Adds a second copy of each non-progressive major item.
+    Plentiful,
+    /// The standard item pool.
+    #[default]
+    Balanced,
+    /// Leaves out most Heart Containers and half of the Bottles.
+    Scarce,
+    /// Keeps only the copies logic requires: a single Bottle and just enough hearts.
+    Minimal,
+}
+
+impl TryFrom<u8> for ItemPool {
+    type Error = String;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::Plentiful),
+            1 => Ok(Self::Balanced),
+            2 => Ok(Self::Scarce),
+            3 => Ok(Self::Minimal),
+            _ => Err(format!("Invalid Item Pool Setting: {}", value)),
+        }
+    }
+}
+
+impl Choices for ItemPool {
+    const CHOICES: &'static [Choice] = &[
+        Choice {
+            value: "Plentiful",
+            index: 0,
+            title: "Plentiful",
+            description: "Adds a second copy of each non-progressive major item.",
+        },
+        Choice { value: "Balanced", index: 1, title: "Balanced", description: "The standard item pool." },
+        Choice {
+            value: "Scarce",
+            index: 2,
+            title: "Scarce",
+            description: "Leaves out most Heart Containers and half of the Bottles.",
+        },
+        Choice {
+            value: "Minimal",
+            index: 3,
+            title: "Minimal",
+            description: "Keeps only the copies logic requires: a single Bottle and just enough hearts.",
+        },
+    ];
+}
+
+impl Display for ItemPool {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Plentiful => "Plentiful",
+                Self::Balanced => "Balanced",
+                Self::Scarce => "Scarce",
+                Self::Minimal => "Minimal",
+            }
+        )
+    }
+}
@@ -0,0 +1,60 @@
+use crate::settings::metadata::{Choice, Choices};
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+/// Items that may be drawn as junk, weighted by [`junk_weights`].
+///
+/// [`junk_weights`]: crate::Settings::junk_weights
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Deserialize, Serialize)]
+pub enum JunkItem {
+    RupeeGreen,
+    RupeeBlue,
+    RupeeRed,
+    MonsterTail,
+    MonsterHorn,
+    MonsterGuts,
+}
+
+impl TryFrom<u8> for JunkItem {
+    type Error = String;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::RupeeGreen),
+            1 => Ok(Self::RupeeBlue),
+            2 => Ok(Self::RupeeRed),
+            3 => Ok(Self::MonsterTail),
+            4 => Ok(Self::MonsterHorn),
+            5 => Ok(Self::MonsterGuts),
+            _ => Err(format!("Invalid Junk Item: {}", value)),
+        }
+    }
+}
+
+impl Choices for JunkItem {
+    const CHOICES: &'static [Choice] = &[
+        Choice { value: "RupeeGreen", index: 0, title: "Green Rupee", description: "Worth 1 Rupee." },
+        Choice { value: "RupeeBlue", index: 1, title: "Blue Rupee", description: "Worth 5 Rupees." },
+        Choice { value: "RupeeRed", index: 2, title: "Red Rupee", description: "Worth 20 Rupees." },
+        Choice { value: "MonsterTail", index: 3, title: "Monster Tail", description: "A potion ingredient." },
+        Choice { value: "MonsterHorn", index: 4, title: "Monster Horn", description: "A potion ingredient." },
+        Choice { value: "MonsterGuts", index: 5, title: "Monster Guts", description: "A potion ingredient." },
+    ];
+}
+
+impl Display for JunkItem {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::RupeeGreen => "Green Rupee",
+                Self::RupeeBlue => "Blue Rupee",
+                Self::RupeeRed => "Red Rupee",
+                Self::MonsterTail => "Monster Tail",
+                Self::MonsterHorn => "Monster Horn",
+                Self::MonsterGuts => "Monster Guts",
+            }
+        )
+    }
+}
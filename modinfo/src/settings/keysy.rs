@@ -1,3 +1,4 @@
+use crate::settings::metadata::{Choice, Choices};
 use serde::{Deserialize, Serialize};
 use std::fmt::{Display, Formatter};
 
@@ -29,6 +30,30 @@ impl TryFrom<u8> for Keysy {
     }
 }
 
+impl Choices for Keysy {
+    const CHOICES: &'static [Choice] = &[
+        Choice { value: "Off", index: 0, title: "Off", description: "Key doors remain as they are in vanilla." },
+        Choice {
+            value: "SmallKeysy",
+            index: 1,
+            title: "Small Keysy",
+            description: "Small Keys and locked doors are removed from all dungeons.",
+        },
+        Choice {
+            value: "BigKeysy",
+            index: 2,
+            title: "Big Keysy",
+            description: "Big Keys and huge doors are removed from all dungeons.",
+        },
+        Choice {
+            value: "AllKeysy",
+            index: 3,
+            title: "All Keysy",
+            description: "All Keys and their doors are removed from all dungeons.",
+        },
+    ];
+}
+
 impl Display for Keysy {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
@@ -1,6 +1,8 @@
+use crate::settings::metadata::{Choice, Choices};
 use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq, Deserialize, Serialize)]
+/// Logic tiers are ordered, each one including the tricks of every lower tier.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Deserialize, Serialize)]
 pub enum LogicMode {
     #[default]
     Normal,
@@ -10,3 +12,60 @@ pub enum LogicMode {
     Hell,
     NoLogic,
 }
+
+impl TryFrom<u8> for LogicMode {
+    type Error = String;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::Normal),
+            1 => Ok(Self::Hard),
+            2 => Ok(Self::Glitched),
+            3 => Ok(Self::AdvGlitched),
+            4 => Ok(Self::Hell),
+            5 => Ok(Self::NoLogic),
+            _ => Err(format!("Invalid Logic Mode: {}", value)),
+        }
+    }
+}
+
+impl Choices for LogicMode {
+    const CHOICES: &'static [Choice] = &[
+        Choice {
+            value: "Normal",
+            index: 0,
+            title: "Normal",
+            description: "Standard gameplay, no tricky item use or glitches. If unsure, choose this.",
+        },
+        Choice {
+            value: "Hard",
+            index: 1,
+            title: "Hard",
+            description: "Adds tricks that aren't technically glitches. Lamp + Net considered as weapons. No glitches.",
+        },
+        Choice {
+            value: "Glitched",
+            index: 2,
+            title: "Glitched",
+            description: "Includes the above plus a selection of easy-to-learn glitches.",
+        },
+        Choice {
+            value: "AdvGlitched",
+            index: 3,
+            title: "Adv. Glitched",
+            description: "Includes the above plus \"advanced\" glitches that may be a challenge to master.",
+        },
+        Choice {
+            value: "Hell",
+            index: 4,
+            title: "Hell",
+            description: "Includes every known RTA-viable glitch, including the insane ones. Don't choose this.",
+        },
+        Choice {
+            value: "NoLogic",
+            index: 5,
+            title: "No Logic",
+            description: "Items are placed with no logic at all. Seeds are likely to not be completable.",
+        },
+    ];
+}
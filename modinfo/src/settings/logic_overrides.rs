@@ -0,0 +1,46 @@
+use serde::{Deserialize, Deserializer, Serialize};
+use std::collections::BTreeMap;
+
+/// Changes to the randomizer's logic files, applied on top of them when building the world graph.
+///
+/// Expressions use the logic file syntax, e.g. `"can_merge && (has_bow || has_eastern_keys(2))"`.
+#[derive(Clone, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct LogicOverrides {
+    /// Named helpers to add or redefine, keyed by helper name.
+    pub helpers: BTreeMap<String, String>,
+
+    /// Rules of checks, keyed by check name. Applies to every check of that name.
+    pub checks: BTreeMap<String, RuleOverride>,
+
+    /// Rules of edges, keyed by `"From -> To"` using node names, e.g. `"EasternPalace1F -> EasternPalaceMiniboss"`.
+    /// Crack edges are keyed by the crack instead, e.g. `"ZeldasStudy -> crack_left HyruleCastle"`.
+    pub edges: BTreeMap<String, RuleOverride>,
+}
+
+impl LogicOverrides {
+    pub fn is_empty(&self) -> bool {
+        self.helpers.is_empty() && self.checks.is_empty() && self.edges.is_empty()
+    }
+}
+
+/// New requirements for a single rule. Requirements left out are unchanged, and requirements set to `null` are removed
+/// so they no longer grant access. A trick the rule doesn't use yet is added to it.
+#[derive(Clone, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct RuleOverride {
+    #[serde(deserialize_with = "present", skip_serializing_if = "Option::is_none")]
+    pub normal: Option<Option<String>>,
+
+    /// Requirements of the rule's tricks, keyed by trick name.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub tricks: BTreeMap<String, Option<String>>,
+}
+
+/// Distinguishes a requirement set to `null` from one that was left out, which `#[serde(default)]` turns into `None`.
+fn present<'de, D>(deserializer: D) -> Result<Option<Option<String>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Option::deserialize(deserializer).map(Some)
+}
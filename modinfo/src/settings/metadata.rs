@@ -0,0 +1,557 @@
+//! Machine-readable descriptions of every [`Settings`] field: types, allowed values, defaults, ranges, help text and
+//! incompatibilities.
+//!
+//! Front-ends should build their options from [`SETTINGS`] (or the [`json_schema`] generated from it) rather than
+//! copying descriptions, and the interactive CLI prompts are generated from the same data.
+
+use crate::settings::{
+    Cracks, Cracksanity, DungeonEntrances, DungeonItems, ItemPool, JunkItem, Keysy, LogicMode, NiceItems,
+    PedestalSetting, RaviosShop, Settings, StartingItem, TrialsDoor, WeatherVanes,
+};
+use serde_json::{json, Map, Value};
+
+/// Describes a single [`Settings`] field.
+#[derive(Clone, Copy, Debug)]
+pub struct SettingInfo {
+    /// Name of the field, as used in presets.
+    pub name: &'static str,
+    /// Human-readable name.
+    pub title: &'static str,
+    /// Help text, which may span multiple lines.
+    pub description: &'static str,
+    pub kind: Kind,
+    /// Other boolean settings that cannot be enabled at the same time as this one.
+    pub incompatible_with: &'static [&'static str],
+    /// Whether the setting can be set from a preset.
+    pub configurable: bool,
+    /// Whether the interactive CLI asks for this setting.
+    pub prompt: bool,
+}
+
+/// The type and allowed values of a setting.
+#[derive(Clone, Copy, Debug)]
+pub enum Kind {
+    Bool,
+    /// An integer within an inclusive range.
+    Number {
+        min: u64,
+        max: u64,
+    },
+    /// One of the variants of a setting enum.
+    Choice(&'static [Choice]),
+    /// A count for some of the variants of a setting enum.
+    Counts(&'static [Choice]),
+    /// A set of check names. Some settings also accept region names.
+    CheckNames,
+    /// Forced item placements, see [`Plando`](crate::settings::Plando).
+    Plando,
+    /// A relative weight for some of the variants of a setting enum.
+    Weights(&'static [Choice]),
+    /// Changes to the logic files, see [`LogicOverrides`](crate::settings::LogicOverrides).
+    LogicOverrides,
+    /// A set of trick names from the randomizer's logic files.
+    TrickNames,
+}
+
+/// Describes a single variant of a setting enum.
+#[derive(Clone, Copy, Debug)]
+pub struct Choice {
+    /// Name of the variant, as used in presets.
+    pub value: &'static str,
+    /// The variant's `TryFrom<u8>` index, also used to select it in the interactive CLI.
+    pub index: u8,
+    /// Human-readable name.
+    pub title: &'static str,
+    pub description: &'static str,
+}
+
+/// A setting enum with described variants.
+pub trait Choices {
+    /// Every variant, in `TryFrom<u8>` order.
+    const CHOICES: &'static [Choice];
+}
+
+impl SettingInfo {
+    /// The value used when the setting is omitted from a preset.
+    pub fn default_value(&self) -> Value {
+        Settings::default().to_json_map().remove(self.name).expect("Metadata for unknown setting")
+    }
+
+    /// Looks up a setting by its preset name.
+    pub fn get(name: &str) -> Option<&'static Self> {
+        SETTINGS.iter().find(|setting| setting.name == name)
+    }
+}
+
+/// Every [`Settings`] field, in the order the interactive CLI presents them.
+pub const SETTINGS: &[SettingInfo] = &[
+    setting("logic_mode", "Logic Mode", "Logic to use for item placement.", Kind::Choice(LogicMode::CHOICES)),
+    setting(
+        "dungeon_prize_shuffle",
+        "Randomize Dungeon Prizes",
+        "This shuffles all Sage Portraits, Pendants, and the Charm among themselves.",
+        Kind::Bool,
+    ),
+    setting(
+        "maiamai_limit",
+        "Maiamai Limit",
+        "The maximum number of Maiamai the seed may require you to collect, assuming you don't waste any on junk.",
+        Kind::Number { min: 0, max: 100 },
+    ),
+    setting(
+        "lc_requirement",
+        "Lorule Castle Requirement",
+        "How many Sage Portraits are needed to enter Lorule Castle.",
+        Kind::Number { min: 0, max: 7 },
+    ),
+    setting(
+        "yuganon_requirement",
+        "Yuga Ganon Requirement",
+        "How many Sage Portraits are needed to enter the Throne Room and fight Yuga Ganon.",
+        Kind::Number { min: 0, max: 7 },
+    ),
+    setting(
+        "ped_requirement",
+        "Pedestal Requirement",
+        "Which Pendants are required to reach the Master Sword Pedestal.",
+        Kind::Choice(PedestalSetting::CHOICES),
+    ),
+    setting(
+        "nice_items",
+        "Nice Items",
+        "How to handle Nice Items and Mother Maiamai's upgrades.",
+        Kind::Choice(NiceItems::CHOICES),
+    ),
+    setting(
+        "super_items",
+        "Shuffle Super Items",
+        "This shuffles a second progressive copy of the Lamp and Net into the general item pool.",
+        Kind::Bool,
+    ),
+    setting(
+        "item_pool",
+        "Item Pool",
+        "How many copies of items the item pool holds. Items left out of the pool are replaced with junk.",
+        Kind::Choice(ItemPool::CHOICES),
+    ),
+    setting(
+        "lamp_and_net_as_weapons",
+        "Lamp & Net as Weapons (advanced)",
+        "Treat the base Lamp and Net as damage-dealing weapons.\n\
+        - The red base Lamp and Net each deal 1/2 the damage of the Forgotten Sword (i.e. they're VERY BAD weapons).\n\
+        - The blue Super Lamp and Super Net each deal 4 damage (same as MS Lv3) and are always considered weapons, regardless of this setting.",
+        Kind::Bool,
+    ),
+    setting(
+        "no_progression_enemies",
+        "No Progression Enemies",
+        "Removes Enemies from dungeons that are themselves Progression (e.g.: Bawbs, the bomb enemy).\n\
+        Logic will be adjusted to require the player's items instead.",
+        Kind::Bool,
+    ),
+    setting(
+        "start_with_merge",
+        "Start with Merge",
+        "Start with the ability to Merge into walls, without Ravio's Bracelet.",
+        Kind::Bool,
+    ),
+    setting("start_with_pouch", "Start with Pouch", "Start with the Pouch and a usable X Button.", Kind::Bool),
+    setting("bell_in_shop", "Bell in Shop", "The Bell will be placed in Ravio's Shop.", Kind::Bool),
+    SettingInfo {
+        incompatible_with: &["swordless_mode", "assured_weapon"],
+        ..setting("sword_in_shop", "Sword in Shop", "At least one Sword will be placed in Ravio's Shop.", Kind::Bool)
+    },
+    SettingInfo {
+        incompatible_with: &["assured_weapon"],
+        ..setting("boots_in_shop", "Boots in Shop", "The Pegasus Boots will be placed in Ravio's Shop.", Kind::Bool)
+    },
+    SettingInfo {
+        incompatible_with: &["sword_in_shop", "boots_in_shop"],
+        ..setting(
+            "assured_weapon",
+            "Assured Weapon in Shop",
+            "At least one weapon is guaranteed to be placed in Ravio's Shop.",
+            Kind::Bool,
+        )
+    },
+    setting(
+        "maiamai_madness",
+        "Maiamai Madness",
+        "This shuffles Maiamai into the pool, adding 100 more locations.",
+        Kind::Bool,
+    ),
+    setting("cracks", "Cracks", "The initial state of the cracks between worlds.", Kind::Choice(Cracks::CHOICES)),
+    setting("cracksanity", "Cracksanity", "How to shuffle cracks.", Kind::Choice(Cracksanity::CHOICES)),
+    setting(
+        "dungeon_entrances",
+        "Dungeon Entrances",
+        "Which dungeon each dungeon entrance leads to.",
+        Kind::Choice(DungeonEntrances::CHOICES),
+    ),
+    setting(
+        "minigames_excluded",
+        "Exclude Minigames",
+        "Excludes the following: Octoball Derby, Dodge the Cuccos, Hyrule Hotfoot, Treacherous Tower, and both Rupee Rushes",
+        Kind::Bool,
+    ),
+    setting(
+        "skip_big_bomb_flower",
+        "Skip Big Bomb Flower",
+        "Skips the Big Bomb Flower by removing the 5 Big Rocks in Lorule Field.\n\
+        (Does not affect Lorule Castle Bomb Trial)",
+        Kind::Bool,
+    ),
+    setting(
+        "trials_door",
+        "Trial's Door",
+        "The behavior of the Lorule Castle Trial's Door.",
+        Kind::Choice(TrialsDoor::CHOICES),
+    ),
+    SettingInfo {
+        incompatible_with: &["progressive_bow_of_light"],
+        ..setting(
+            "bow_of_light_in_castle",
+            "Bow of Light in Castle",
+            "Limits the Bow of Light's placement to somewhere in Lorule Castle (including possibly Zelda).",
+            Kind::Bool,
+        )
+    },
+    setting(
+        "weather_vanes",
+        "Weather Vanes",
+        "Which Weather Vanes are active at the start of the game. Logic may require using them to progress.",
+        Kind::Choice(WeatherVanes::CHOICES),
+    ),
+    setting(
+        "dark_rooms_lampless",
+        "Dark Room Crossing (advanced)",
+        "The logic may expect players to cross Dark Rooms without the Lamp.\n\
+        Not for beginners and those who like being able to see things.",
+        Kind::Bool,
+    ),
+    SettingInfo {
+        incompatible_with: &["sword_in_shop"],
+        ..setting(
+            "swordless_mode",
+            "Swordless Mode (advanced)",
+            "Removes *ALL* Swords from the game.\n\
+            The Bug Net becomes a required item to play Dead Man's Volley against Yuga Ganon.",
+            Kind::Bool,
+        )
+    },
+    setting(
+        "chest_size_matches_contents",
+        "Chest Size Matches Contents",
+        "All chests containing progression or unique items will become large, and others will be made small.\n\
+        Note: Some large chests will have a reduced hitbox to prevent negative gameplay interference.",
+        Kind::Bool,
+    ),
+    setting(
+        "treacherous_tower_floors",
+        "Treacherous Tower Floors",
+        "How many floors Treacherous Tower should have. Recommended: 5",
+        Kind::Number { min: 2, max: 66 },
+    ),
+    setting(
+        "purple_potion_bottles",
+        "Purple Potion Bottles",
+        "Fills all Empty Bottles with a free Purple Potion.",
+        Kind::Bool,
+    ),
+    setting("keysy", "Keysy", "Removes locked keys and doors from dungeons.", Kind::Choice(Keysy::CHOICES)),
+    setting(
+        "small_key_shuffle",
+        "Small Keys",
+        "Where Small Keys may be placed. Outside Own Dungeon, the first one found opens all of its dungeon's locked \
+        doors.",
+        Kind::Choice(DungeonItems::CHOICES),
+    ),
+    setting(
+        "big_key_shuffle",
+        "Big Keys",
+        "Where Big Keys may be placed.",
+        Kind::Choice(DungeonItems::CHOICES),
+    ),
+    setting(
+        "key_rings",
+        "Key Rings",
+        "Collapses each dungeon's Small Keys into a single Key Ring that opens all of its locked doors.",
+        Kind::Bool,
+    ),
+    SettingInfo {
+        incompatible_with: &["bow_of_light_in_castle"],
+        prompt: false,
+        ..setting(
+            "progressive_bow_of_light",
+            "Progressive Bow of Light",
+            "Replaces the Bow of Light with a third copy of the Bow. Obtaining all 3 Bows will reward the Bow of Light.\n\
+            There will *NOT* be a Bow of Light Hint in Hilda's Study if this is enabled.",
+            Kind::Bool,
+        )
+    },
+    SettingInfo {
+        prompt: false,
+        ..setting(
+            "night_mode",
+            "Night Mode",
+            "Experimental: Change Hyrule to the nighttime color scheme (until visiting Lorule).",
+            Kind::Bool,
+        )
+    },
+    SettingInfo {
+        configurable: false,
+        prompt: false,
+        ..setting(
+            "ravios_shop",
+            "Ravio's Shop",
+            "Whether Ravio's Shop is open at the start of the game. Not currently configurable.",
+            Kind::Choice(RaviosShop::CHOICES),
+        )
+    },
+    SettingInfo {
+        prompt: false,
+        ..setting("dev_mode", "Dev Mode", "Enables features for randomizer development.", Kind::Bool)
+    },
+    SettingInfo {
+        prompt: false,
+        ..setting(
+            "starting_items",
+            "Starting Items",
+            "How many copies of each item to start the game with, e.g. {\"HeartContainer\": 2}. These are removed \
+            from the item pool.",
+            Kind::Counts(StartingItem::CHOICES),
+        )
+    },
+    SettingInfo {
+        prompt: false,
+        ..setting(
+            "user_exclusions",
+            "Excluded Checks",
+            "Checks that are guaranteed to hold Junk items. Consult a spoiler log for a complete list of check names.",
+            Kind::CheckNames,
+        )
+    },
+    SettingInfo {
+        prompt: false,
+        ..setting(
+            "user_priorities",
+            "Priority Checks",
+            "Checks, or whole regions such as \"Eastern Palace\", that are guaranteed to hold Progression items. \
+            Consult a spoiler log for a complete list of check and region names.",
+            Kind::CheckNames,
+        )
+    },
+    SettingInfo {
+        prompt: false,
+        ..setting(
+            "plando",
+            "Plando",
+            "Items forced onto specific checks (\"checks\": check name to item name) or into specific regions \
+            (\"items\": item name to a list of worlds or regions). Everything else is placed by the randomizer.",
+            Kind::Plando,
+        )
+    },
+    SettingInfo {
+        prompt: false,
+        ..setting(
+            "junk_weights",
+            "Junk Weights",
+            "Relative weights junk items are drawn with, e.g. {\"RupeeGreen\": 1} for nothing but Green Rupees. Junk \
+            items missing from the table are never drawn. Leave empty for the standard junk pool.",
+            Kind::Weights(JunkItem::CHOICES),
+        )
+    },
+    SettingInfo {
+        prompt: false,
+        ..setting(
+            "logic_overrides",
+            "Logic Overrides",
+            "Changes to the logic files: named helpers to add or redefine (\"helpers\"), and new normal or trick \
+            requirements of checks (\"checks\", keyed by check name) or edges (\"edges\", keyed by \"From -> To\"). \
+            A requirement set to null is removed.",
+            Kind::LogicOverrides,
+        )
+    },
+    SettingInfo {
+        prompt: false,
+        ..setting(
+            "enabled_tricks",
+            "Enabled Tricks",
+            "Tricks to include in logic on top of those of the Logic Mode, e.g. [\"enemy_clips\"]. Consult the \
+            randomizer's tricks.json for a complete list of trick names.",
+            Kind::TrickNames,
+        )
+    },
+];
+
+const fn setting(name: &'static str, title: &'static str, description: &'static str, kind: Kind) -> SettingInfo {
+    SettingInfo { name, title, description, kind, incompatible_with: &[], configurable: true, prompt: true }
+}
+
+/// Generates a JSON Schema describing the `settings` object of a preset.
+///
+/// Choices are listed with `oneOf` so their titles and descriptions are included, and incompatible settings are listed
+/// under the non-standard `x-incompatible-with` keyword as well as being enforced by `allOf`.
+pub fn json_schema() -> Value {
+    let mut properties = Map::new();
+    let mut incompatibilities = Vec::new();
+
+    for setting in SETTINGS.iter().filter(|setting| setting.configurable) {
+        let mut property = json!({
+            "title": setting.title,
+            "description": setting.description,
+            "default": setting.default_value(),
+        });
+        let object = property.as_object_mut().unwrap();
+
+        match setting.kind {
+            Kind::Bool => {
+                object.insert("type".to_owned(), json!("boolean"));
+            },
+            Kind::Number { min, max } => {
+                object.insert("type".to_owned(), json!("integer"));
+                object.insert("minimum".to_owned(), json!(min));
+                object.insert("maximum".to_owned(), json!(max));
+            },
+            Kind::Choice(choices) => {
+                object.insert("type".to_owned(), json!("string"));
+                object.insert("oneOf".to_owned(), choices_schema(choices));
+            },
+            Kind::Counts(choices) => {
+                let count = json!({ "type": "integer", "minimum": 0, "maximum": u8::MAX });
+                let counts =
+                    choices.iter().map(|choice| (choice.value.to_owned(), count.clone())).collect::<Map<_, _>>();
+                object.insert("type".to_owned(), json!("object"));
+                object.insert("additionalProperties".to_owned(), json!(false));
+                object.insert("properties".to_owned(), Value::Object(counts));
+            },
+            Kind::CheckNames | Kind::TrickNames => {
+                object.insert("type".to_owned(), json!("array"));
+                object.insert("items".to_owned(), json!({ "type": "string" }));
+                object.insert("uniqueItems".to_owned(), json!(true));
+            },
+            Kind::Plando => {
+                object.insert("type".to_owned(), json!("object"));
+                object.insert("additionalProperties".to_owned(), json!(false));
+                object.insert(
+                    "properties".to_owned(),
+                    json!({
+                        "checks": { "type": "object", "additionalProperties": { "type": "string" } },
+                        "items": {
+                            "type": "object",
+                            "additionalProperties": { "type": "array", "items": { "type": "string" }, "uniqueItems": true },
+                        },
+                    }),
+                );
+            },
+            Kind::Weights(choices) => {
+                let weight = json!({ "type": "integer", "minimum": 0 });
+                let weights =
+                    choices.iter().map(|choice| (choice.value.to_owned(), weight.clone())).collect::<Map<_, _>>();
+                object.insert("type".to_owned(), json!("object"));
+                object.insert("additionalProperties".to_owned(), json!(false));
+                object.insert("properties".to_owned(), Value::Object(weights));
+            },
+            Kind::LogicOverrides => {
+                let requirement = json!({ "type": ["string", "null"] });
+                let rule = json!({
+                    "type": "object",
+                    "additionalProperties": false,
+                    "properties": {
+                        "normal": requirement,
+                        "tricks": { "type": "object", "additionalProperties": requirement },
+                    },
+                });
+                object.insert("type".to_owned(), json!("object"));
+                object.insert("additionalProperties".to_owned(), json!(false));
+                object.insert(
+                    "properties".to_owned(),
+                    json!({
+                        "helpers": { "type": "object", "additionalProperties": { "type": "string" } },
+                        "checks": { "type": "object", "additionalProperties": rule },
+                        "edges": { "type": "object", "additionalProperties": rule },
+                    }),
+                );
+            },
+        }
+
+        if !setting.incompatible_with.is_empty() {
+            object.insert("x-incompatible-with".to_owned(), json!(setting.incompatible_with));
+            for other in setting.incompatible_with.iter().filter(|&&other| setting.name < other) {
+                incompatibilities.push(json!({
+                    "not": {
+                        "required": [setting.name, other],
+                        "properties": { setting.name: { "const": true }, *other: { "const": true } },
+                    }
+                }));
+            }
+        }
+
+        properties.insert(setting.name.to_owned(), property);
+    }
+
+    json!({
+        "$schema": "http://json-schema.org/draft-07/schema#",
+        "title": "Settings",
+        "description": "Logic and behavior settings for A Link Between Worlds Randomizer, i.e. the \"settings\" of a preset.",
+        "type": "object",
+        "additionalProperties": false,
+        "properties": properties,
+        "allOf": incompatibilities,
+    })
+}
+
+fn choices_schema(choices: &[Choice]) -> Value {
+    choices
+        .iter()
+        .map(|choice| json!({ "const": choice.value, "title": choice.title, "description": choice.description }))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::de::DeserializeOwned;
+    use std::fmt::Debug;
+
+    #[test]
+    fn describes_every_setting() {
+        let fields = Settings::default().to_json_map();
+        for name in fields.keys() {
+            assert!(SettingInfo::get(name).is_some(), "No metadata for {}", name);
+        }
+        for setting in SETTINGS {
+            assert!(fields.contains_key(setting.name), "Metadata for unknown setting {}", setting.name);
+            for other in setting.incompatible_with {
+                let other = SettingInfo::get(other).unwrap();
+                assert!(other.incompatible_with.contains(&setting.name), "{} is not symmetric", setting.name);
+            }
+        }
+    }
+
+    #[test]
+    fn choices_match_variants() {
+        fn check<T: Choices + DeserializeOwned + TryFrom<u8, Error = String> + PartialEq + Debug>() {
+            for choice in T::CHOICES {
+                let by_name: T = serde_json::from_value(Value::from(choice.value)).unwrap();
+                assert_eq!(T::try_from(choice.index), Ok(by_name));
+            }
+            assert_eq!((0..=u8::MAX).filter(|&index| T::try_from(index).is_ok()).count(), T::CHOICES.len());
+        }
+
+        check::<Cracks>();
+        check::<Cracksanity>();
+        check::<DungeonEntrances>();
+        check::<DungeonItems>();
+        check::<ItemPool>();
+        check::<JunkItem>();
+        check::<Keysy>();
+        check::<LogicMode>();
+        check::<NiceItems>();
+        check::<PedestalSetting>();
+        check::<RaviosShop>();
+        check::<StartingItem>();
+        check::<TrialsDoor>();
+        check::<WeatherVanes>();
+    }
+}
@@ -1,30 +1,50 @@
 pub use crate::settings::cracks::Cracks;
 pub use crate::settings::cracksanity::Cracksanity;
+pub use crate::settings::dungeon_entrances::DungeonEntrances;
+pub use crate::settings::dungeon_items::DungeonItems;
+pub use crate::settings::item_pool::ItemPool;
+pub use crate::settings::junk_items::JunkItem;
 pub use crate::settings::keysy::Keysy;
 pub use crate::settings::logic::LogicMode;
+pub use crate::settings::logic_overrides::LogicOverrides;
 pub use crate::settings::nice_items::NiceItems;
 pub use crate::settings::pedestal::PedestalSetting;
+pub use crate::settings::plando::Plando;
 pub use crate::settings::ravios_shop::RaviosShop;
+pub use crate::settings::starting_items::StartingItem;
 pub use crate::settings::trials_door::TrialsDoor;
 pub use crate::settings::weather_vanes::WeatherVanes;
 use log::info;
 use logic::LogicMode::*;
 use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
 use std::collections::btree_set::BTreeSet;
+use std::collections::BTreeMap;
 use std::hash::Hash;
 
 pub mod cracks;
 pub mod cracksanity;
+pub mod dungeon_entrances;
+pub mod dungeon_items;
+pub mod item_pool;
+pub mod junk_items;
 pub mod keysy;
 pub mod logic;
+pub mod logic_overrides;
+pub mod metadata;
+pub mod mystery;
 pub mod nice_items;
 pub mod pedestal;
+pub mod plando;
 pub mod ravios_shop;
+pub mod settings_string;
+pub mod starting_items;
 pub mod trials_door;
+pub mod validation;
 pub mod weather_vanes;
 
 /// Logic and behavior settings.
-#[derive(Clone, Debug, Default, Deserialize, Hash, Serialize)]
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
 #[serde(default, deny_unknown_fields)]
 pub struct Settings {
     #[serde(skip_serializing_if = "is_false")]
@@ -35,7 +55,7 @@ pub struct Settings {
     pub lc_requirement: u8,
 
     /// The number of Portraits needed to fight Yuga Ganon
-    #[serde(default = "seven", skip_serializing)]
+    #[serde(default = "seven")]
     pub yuganon_requirement: u8,
 
     /// Configure which Pendants are required to reach the Master Sword Pedestal
@@ -46,6 +66,12 @@ pub struct Settings {
     #[serde(default)]
     pub logic_mode: LogicMode,
 
+    /// Tricks to include in logic on top of those of the [`logic_mode`], by their name in the randomizer's logic files.
+    ///
+    /// [`logic_mode`]: Settings::logic_mode
+    #[serde(default)]
+    pub enabled_tricks: BTreeSet<String>,
+
     /// Dark Room Lamp Requirement. If enabled, the player may have to cross dark rooms without Lamp
     #[serde(default)]
     pub dark_rooms_lampless: bool,
@@ -70,6 +96,15 @@ pub struct Settings {
     #[serde(default)]
     pub super_items: bool,
 
+    /// How many copies of items the item pool holds
+    #[serde(default)]
+    pub item_pool: ItemPool,
+
+    /// Relative weights junk is drawn with. Junk items missing from the table are never drawn, and an empty table keeps
+    /// the standard junk pool.
+    #[serde(default)]
+    pub junk_weights: BTreeMap<JunkItem, usize>,
+
     /// Lamp & Net as Weapons
     #[serde(default)]
     pub lamp_and_net_as_weapons: bool,
@@ -81,6 +116,10 @@ pub struct Settings {
     #[serde(default)]
     pub cracksanity: Cracksanity,
 
+    /// Shuffles which dungeon each dungeon entrance leads to
+    #[serde(default)]
+    pub dungeon_entrances: DungeonEntrances,
+
     /// Weather Vanes behavior and activation setting.
     #[serde(default)]
     pub weather_vanes: WeatherVanes,
@@ -102,6 +141,18 @@ pub struct Settings {
     #[serde(default)]
     pub keysy: Keysy,
 
+    /// Where Small Keys may be placed. Ignored if Small Keys are removed by [`keysy`].
+    #[serde(default)]
+    pub small_key_shuffle: DungeonItems,
+
+    /// Where Big Keys may be placed. Ignored if Big Keys are removed by [`keysy`].
+    #[serde(default)]
+    pub big_key_shuffle: DungeonItems,
+
+    /// Collapses each dungeon's Small Keys into a single Key Ring that grants all of them at once.
+    #[serde(default)]
+    pub key_rings: bool,
+
     /// Makes the Bow of Light the third upgrade for the Bow
     #[serde(default, skip_serializing_if = "is_false")]
     pub progressive_bow_of_light: bool,
@@ -119,6 +170,10 @@ pub struct Settings {
     #[serde(default)]
     pub start_with_pouch: bool,
 
+    /// How many copies of each item to start the game with. These are removed from the item pool.
+    #[serde(default)]
+    pub starting_items: BTreeMap<StartingItem, u8>,
+
     /// Places the Bell in Ravio's Shop
     #[serde(default)]
     pub bell_in_shop: bool,
@@ -163,9 +218,111 @@ pub struct Settings {
 
     /// Set of user-provided locations to be excluded from having progression.
     pub user_exclusions: BTreeSet<String>,
+
+    /// Set of user-provided locations or subregions guaranteed to have progression.
+    pub user_priorities: BTreeSet<String>,
+
+    /// Items forced onto specific checks or into specific regions.
+    #[serde(default)]
+    pub plando: Plando,
+
+    /// Changes to the logic files, e.g. to relax or remove the requirements of specific checks and edges.
+    #[serde(default, skip_serializing_if = "LogicOverrides::is_empty")]
+    pub logic_overrides: LogicOverrides,
+}
+
+/// Matches the defaults used when a field is omitted from a preset.
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            dev_mode: false,
+            lc_requirement: seven(),
+            yuganon_requirement: seven(),
+            ped_requirement: Default::default(),
+            logic_mode: Default::default(),
+            enabled_tricks: Default::default(),
+            dark_rooms_lampless: false,
+            dungeon_prize_shuffle: r#true(),
+            maiamai_limit: fifty(),
+            maiamai_madness: false,
+            nice_items: Default::default(),
+            super_items: false,
+            item_pool: Default::default(),
+            junk_weights: Default::default(),
+            lamp_and_net_as_weapons: false,
+            cracks: Default::default(),
+            cracksanity: Default::default(),
+            dungeon_entrances: Default::default(),
+            weather_vanes: Default::default(),
+            ravios_shop: Default::default(),
+            bow_of_light_in_castle: false,
+            no_progression_enemies: false,
+            keysy: Default::default(),
+            small_key_shuffle: Default::default(),
+            big_key_shuffle: Default::default(),
+            key_rings: false,
+            progressive_bow_of_light: false,
+            swordless_mode: false,
+            start_with_merge: false,
+            start_with_pouch: false,
+            starting_items: Default::default(),
+            bell_in_shop: false,
+            sword_in_shop: false,
+            boots_in_shop: false,
+            assured_weapon: false,
+            chest_size_matches_contents: false,
+            minigames_excluded: false,
+            skip_big_bomb_flower: false,
+            trials_door: Default::default(),
+            treacherous_tower_floors: five(),
+            purple_potion_bottles: false,
+            night_mode: false,
+            user_exclusions: Default::default(),
+            user_priorities: Default::default(),
+            plando: Default::default(),
+            logic_overrides: Default::default(),
+        }
+    }
 }
 
 impl Settings {
+    /// Converts the Settings to a JSON object using preset names and values, including fields that are normally skipped
+    /// when serializing.
+    pub(crate) fn to_json_map(&self) -> Map<String, Value> {
+        let mut map = match serde_json::to_value(self).expect("Could not serialize Settings") {
+            Value::Object(map) => map,
+            _ => unreachable!("Settings always serialize to an object"),
+        };
+
+        map.insert("dev_mode".to_owned(), Value::from(self.dev_mode));
+        map.insert(
+            "ravios_shop".to_owned(),
+            serde_json::to_value(self.ravios_shop).expect("Could not serialize Settings"),
+        );
+        map.insert("progressive_bow_of_light".to_owned(), Value::from(self.progressive_bow_of_light));
+        map.insert(
+            "logic_overrides".to_owned(),
+            serde_json::to_value(&self.logic_overrides).expect("Could not serialize Settings"),
+        );
+        map
+    }
+
+    /// How many copies of the given item the player starts the game with, see [`starting_items`].
+    ///
+    /// [`starting_items`]: Settings::starting_items
+    pub fn starting_count(&self, item: StartingItem) -> u8 {
+        self.starting_items.get(&item).copied().unwrap_or_default()
+    }
+
+    /// Whether Small Keys are collapsed into Key Rings, i.e. [`key_rings`] is set and Small Keys aren't removed by
+    /// [`keysy`].
+    ///
+    /// [`key_rings`]: Settings::key_rings
+    /// [`keysy`]: Settings::keysy
+    pub fn has_key_rings(&self) -> bool {
+        self.key_rings && !matches!(self.keysy, Keysy::SmallKeysy | Keysy::AllKeysy)
+    }
+
     pub fn log_settings(&self) {
         info!(
             "Logic Mode:                     {}",
@@ -178,6 +335,10 @@ impl Settings {
                 NoLogic => "No Logic",
             }
         );
+        if !self.enabled_tricks.is_empty() {
+            let enabled_tricks = self.enabled_tricks.iter().cloned().collect::<Vec<_>>().join(", ");
+            info!("Enabled Tricks:                 {}", enabled_tricks);
+        }
         info!(
             "Dungeon Prizes:                 {}",
             if self.dungeon_prize_shuffle { "Randomized" } else { "Not Randomized" }
@@ -187,12 +348,33 @@ impl Settings {
         info!("Pedestal Requirement:           {}", self.ped_requirement);
 
         info!("Super Items:                    {}", if self.super_items { "Shuffled" } else { "Not Shuffled" });
+        info!("Item Pool:                      {}", self.item_pool);
+        if !self.junk_weights.is_empty() {
+            info!(
+                "Junk Weights:                   {}",
+                self.junk_weights
+                    .iter()
+                    .map(|(item, weight)| format!("{} {}", item, weight))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            );
+        }
         info!("Progression-Granting Enemies:   {}", if self.no_progression_enemies { "Removed" } else { "Vanilla" });
 
         info!("Maiamai:                        {}", if self.maiamai_madness { "Madness" } else { "Not Randomized" });
 
         info!("Start with Merge:               {}", if self.start_with_merge { "Yes" } else { "No" });
         info!("Start with Pouch:               {}", if self.start_with_pouch { "Yes" } else { "No" });
+        if !self.starting_items.is_empty() {
+            info!(
+                "Starting Items:                 {}",
+                self.starting_items
+                    .iter()
+                    .map(|(item, count)| format!("{}x {}", count, item))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            );
+        }
         let shop_items = [
             (&self.bell_in_shop, "Bell"),
             (&self.sword_in_shop, "Sword"),
@@ -222,7 +404,11 @@ impl Settings {
             "Chest Size:                     {}",
             if self.chest_size_matches_contents { "Matches Contents" } else { "Normal" }
         );
-        info!("Cracksanity:                   {}", self.cracksanity)
+        info!("Cracksanity:                   {}", self.cracksanity);
+        info!("Dungeon Entrances:              {}", self.dungeon_entrances);
+        info!("Small Keys:                     {}", self.small_key_shuffle);
+        info!("Big Keys:                       {}", self.big_key_shuffle);
+        info!("Key Rings:                      {}", if self.key_rings { "Yes" } else { "No" });
     }
 }
 
@@ -0,0 +1,185 @@
+//! Mystery Settings: [`Settings`] that are themselves rolled at random from a weights file.
+//!
+//! A weights file gives each setting either a fixed value, weighted choices, or an inclusive range of numbers, using
+//! the same names and values as preset files:
+//!
+//! ```json
+//! {
+//!   "settings": {
+//!     "logic_mode":        { "Normal": 3, "Hard": 1 },
+//!     "cracksanity":       { "Off": 1, "CrossWorldPairs": 1, "AnyWorldPairs": 1 },
+//!     "lc_requirement":    { "range": [4, 7] },
+//!     "maiamai_madness":   { "true": 1, "false": 4 },
+//!     "keysy":             "Off"
+//!   },
+//!   "rules": [
+//!     { "if": { "cracksanity": { "not": "Off" } }, "then": { "cracks": "Open" } }
+//!   ]
+//! }
+//! ```
+//!
+//! Rules are applied in order after every setting is rolled. A rule's condition may match a value, any of a list of
+//! values, or `{ "not": value }`, and its `then` uses the same format as `settings`.
+
+use crate::settings::validation;
+use crate::Settings;
+use rand::Rng;
+use serde::Deserialize;
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+/// Number of times to reroll settings that turn out to be invalid before giving up.
+const MAX_ROLLS: usize = 1000;
+
+/// A weights file for rolling Mystery Settings.
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct MysteryWeights {
+    /// How to roll each setting. Any setting not listed keeps its value from the base Settings.
+    #[serde(default)]
+    pub settings: BTreeMap<String, Weight>,
+
+    /// Conditional overrides, applied in order after all settings are rolled.
+    #[serde(default)]
+    pub rules: Vec<Rule>,
+}
+
+/// How to roll a single setting.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(untagged)]
+pub enum Weight {
+    /// A number chosen uniformly from an inclusive range.
+    Range { range: (u64, u64) },
+    /// Always the given value. Only needed for values that would otherwise look like weighted choices.
+    Value { value: Value },
+    /// Values chosen by relative weight, keyed by the value as it would appear in a preset.
+    Choices(BTreeMap<String, u32>),
+    /// Always the given value.
+    Fixed(Value),
+}
+
+/// Overrides settings when every condition in `if` is met.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Rule {
+    #[serde(rename = "if")]
+    pub condition: BTreeMap<String, Condition>,
+    pub then: BTreeMap<String, Weight>,
+}
+
+/// A condition on the rolled value of a setting.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(untagged)]
+pub enum Condition {
+    Not { not: Value },
+    AnyOf(Vec<Value>),
+    Equals(Value),
+}
+
+impl MysteryWeights {
+    /// Rolls a valid [`Settings`] value, starting from `base` for any setting without a weight.
+    ///
+    /// Rolled settings that fail validation are rerolled, so the result always passes [`validation::validate`] (not
+    /// including exclusion names).
+    pub fn roll<R: Rng>(&self, base: &Settings, rng: &mut R) -> Result<Settings, String> {
+        let mut base_value = base.to_json_map();
+        base_value.remove("ravios_shop"); // Can't be deserialized
+
+        for _ in 0..MAX_ROLLS {
+            let mut rolled = base_value.clone();
+
+            for (field, weight) in &self.settings {
+                rolled.insert(field.clone(), weight.roll(field, rng)?);
+            }
+
+            for rule in &self.rules {
+                if rule.condition.iter().all(|(field, condition)| condition.matches(rolled.get(field))) {
+                    for (field, weight) in &rule.then {
+                        rolled.insert(field.clone(), weight.roll(field, rng)?);
+                    }
+                }
+            }
+
+            let mut settings: Settings =
+                serde_json::from_value(Value::Object(rolled)).map_err(|err| format!("Invalid weights: {}", err))?;
+            settings.ravios_shop = base.ravios_shop;
+
+            if !validation::validate(&settings, None).iter().any(validation::Problem::is_error) {
+                return Ok(settings);
+            }
+        }
+
+        Err(format!("Could not roll valid Settings in {} attempts, please check the weights and rules.", MAX_ROLLS))
+    }
+}
+
+impl Weight {
+    fn roll<R: Rng>(&self, field: &str, rng: &mut R) -> Result<Value, String> {
+        match self {
+            Self::Range { range: (min, max) } => {
+                if min > max {
+                    return Err(format!("Invalid range for \"{}\": {} is greater than {}", field, min, max));
+                }
+                Ok(Value::from(rng.gen_range(*min..=*max)))
+            },
+            Self::Value { value } | Self::Fixed(value) => Ok(value.clone()),
+            Self::Choices(choices) => {
+                let total: u32 = choices.values().sum();
+                if total == 0 {
+                    return Err(format!("Weights for \"{}\" must not all be zero", field));
+                }
+
+                let mut roll = rng.gen_range(0..total);
+                for (choice, &weight) in choices {
+                    if roll < weight {
+                        // Choices are keyed by strings, so parse any non-string values such as `true` or `5`
+                        return Ok(serde_json::from_str(choice).unwrap_or_else(|_| Value::String(choice.clone())));
+                    }
+                    roll -= weight;
+                }
+
+                unreachable!()
+            },
+        }
+    }
+}
+
+impl Condition {
+    fn matches(&self, value: Option<&Value>) -> bool {
+        match self {
+            Self::Not { not } => value != Some(not),
+            Self::AnyOf(values) => value.is_some_and(|value| values.contains(value)),
+            Self::Equals(expected) => value == Some(expected),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::settings::{Cracks, Cracksanity};
+    use rand::{rngs::StdRng, SeedableRng};
+
+    #[test]
+    fn rules_apply_to_rolled_settings() {
+        let weights: MysteryWeights = serde_json::from_str(
+            r#"{
+                "settings": {
+                    "cracksanity": { "Off": 1, "AnyWorldPairs": 1 },
+                    "lc_requirement": { "range": [2, 4] },
+                    "maiamai_madness": { "true": 1, "false": 0 }
+                },
+                "rules": [{ "if": { "cracksanity": { "not": "Off" } }, "then": { "cracks": "Open" } }]
+            }"#,
+        )
+        .unwrap();
+
+        let rng = &mut StdRng::seed_from_u64(0);
+        for _ in 0..20 {
+            let settings = weights.roll(&Settings::default(), rng).unwrap();
+            assert!((2..=4).contains(&settings.lc_requirement));
+            assert!(settings.maiamai_madness);
+            assert_eq!(settings.cracks == Cracks::Open, settings.cracksanity != Cracksanity::Off);
+        }
+    }
+}
@@ -1,3 +1,4 @@
+use crate::settings::metadata::{Choice, Choices};
 use serde::{Deserialize, Serialize};
 use std::fmt::{Display, Formatter};
 
@@ -29,6 +30,29 @@ impl TryFrom<u8> for NiceItems {
     }
 }
 
+impl Choices for NiceItems {
+    const CHOICES: &'static [Choice] = &[
+        Choice {
+            value: "Vanilla",
+            index: 0,
+            title: "Vanilla",
+            description: "Nice Items are obtained as upgrades from Mother Maiamai.",
+        },
+        Choice {
+            value: "Shuffled",
+            index: 1,
+            title: "Shuffled",
+            description: "Freely shuffles two progressive copies of each Ravio Item, and randomizes Mother Maiamai's rewards.",
+        },
+        Choice {
+            value: "Off",
+            index: 2,
+            title: "Off",
+            description: "Removes Nice Items from the game, and randomizes Mother Maiamai's rewards.",
+        },
+    ];
+}
+
 impl Display for NiceItems {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
@@ -1,3 +1,4 @@
+use crate::settings::metadata::{Choice, Choices};
 use crate::settings::pedestal::PedestalSetting::*;
 use serde::{Deserialize, Serialize};
 use std::fmt::{Display, Formatter};
@@ -21,6 +22,23 @@ impl TryFrom<u8> for PedestalSetting {
     }
 }
 
+impl Choices for PedestalSetting {
+    const CHOICES: &'static [Choice] = &[
+        Choice {
+            value: "Vanilla",
+            index: 2,
+            title: "Vanilla",
+            description: "Requires only the Pendants of Power and Wisdom.",
+        },
+        Choice {
+            value: "Standard",
+            index: 3,
+            title: "Standard",
+            description: "Requires the Pendants of Power, Wisdom, and Courage.",
+        },
+    ];
+}
+
 impl Display for PedestalSetting {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
@@ -0,0 +1,24 @@
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Partial plando: items forced onto specific checks or into specific regions, while everything else is placed by the
+/// filler as normal.
+///
+/// Item names are those used by the spoiler log, with or without the trailing `+` of progressive items, e.g. `"Bow"`,
+/// `"Quake"` or `"Eastern Palace Small Key"`.
+#[derive(Clone, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Plando {
+    /// Items to place on specific checks, keyed by check name.
+    pub checks: BTreeMap<String, String>,
+
+    /// Regions that every copy of an item must be placed in, keyed by item name. A region is either a world (`Hyrule`,
+    /// `Lorule` or `Dungeons`) or any region name used by the spoiler log, e.g. `Eastern Palace`.
+    pub items: BTreeMap<String, BTreeSet<String>>,
+}
+
+impl Plando {
+    pub fn is_empty(&self) -> bool {
+        self.checks.is_empty() && self.items.is_empty()
+    }
+}
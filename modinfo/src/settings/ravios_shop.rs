@@ -1,3 +1,4 @@
+use crate::settings::metadata::{Choice, Choices};
 use serde::{Deserialize, Serialize};
 use std::fmt::{Display, Formatter};
 
@@ -24,6 +25,23 @@ impl TryFrom<u8> for RaviosShop {
     }
 }
 
+impl Choices for RaviosShop {
+    const CHOICES: &'static [Choice] = &[
+        Choice {
+            value: "Closed",
+            index: 0,
+            title: "Closed",
+            description: "Game starts with Link waking up to Ravio in his house, and the shop must be opened by one of three triggers.",
+        },
+        Choice {
+            value: "Open",
+            index: 1,
+            title: "Open",
+            description: "Game starts with Ravio's Shop already open for business.",
+        },
+    ];
+}
+
 impl Display for RaviosShop {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
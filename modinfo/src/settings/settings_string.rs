@@ -0,0 +1,423 @@
+//! Compact, shareable Settings strings.
+//!
+//! A settings string is a URL-safe Base64 encoding of a format version number followed by every [`Settings`] field,
+//! bit-packed in a fixed order. Strings are only readable by a randomizer using the same format version.
+
+use crate::settings::{
+    logic_overrides::RuleOverride, Cracks, Cracksanity, DungeonEntrances, DungeonItems, ItemPool, JunkItem, Keysy,
+    LogicMode, LogicOverrides, NiceItems, PedestalSetting, Plando, RaviosShop, Settings, StartingItem, TrialsDoor,
+    WeatherVanes,
+};
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Bump whenever fields are added, removed, reordered, or change their encoding.
+const FORMAT_VERSION: u8 = 11;
+
+/// Invokes the given macro with every [`Settings`] field, in encoding order.
+macro_rules! with_fields {
+    ($m:ident, $($args:tt)*) => {
+        $m!(
+            $($args)*;
+            dev_mode,
+            lc_requirement,
+            yuganon_requirement,
+            ped_requirement,
+            logic_mode,
+            enabled_tricks,
+            dark_rooms_lampless,
+            dungeon_prize_shuffle,
+            maiamai_limit,
+            maiamai_madness,
+            nice_items,
+            super_items,
+            item_pool,
+            junk_weights,
+            lamp_and_net_as_weapons,
+            cracks,
+            cracksanity,
+            dungeon_entrances,
+            weather_vanes,
+            ravios_shop,
+            bow_of_light_in_castle,
+            no_progression_enemies,
+            keysy,
+            small_key_shuffle,
+            big_key_shuffle,
+            key_rings,
+            progressive_bow_of_light,
+            swordless_mode,
+            start_with_merge,
+            start_with_pouch,
+            starting_items,
+            bell_in_shop,
+            sword_in_shop,
+            boots_in_shop,
+            assured_weapon,
+            chest_size_matches_contents,
+            minigames_excluded,
+            skip_big_bomb_flower,
+            trials_door,
+            treacherous_tower_floors,
+            purple_potion_bottles,
+            night_mode,
+            user_exclusions,
+            user_priorities,
+            plando,
+            logic_overrides
+        )
+    };
+}
+
+/// Encodes the given [`Settings`] into a settings string.
+pub fn encode(settings: &Settings) -> String {
+    macro_rules! write {
+        ($writer:ident; $($field:ident),+) => {
+            $(settings.$field.write(&mut $writer);)+
+        };
+    }
+
+    let mut writer = BitWriter::default();
+    writer.varint(FORMAT_VERSION as u64);
+    with_fields!(write, writer);
+
+    URL_SAFE_NO_PAD.encode(writer.bytes)
+}
+
+/// Decodes a settings string created by [`encode`] back into [`Settings`].
+pub fn decode(string: &str) -> Result<Settings, String> {
+    macro_rules! read {
+        ($reader:ident; $($field:ident),+) => {
+            Settings { $($field: Field::read(&mut $reader)?),+ }
+        };
+    }
+
+    let bytes = URL_SAFE_NO_PAD
+        .decode(string.trim())
+        .map_err(|err| format!("Settings string is not valid URL-safe Base64: {}", err))?;
+    let mut reader = BitReader::new(&bytes);
+
+    let version = reader.varint()?;
+    if version != FORMAT_VERSION as u64 {
+        return Err(format!(
+            "Settings string uses format version {}, but this randomizer only supports version {}",
+            version, FORMAT_VERSION
+        ));
+    }
+
+    let settings = with_fields!(read, reader);
+    reader.finish()?;
+
+    Ok(settings)
+}
+
+#[derive(Default)]
+struct BitWriter {
+    bytes: Vec<u8>,
+    /// Number of bits written to the last byte.
+    used: u8,
+}
+
+impl BitWriter {
+    fn bit(&mut self, bit: bool) {
+        if self.used == 0 {
+            self.bytes.push(0);
+        }
+        if bit {
+            *self.bytes.last_mut().unwrap() |= 0x80 >> self.used;
+        }
+        self.used = (self.used + 1) % 8;
+    }
+
+    fn bits(&mut self, value: u64, count: u8) {
+        for i in (0..count).rev() {
+            self.bit(value >> i & 1 == 1);
+        }
+    }
+
+    /// Writes `value` in 4-bit groups, each preceded by a bit indicating whether another group follows.
+    fn varint(&mut self, mut value: u64) {
+        loop {
+            let group = value & 0xF;
+            value >>= 4;
+            self.bit(value != 0);
+            self.bits(group, 4);
+            if value == 0 {
+                break;
+            }
+        }
+    }
+}
+
+struct BitReader<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl<'a> BitReader<'a> {
+    fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes, position: 0 }
+    }
+
+    fn bit(&mut self) -> Result<bool, String> {
+        let byte = self.bytes.get(self.position / 8).ok_or("Settings string is too short")?;
+        let bit = byte & (0x80 >> (self.position % 8)) != 0;
+        self.position += 1;
+        Ok(bit)
+    }
+
+    fn bits(&mut self, count: u8) -> Result<u64, String> {
+        let mut value = 0;
+        for _ in 0..count {
+            value = value << 1 | self.bit()? as u64;
+        }
+        Ok(value)
+    }
+
+    fn varint(&mut self) -> Result<u64, String> {
+        let mut value = 0;
+        let mut shift = 0;
+        loop {
+            let more = self.bit()?;
+            if shift >= u64::BITS {
+                return Err("Settings string contains an out of range number".to_owned());
+            }
+            value |= self.bits(4)? << shift;
+            shift += 4;
+            if !more {
+                return Ok(value);
+            }
+        }
+    }
+
+    /// Verifies nothing but zero padding remains.
+    fn finish(mut self) -> Result<(), String> {
+        if self.bytes.len() * 8 - self.position >= 8 {
+            return Err("Settings string is too long".to_owned());
+        }
+        while self.position < self.bytes.len() * 8 {
+            if self.bit()? {
+                return Err("Settings string is too long".to_owned());
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A single [`Settings`] field that can be written to and read from a settings string.
+trait Field: Sized {
+    fn write(&self, writer: &mut BitWriter);
+    fn read(reader: &mut BitReader) -> Result<Self, String>;
+}
+
+impl Field for bool {
+    fn write(&self, writer: &mut BitWriter) {
+        writer.bit(*self);
+    }
+
+    fn read(reader: &mut BitReader) -> Result<Self, String> {
+        reader.bit()
+    }
+}
+
+impl Field for u8 {
+    fn write(&self, writer: &mut BitWriter) {
+        writer.varint(*self as u64);
+    }
+
+    fn read(reader: &mut BitReader) -> Result<Self, String> {
+        u8::try_from(reader.varint()?).map_err(|err| err.to_string())
+    }
+}
+
+impl Field for usize {
+    fn write(&self, writer: &mut BitWriter) {
+        writer.varint(*self as u64);
+    }
+
+    fn read(reader: &mut BitReader) -> Result<Self, String> {
+        usize::try_from(reader.varint()?).map_err(|err| err.to_string())
+    }
+}
+
+impl Field for String {
+    fn write(&self, writer: &mut BitWriter) {
+        self.len().write(writer);
+        for byte in self.bytes() {
+            writer.bits(byte as u64, 8);
+        }
+    }
+
+    fn read(reader: &mut BitReader) -> Result<Self, String> {
+        let len = usize::read(reader)?;
+        let bytes = (0..len).map(|_| reader.bits(8).map(|byte| byte as u8)).collect::<Result<Vec<_>, _>>()?;
+        String::from_utf8(bytes).map_err(|err| err.to_string())
+    }
+}
+
+impl<T: Field> Field for Option<T> {
+    fn write(&self, writer: &mut BitWriter) {
+        self.is_some().write(writer);
+        if let Some(value) = self {
+            value.write(writer);
+        }
+    }
+
+    fn read(reader: &mut BitReader) -> Result<Self, String> {
+        Ok(if bool::read(reader)? { Some(T::read(reader)?) } else { None })
+    }
+}
+
+impl<T: Field + Ord> Field for BTreeSet<T> {
+    fn write(&self, writer: &mut BitWriter) {
+        self.len().write(writer);
+        for value in self {
+            value.write(writer);
+        }
+    }
+
+    fn read(reader: &mut BitReader) -> Result<Self, String> {
+        let len = usize::read(reader)?;
+        (0..len).map(|_| T::read(reader)).collect()
+    }
+}
+
+impl<K: Field + Ord, V: Field> Field for BTreeMap<K, V> {
+    fn write(&self, writer: &mut BitWriter) {
+        self.len().write(writer);
+        for (key, value) in self {
+            key.write(writer);
+            value.write(writer);
+        }
+    }
+
+    fn read(reader: &mut BitReader) -> Result<Self, String> {
+        let len = usize::read(reader)?;
+        (0..len).map(|_| Ok((K::read(reader)?, V::read(reader)?))).collect()
+    }
+}
+
+impl Field for Plando {
+    fn write(&self, writer: &mut BitWriter) {
+        self.checks.write(writer);
+        self.items.write(writer);
+    }
+
+    fn read(reader: &mut BitReader) -> Result<Self, String> {
+        Ok(Self { checks: Field::read(reader)?, items: Field::read(reader)? })
+    }
+}
+
+impl Field for LogicOverrides {
+    fn write(&self, writer: &mut BitWriter) {
+        self.helpers.write(writer);
+        self.checks.write(writer);
+        self.edges.write(writer);
+    }
+
+    fn read(reader: &mut BitReader) -> Result<Self, String> {
+        Ok(Self { helpers: Field::read(reader)?, checks: Field::read(reader)?, edges: Field::read(reader)? })
+    }
+}
+
+impl Field for RuleOverride {
+    fn write(&self, writer: &mut BitWriter) {
+        self.normal.write(writer);
+        self.tricks.write(writer);
+    }
+
+    fn read(reader: &mut BitReader) -> Result<Self, String> {
+        Ok(Self { normal: Field::read(reader)?, tricks: Field::read(reader)? })
+    }
+}
+
+/// Encodes setting enums by their `TryFrom<u8>` index.
+macro_rules! enum_field {
+    ($($setting:ty),+) => {
+        $(impl Field for $setting {
+            fn write(&self, writer: &mut BitWriter) {
+                writer.varint(*self as u64);
+            }
+
+            fn read(reader: &mut BitReader) -> Result<Self, String> {
+                Self::try_from(u8::read(reader)?)
+            }
+        })+
+    };
+}
+
+enum_field!(
+    Cracks, Cracksanity, DungeonEntrances, DungeonItems, ItemPool, JunkItem, Keysy, LogicMode, NiceItems, RaviosShop,
+    StartingItem, TrialsDoor, WeatherVanes
+);
+
+/// [`PedestalSetting`] indices are the number of required Pendants, not the variant's position.
+impl Field for PedestalSetting {
+    fn write(&self, writer: &mut BitWriter) {
+        writer.varint(match self {
+            PedestalSetting::Vanilla => 2,
+            PedestalSetting::Standard => 3,
+        });
+    }
+
+    fn read(reader: &mut BitReader) -> Result<Self, String> {
+        Self::try_from(u8::read(reader)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let mut settings = Settings {
+            lc_requirement: 3,
+            ped_requirement: PedestalSetting::Vanilla,
+            logic_mode: LogicMode::AdvGlitched,
+            maiamai_limit: 100,
+            cracksanity: Cracksanity::MirroredAnyWorldPairs,
+            dungeon_entrances: DungeonEntrances::SameWorld,
+            weather_vanes: WeatherVanes::All,
+            keysy: Keysy::AllKeysy,
+            big_key_shuffle: DungeonItems::Anywhere,
+            key_rings: true,
+            item_pool: ItemPool::Minimal,
+            junk_weights: BTreeMap::from([(JunkItem::RupeeGreen, 3), (JunkItem::MonsterGuts, 1)]),
+            trials_door: TrialsDoor::AllTrialsRequired,
+            treacherous_tower_floors: 66,
+            night_mode: true,
+            ..Default::default()
+        };
+        settings.user_exclusions.insert("[Mai] Hyrule Rupee Rush Wall".to_owned());
+        settings.user_exclusions.insert("Ravio's Shop (6)".to_owned());
+        settings.user_priorities.insert("Eastern Palace".to_owned());
+        settings.starting_items.insert(StartingItem::Quake, 1);
+        settings.starting_items.insert(StartingItem::HeartContainer, 2);
+        settings.plando.checks.insert("Ravio's Shop (3)".to_owned(), "Bow".to_owned());
+        settings.plando.items.insert("Quake".to_owned(), BTreeSet::from(["Lorule".to_owned()]));
+        settings.logic_overrides.helpers.insert("can_use_crack".to_owned(), "can_merge".to_owned());
+        settings.logic_overrides.checks.insert(
+            "[EP] (1F) Popo Room".to_owned(),
+            RuleOverride {
+                normal: Some(None),
+                tricks: BTreeMap::from([("thrown_objects".to_owned(), Some("true".to_owned()))]),
+            },
+        );
+        settings.enabled_tricks.insert("enemy_clips".to_owned());
+
+        for settings in [Settings::default(), settings] {
+            let string = encode(&settings);
+            assert!(string.bytes().all(|b| b.is_ascii_alphanumeric() || b == b'-' || b == b'_'));
+            assert_eq!(decode(&string), Ok(settings));
+        }
+    }
+
+    #[test]
+    fn rejects_bad_strings() {
+        let string = encode(&Settings::default());
+        assert!(decode(&string[..string.len() - 2]).is_err());
+        assert!(decode(&format!("{}AAAA", string)).is_err());
+        assert!(decode("not a settings string!").is_err());
+    }
+}
@@ -0,0 +1,287 @@
+use crate::settings::metadata::{Choice, Choices};
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+/// Items the player may start the game with, counted by [`starting_items`].
+///
+/// Each copy started with is taken out of the item pool. Ravio's Bracelet and the Pouch are granted by the older
+/// `start_with_merge` and `start_with_pouch` settings instead.
+///
+/// [`starting_items`]: crate::Settings::starting_items
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Deserialize, Serialize)]
+pub enum StartingItem {
+    Quake,
+    PegasusBoots,
+    Flippers,
+    Bell,
+    HintGlasses,
+    Lamp,
+    Net,
+    Glove,
+    HeartContainer,
+
+    HyruleSanctuaryKey,
+    EasternKeySmall,
+    GalesKeySmall,
+    HeraKeySmall,
+    LoruleSanctuaryKey,
+    DarkKeySmall,
+    SwampKeySmall,
+    SkullKeySmall,
+    ThievesKeySmall,
+    IceKeySmall,
+    DesertKeySmall,
+    TurtleKeySmall,
+    LoruleCastleKeySmall,
+
+    EasternKeyBig,
+    GalesKeyBig,
+    HeraKeyBig,
+    DarkKeyBig,
+    SwampKeyBig,
+    SkullKeyBig,
+    ThievesKeyBig,
+    IceKeyBig,
+    DesertKeyBig,
+    TurtleKeyBig,
+}
+
+impl StartingItem {
+    /// How many copies of this item exist in the game, and so can be started with.
+    pub fn max_count(self) -> u8 {
+        match self {
+            Self::Lamp | Self::Net | Self::Glove => 2,
+            Self::HeartContainer => 10,
+            Self::EasternKeySmall | Self::HeraKeySmall => 2,
+            Self::SkullKeySmall | Self::IceKeySmall | Self::TurtleKeySmall => 3,
+            Self::GalesKeySmall | Self::DarkKeySmall | Self::SwampKeySmall => 4,
+            Self::DesertKeySmall | Self::LoruleCastleKeySmall => 5,
+            _ => 1,
+        }
+    }
+}
+
+impl TryFrom<u8> for StartingItem {
+    type Error = String;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::Quake),
+            1 => Ok(Self::PegasusBoots),
+            2 => Ok(Self::Flippers),
+            3 => Ok(Self::Bell),
+            4 => Ok(Self::HintGlasses),
+            5 => Ok(Self::Lamp),
+            6 => Ok(Self::Net),
+            7 => Ok(Self::Glove),
+            8 => Ok(Self::HeartContainer),
+            9 => Ok(Self::HyruleSanctuaryKey),
+            10 => Ok(Self::EasternKeySmall),
+            11 => Ok(Self::GalesKeySmall),
+            12 => Ok(Self::HeraKeySmall),
+            13 => Ok(Self::LoruleSanctuaryKey),
+            14 => Ok(Self::DarkKeySmall),
+            15 => Ok(Self::SwampKeySmall),
+            16 => Ok(Self::SkullKeySmall),
+            17 => Ok(Self::ThievesKeySmall),
+            18 => Ok(Self::IceKeySmall),
+            19 => Ok(Self::DesertKeySmall),
+            20 => Ok(Self::TurtleKeySmall),
+            21 => Ok(Self::LoruleCastleKeySmall),
+            22 => Ok(Self::EasternKeyBig),
+            23 => Ok(Self::GalesKeyBig),
+            24 => Ok(Self::HeraKeyBig),
+            25 => Ok(Self::DarkKeyBig),
+            26 => Ok(Self::SwampKeyBig),
+            27 => Ok(Self::SkullKeyBig),
+            28 => Ok(Self::ThievesKeyBig),
+            29 => Ok(Self::IceKeyBig),
+            30 => Ok(Self::DesertKeyBig),
+            31 => Ok(Self::TurtleKeyBig),
+            _ => Err(format!("Invalid Starting Item: {}", value)),
+        }
+    }
+}
+
+impl Choices for StartingItem {
+    const CHOICES: &'static [Choice] = &[
+        Choice { value: "Quake", index: 0, title: "Quake", description: "Opens the cracks between worlds." },
+        Choice { value: "PegasusBoots", index: 1, title: "Pegasus Boots", description: "Lets Link dash." },
+        Choice { value: "Flippers", index: 2, title: "Zora's Flippers", description: "Lets Link swim." },
+        Choice { value: "Bell", index: 3, title: "Bell", description: "Calls Irene to any Weather Vane." },
+        Choice { value: "HintGlasses", index: 4, title: "Hint Glasses", description: "Reveals the Hint Ghosts." },
+        Choice { value: "Lamp", index: 5, title: "Lamp", description: "Up to 2, the second being the Super Lamp." },
+        Choice { value: "Net", index: 6, title: "Bug Net", description: "Up to 2, the second being the Super Net." },
+        Choice {
+            value: "Glove",
+            index: 7,
+            title: "Power Glove",
+            description: "Up to 2, the second being the Titan's Mitt.",
+        },
+        Choice { value: "HeartContainer", index: 8, title: "Heart Container", description: "Up to 10." },
+        Choice {
+            value: "HyruleSanctuaryKey",
+            index: 9,
+            title: "Small Key (Hyrule Sanctuary)",
+            description: "Any number opens every locked door of the dungeon. Up to 1.",
+        },
+        Choice {
+            value: "EasternKeySmall",
+            index: 10,
+            title: "Small Key (Eastern Palace)",
+            description: "Any number opens every locked door of the dungeon. Up to 2.",
+        },
+        Choice {
+            value: "GalesKeySmall",
+            index: 11,
+            title: "Small Key (House of Gales)",
+            description: "Any number opens every locked door of the dungeon. Up to 4.",
+        },
+        Choice {
+            value: "HeraKeySmall",
+            index: 12,
+            title: "Small Key (Tower of Hera)",
+            description: "Any number opens every locked door of the dungeon. Up to 2.",
+        },
+        Choice {
+            value: "LoruleSanctuaryKey",
+            index: 13,
+            title: "Small Key (Lorule Sanctuary)",
+            description: "Any number opens every locked door of the dungeon. Up to 1.",
+        },
+        Choice {
+            value: "DarkKeySmall",
+            index: 14,
+            title: "Small Key (Dark Palace)",
+            description: "Any number opens every locked door of the dungeon. Up to 4.",
+        },
+        Choice {
+            value: "SwampKeySmall",
+            index: 15,
+            title: "Small Key (Swamp Palace)",
+            description: "Any number opens every locked door of the dungeon. Up to 4.",
+        },
+        Choice {
+            value: "SkullKeySmall",
+            index: 16,
+            title: "Small Key (Skull Woods)",
+            description: "Any number opens every locked door of the dungeon. Up to 3.",
+        },
+        Choice {
+            value: "ThievesKeySmall",
+            index: 17,
+            title: "Small Key (Thieves' Hideout)",
+            description: "Any number opens every locked door of the dungeon. Up to 1.",
+        },
+        Choice {
+            value: "IceKeySmall",
+            index: 18,
+            title: "Small Key (Ice Ruins)",
+            description: "Any number opens every locked door of the dungeon. Up to 3.",
+        },
+        Choice {
+            value: "DesertKeySmall",
+            index: 19,
+            title: "Small Key (Desert Palace)",
+            description: "Any number opens every locked door of the dungeon. Up to 5.",
+        },
+        Choice {
+            value: "TurtleKeySmall",
+            index: 20,
+            title: "Small Key (Turtle Rock)",
+            description: "Any number opens every locked door of the dungeon. Up to 3.",
+        },
+        Choice {
+            value: "LoruleCastleKeySmall",
+            index: 21,
+            title: "Small Key (Lorule Castle)",
+            description: "Any number opens every locked door of the dungeon. Up to 5.",
+        },
+        Choice {
+            value: "EasternKeyBig",
+            index: 22,
+            title: "Big Key (Eastern Palace)",
+            description: "Opens the huge door of the dungeon.",
+        },
+        Choice {
+            value: "GalesKeyBig",
+            index: 23,
+            title: "Big Key (House of Gales)",
+            description: "Opens the huge door of the dungeon.",
+        },
+        Choice {
+            value: "HeraKeyBig",
+            index: 24,
+            title: "Big Key (Tower of Hera)",
+            description: "Opens the huge door of the dungeon.",
+        },
+        Choice {
+            value: "DarkKeyBig",
+            index: 25,
+            title: "Big Key (Dark Palace)",
+            description: "Opens the huge door of the dungeon.",
+        },
+        Choice {
+            value: "SwampKeyBig",
+            index: 26,
+            title: "Big Key (Swamp Palace)",
+            description: "Opens the huge door of the dungeon.",
+        },
+        Choice {
+            value: "SkullKeyBig",
+            index: 27,
+            title: "Big Key (Skull Woods)",
+            description: "Opens the huge door of the dungeon.",
+        },
+        Choice {
+            value: "ThievesKeyBig",
+            index: 28,
+            title: "Big Key (Thieves' Hideout)",
+            description: "Opens the huge door of the dungeon.",
+        },
+        Choice {
+            value: "IceKeyBig",
+            index: 29,
+            title: "Big Key (Ice Ruins)",
+            description: "Opens the huge door of the dungeon.",
+        },
+        Choice {
+            value: "DesertKeyBig",
+            index: 30,
+            title: "Big Key (Desert Palace)",
+            description: "Opens the huge door of the dungeon.",
+        },
+        Choice {
+            value: "TurtleKeyBig",
+            index: 31,
+            title: "Big Key (Turtle Rock)",
+            description: "Opens the huge door of the dungeon.",
+        },
+    ];
+}
+
+impl Display for StartingItem {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", Self::CHOICES[*self as usize].title)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn displays_choice_titles() {
+        for choice in StartingItem::CHOICES {
+            assert_eq!(StartingItem::try_from(choice.index).unwrap().to_string(), choice.title);
+        }
+    }
+
+    #[test]
+    fn counts_serialize_as_a_map() {
+        let counts = serde_json::json!({ "PegasusBoots": 1, "HeartContainer": 2, "EasternKeySmall": 2 });
+        let items: std::collections::BTreeMap<StartingItem, u8> = serde_json::from_value(counts.clone()).unwrap();
+        assert_eq!(items[&StartingItem::HeartContainer], 2);
+        assert_eq!(serde_json::to_value(&items).unwrap(), counts);
+    }
+}
@@ -1,14 +1,16 @@
+use crate::settings::metadata::{Choice, Choices};
 use serde::{Deserialize, Serialize};
 use std::fmt::{Display, Formatter};
 
 /// Trial's Door
-#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Deserialize, Serialize)]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq, Deserialize, Serialize)]
 pub enum TrialsDoor {
     /// The Trials Door will open by itself automatically.
     /// WARNING: This can require entering LC early via the crack.
     Open,
 
     /// Turns on 1 random trial.
+    #[default]
     OneTrialRequired,
 
     /// Turns on 2 random trials.
@@ -24,12 +26,6 @@ pub enum TrialsDoor {
     // Sealed, todo
 }
 
-impl Default for TrialsDoor {
-    fn default() -> Self {
-        Self::OneTrialRequired
-    }
-}
-
 impl TryFrom<u8> for TrialsDoor {
     type Error = String;
 
@@ -45,6 +41,41 @@ impl TryFrom<u8> for TrialsDoor {
     }
 }
 
+impl Choices for TrialsDoor {
+    const CHOICES: &'static [Choice] = &[
+        Choice {
+            value: "Open",
+            index: 0,
+            title: "Open",
+            description: "Trial's Door removed. (WARNING: May require entering Lorule Castle via the crack.)",
+        },
+        Choice {
+            value: "OneTrialRequired",
+            index: 1,
+            title: "1 Trial Required",
+            description: "1 Trial Required, randomly selected.",
+        },
+        Choice {
+            value: "TwoTrialsRequired",
+            index: 2,
+            title: "2 Trials Required",
+            description: "2 Trials Required, randomly selected.",
+        },
+        Choice {
+            value: "ThreeTrialsRequired",
+            index: 3,
+            title: "3 Trials Required",
+            description: "3 Trials Required, randomly selected.",
+        },
+        Choice {
+            value: "AllTrialsRequired",
+            index: 4,
+            title: "All Trials Required",
+            description: "All 4 Trials Required.",
+        },
+    ];
+}
+
 impl Display for TrialsDoor {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
@@ -0,0 +1,361 @@
+//! Validation of [`Settings`], reporting every problem at once.
+
+use crate::settings::{Cracks, LogicMode, Settings, StartingItem};
+use serde::Serialize;
+use std::collections::BTreeSet;
+use std::fmt::{Display, Formatter};
+
+/// How serious a [`Problem`] is.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    /// The Settings cannot be used to generate a seed.
+    Error,
+    /// The Settings can be used, but probably don't do what the user intended.
+    Warning,
+}
+
+/// A single problem found with a [`Settings`] value.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct Problem {
+    /// Path to the offending setting, using the same names as preset files, e.g. `user_exclusions`.
+    pub field: String,
+    pub severity: Severity,
+    pub message: String,
+}
+
+impl Problem {
+    pub fn error(field: &str, message: impl Into<String>) -> Self {
+        Self { field: field.to_owned(), severity: Severity::Error, message: message.into() }
+    }
+
+    pub fn warning(field: &str, message: impl Into<String>) -> Self {
+        Self { field: field.to_owned(), severity: Severity::Warning, message: message.into() }
+    }
+
+    pub fn is_error(&self) -> bool {
+        self.severity == Severity::Error
+    }
+}
+
+impl Display for Problem {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.field, self.message)
+    }
+}
+
+/// Checks the Settings for invalid values and incompatible selections, returning every problem found.
+///
+/// If `check_names` is provided, each of the `user_exclusions` and `plando` checks must be one of them.
+pub fn validate(settings: &Settings, check_names: Option<&BTreeSet<String>>) -> Vec<Problem> {
+    let mut problems = Vec::new();
+
+    // Ranges
+    if settings.lc_requirement > 7 {
+        problems.push(Problem::error(
+            "lc_requirement",
+            format!("Lorule Castle Requirement: \"{}\" was not between 0-7, inclusive.", settings.lc_requirement),
+        ));
+    }
+
+    if settings.yuganon_requirement > 7 {
+        problems.push(Problem::error(
+            "yuganon_requirement",
+            format!("Yuga Ganon Requirement: \"{}\" was not between 0-7, inclusive.", settings.yuganon_requirement),
+        ));
+    }
+
+    if !(2..=66).contains(&settings.treacherous_tower_floors) {
+        problems.push(Problem::error(
+            "treacherous_tower_floors",
+            format!(
+                "Treacherous Tower Floors: \"{}\" was not between 2-66, inclusive.",
+                settings.treacherous_tower_floors
+            ),
+        ));
+    } else if settings.treacherous_tower_floors > 15 {
+        problems.push(Problem::warning(
+            "treacherous_tower_floors",
+            "With more than 15 floors, Treacherous Tower will mostly use Advanced floors.",
+        ));
+    }
+
+    if settings.maiamai_limit > 100 {
+        problems.push(Problem::warning(
+            "maiamai_limit",
+            format!("Maiamai Limit: \"{}\" is more than the 100 Maiamai that exist.", settings.maiamai_limit),
+        ));
+    }
+
+    for (&item, &count) in &settings.starting_items {
+        if count > item.max_count() {
+            problems.push(Problem::error(
+                "starting_items",
+                format!("Starting Items: {} \"{}\" was more than the {} that exist.", item, count, item.max_count()),
+            ));
+        }
+    }
+
+    // Incompatible selections
+    if settings.progressive_bow_of_light && settings.bow_of_light_in_castle {
+        problems.push(Problem::error(
+            "bow_of_light_in_castle",
+            "The progressive_bow_of_light and bow_of_light_in_castle settings cannot both be enabled.",
+        ));
+    }
+
+    if settings.sword_in_shop && settings.swordless_mode {
+        problems.push(Problem::error(
+            "swordless_mode",
+            "The sword_in_shop and swordless_mode settings cannot both be enabled.",
+        ));
+    }
+
+    if settings.assured_weapon && (settings.sword_in_shop || settings.boots_in_shop) {
+        problems.push(Problem::error(
+            "assured_weapon",
+            "The assured_weapon setting cannot be enabled when either sword_in_shop or boots_in_shop is also enabled.",
+        ));
+    }
+
+    // Questionable selections
+    if settings.starting_count(StartingItem::Quake) > 0 && settings.cracks == Cracks::Open {
+        problems.push(Problem::warning("starting_items", "Starting with Quake has no effect when Cracks are Open."));
+    }
+
+    if settings.starting_count(StartingItem::Bell) > 0 && settings.bell_in_shop {
+        problems.push(Problem::warning("bell_in_shop", "Bell in Shop has no effect when starting with the Bell."));
+    }
+
+    if settings.starting_count(StartingItem::PegasusBoots) > 0 && settings.boots_in_shop {
+        problems.push(Problem::warning(
+            "boots_in_shop",
+            "Boots in Shop has no effect when starting with the Pegasus Boots.",
+        ));
+    }
+
+    if settings.key_rings && !settings.has_key_rings() {
+        problems.push(Problem::warning("key_rings", "Key Rings have no effect when Small Keys are removed by keysy."));
+    }
+
+    if !settings.junk_weights.is_empty() && settings.junk_weights.values().all(|&weight| weight == 0) {
+        problems.push(Problem::warning("junk_weights", "Every junk weight is 0, the standard junk pool will be used."));
+    }
+
+    if settings.logic_mode == LogicMode::Hell {
+        problems.push(Problem::warning("logic_mode", "Hell Logic - Did you really mean to choose this?"));
+    }
+
+    if settings.dev_mode {
+        problems.push(Problem::warning("dev_mode", "Dev Mode is enabled."));
+    }
+
+    // Plando
+    for (check, item) in &settings.plando.checks {
+        if settings.user_exclusions.contains(check) {
+            problems.push(Problem::error(
+                "plando",
+                format!("Could not place \"{}\" on \"{}\", the check is also in user_exclusions.", item, check),
+            ));
+        }
+    }
+
+    for (item, regions) in &settings.plando.items {
+        if regions.is_empty() {
+            problems.push(Problem::error("plando", format!("No regions were given for \"{}\".", item)));
+        }
+    }
+
+    // Priorities
+    for priority in settings.user_priorities.intersection(&settings.user_exclusions) {
+        problems.push(Problem::error(
+            "user_priorities",
+            format!("Could not prioritize \"{}\", the check is also in user_exclusions.", priority),
+        ));
+    }
+
+    // Exclusions
+    if let Some(check_names) = check_names {
+        for check in settings.plando.checks.keys() {
+            if !check_names.contains(check) {
+                problems.push(Problem::error(
+                    "plando",
+                    format!(
+                        "Could not place an item on \"{}\", no matching check found with that name. \
+                        Consult a spoiler log for a list of valid check names.",
+                        check
+                    ),
+                ));
+            }
+        }
+
+        for exclusion in &settings.user_exclusions {
+            if !check_names.contains(exclusion) {
+                problems.push(Problem::error(
+                    "user_exclusions",
+                    format!(
+                        "Could not exclude \"{}\", no matching check found with that name. \
+                        Consult a spoiler log for a list of valid check names.",
+                        exclusion
+                    ),
+                ));
+            }
+        }
+    }
+
+    problems
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::settings::{JunkItem, Keysy, Plando};
+    use Severity::*;
+
+    /// The field and severity of each problem found, in order.
+    fn problems(settings: &Settings, check_names: Option<&BTreeSet<String>>) -> Vec<(String, Severity)> {
+        validate(settings, check_names).into_iter().map(|problem| (problem.field, problem.severity)).collect()
+    }
+
+    fn expected(problems: &[(&str, Severity)]) -> Vec<(String, Severity)> {
+        problems.iter().map(|&(field, severity)| (field.to_owned(), severity)).collect()
+    }
+
+    fn names(names: &[&str]) -> BTreeSet<String> {
+        names.iter().map(|&name| name.to_owned()).collect()
+    }
+
+    #[test]
+    fn accepts_default_settings() {
+        assert_eq!(problems(&Settings::default(), Some(&names(&[]))), vec![]);
+    }
+
+    #[test]
+    fn checks_ranges() {
+        let settings = Settings {
+            lc_requirement: 8,
+            yuganon_requirement: 8,
+            treacherous_tower_floors: 67,
+            maiamai_limit: 101,
+            starting_items: [(StartingItem::Lamp, 3), (StartingItem::HeartContainer, 10)].into(),
+            ..Default::default()
+        };
+        assert_eq!(
+            problems(&settings, None),
+            expected(&[
+                ("lc_requirement", Error),
+                ("yuganon_requirement", Error),
+                ("treacherous_tower_floors", Error),
+                ("maiamai_limit", Warning),
+                ("starting_items", Error),
+            ])
+        );
+
+        let settings = Settings { treacherous_tower_floors: 16, ..Default::default() };
+        assert_eq!(problems(&settings, None), expected(&[("treacherous_tower_floors", Warning)]));
+    }
+
+    #[test]
+    fn checks_incompatible_selections() {
+        let settings = Settings {
+            progressive_bow_of_light: true,
+            bow_of_light_in_castle: true,
+            sword_in_shop: true,
+            swordless_mode: true,
+            assured_weapon: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            problems(&settings, None),
+            expected(&[("bow_of_light_in_castle", Error), ("swordless_mode", Error), ("assured_weapon", Error)])
+        );
+    }
+
+    #[test]
+    fn checks_questionable_selections() {
+        let settings = Settings {
+            starting_items: [(StartingItem::Quake, 1), (StartingItem::Bell, 1), (StartingItem::PegasusBoots, 1)].into(),
+            cracks: Cracks::Open,
+            bell_in_shop: true,
+            boots_in_shop: true,
+            key_rings: true,
+            keysy: Keysy::AllKeysy,
+            junk_weights: [(JunkItem::RupeeGreen, 0)].into(),
+            logic_mode: LogicMode::Hell,
+            dev_mode: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            problems(&settings, None),
+            expected(&[
+                ("starting_items", Warning),
+                ("bell_in_shop", Warning),
+                ("boots_in_shop", Warning),
+                ("key_rings", Warning),
+                ("junk_weights", Warning),
+                ("logic_mode", Warning),
+                ("dev_mode", Warning),
+            ])
+        );
+    }
+
+    #[test]
+    fn checks_plando_and_exclusions() {
+        let mut plando = Plando::default();
+        plando.checks.insert("Dampe".to_owned(), "Bow".to_owned());
+        plando.checks.insert("Nowhere".to_owned(), "Bell".to_owned());
+        plando.items.insert("Quake".to_owned(), BTreeSet::new());
+        let settings = Settings {
+            plando,
+            user_exclusions: names(&["Dampe", "Somewhere"]),
+            user_priorities: names(&["Dampe", "Eastern Palace"]),
+            ..Default::default()
+        };
+
+        // Check names are only verified when known
+        assert_eq!(
+            problems(&settings, None),
+            expected(&[("plando", Error), ("plando", Error), ("user_priorities", Error)])
+        );
+        assert_eq!(
+            problems(&settings, Some(&names(&["Dampe"]))),
+            expected(&[
+                ("plando", Error),
+                ("plando", Error),
+                ("user_priorities", Error),
+                ("plando", Error),
+                ("user_exclusions", Error),
+            ])
+        );
+
+        let messages = validate(&settings, Some(&names(&["Dampe"]))).into_iter().map(|problem| problem.message);
+        let messages = messages.collect::<Vec<_>>();
+        assert!(messages[0].contains("\"Bow\" on \"Dampe\""), "{}", messages[0]);
+        assert!(messages[3].contains("\"Nowhere\""), "{}", messages[3]);
+        assert!(messages[4].contains("\"Somewhere\""), "{}", messages[4]);
+    }
+
+    #[test]
+    fn reports_every_problem_at_once() {
+        let settings = Settings {
+            lc_requirement: 9,
+            sword_in_shop: true,
+            swordless_mode: true,
+            dev_mode: true,
+            user_exclusions: names(&["Nowhere"]),
+            ..Default::default()
+        };
+        let check_names = names(&["Dampe"]);
+
+        assert_eq!(
+            problems(&settings, Some(&check_names)),
+            expected(&[
+                ("lc_requirement", Error),
+                ("swordless_mode", Error),
+                ("dev_mode", Warning),
+                ("user_exclusions", Error),
+            ])
+        );
+        assert_eq!(validate(&settings, Some(&check_names)).iter().filter(|problem| problem.is_error()).count(), 3);
+    }
+}
@@ -1,3 +1,4 @@
+use crate::settings::metadata::{Choice, Choices};
 use serde::{Deserialize, Serialize};
 use std::fmt::{Display, Formatter};
 
@@ -34,6 +35,42 @@ impl TryFrom<u8> for WeatherVanes {
     }
 }
 
+impl Choices for WeatherVanes {
+    const CHOICES: &'static [Choice] = &[
+        Choice {
+            value: "Standard",
+            index: 0,
+            title: "Standard",
+            description: "Only the standard complimentary Weather Vanes (Link's House & Vacant House).",
+        },
+        Choice {
+            value: "Shuffled",
+            index: 1,
+            title: "Shuffled",
+            description: "Weather Vane destinations are shuffled into random pairs.",
+        },
+        Choice {
+            value: "Convenient",
+            index: 2,
+            title: "Convenient",
+            description: "Only convenient Weather Vanes that don't affect logic.",
+        },
+        Choice {
+            value: "Hyrule",
+            index: 3,
+            title: "Hyrule",
+            description: "Only the 9 Hyrule Weather Vanes (and Vacant House).",
+        },
+        Choice {
+            value: "Lorule",
+            index: 4,
+            title: "Lorule",
+            description: "Only the 13 Lorule Weather Vanes (and Link's House).",
+        },
+        Choice { value: "All", index: 5, title: "All", description: "All 22 Weather Vanes." },
+    ];
+}
+
 impl Display for WeatherVanes {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
@@ -0,0 +1,37 @@
+//! Times seed generation, without patching, for a few fixed seeds.
+//!
+//! Run with `cargo bench -p randomizer`. Maiamai Madness adds 100 checks to the world, so it's the slowest case for the
+//! filler's reachability searches.
+
+use modinfo::Settings;
+use std::time::{Duration, Instant};
+
+const SEEDS: [u32; 5] = [61, 62, 63, 64, 65];
+
+fn main() {
+    let cases = [
+        ("Default", Settings::default()),
+        ("Maiamai Madness", Settings { maiamai_madness: true, ..Default::default() }),
+    ];
+
+    for (name, settings) in cases {
+        let mut total = Duration::ZERO;
+        let mut failed = 0;
+
+        for seed in SEEDS {
+            let start = Instant::now();
+            if randomizer::generate_seed_info(seed, settings.clone()).is_err() {
+                failed += 1;
+            }
+            total += start.elapsed();
+        }
+
+        println!(
+            "{:<16} {:>8.1} ms/seed ({} seeds, {} failed)",
+            name,
+            total.as_secs_f64() * 1000.0 / SEEDS.len() as f64,
+            SEEDS.len(),
+            failed
+        );
+    }
+}
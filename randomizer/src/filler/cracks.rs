@@ -11,13 +11,14 @@ use rand::rngs::StdRng;
 use rand::Rng;
 use rom::flag::Flag;
 use rom::scene::SpawnPoint;
-use serde::{Serialize, Serializer};
+use serde::{Deserialize, Serialize, Serializer};
 use std::cmp::Ordering;
 use std::fmt::{Display, Formatter};
 use std::hash::Hash;
+use strum::EnumIter;
 
 /// Crack item
-#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Deserialize, EnumIter)]
 pub enum Crack {
     // --- Hyrule --- //
     StylishWoman,
@@ -0,0 +1,262 @@
+use crate::filler::location::Location;
+use crate::{filler, DungeonEntranceMap};
+use game::Course::{self, *};
+use log::info;
+use modinfo::settings::dungeon_entrances::DungeonEntrances;
+use modinfo::Settings;
+use rand::rngs::StdRng;
+use rom::scene::SpawnPoint;
+use serde::{Deserialize, Serialize, Serializer};
+use std::fmt::{Display, Formatter};
+
+/// The main entrance of a dungeon. As a key of the [`DungeonEntranceMap`] it stands for the door in the overworld,
+/// named after the dungeon it leads to in the vanilla game. As a value it stands for the dungeon itself.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Deserialize)]
+pub enum DungeonEntrance {
+    // --- Hyrule --- //
+    EasternPalace,
+    HouseOfGales,
+    TowerOfHera,
+    HyruleCastle,
+    DesertPalace,
+
+    // --- Lorule --- //
+    DarkPalace,
+    SwampPalace,
+    SkullWoods,
+    ThievesHideout,
+    IceRuins,
+    TurtleRock,
+    LoruleCastle,
+}
+
+impl DungeonEntrance {
+    pub const ALL: [Self; 12] = [
+        Self::EasternPalace,
+        Self::HouseOfGales,
+        Self::TowerOfHera,
+        Self::HyruleCastle,
+        Self::DesertPalace,
+        Self::DarkPalace,
+        Self::SwampPalace,
+        Self::SkullWoods,
+        Self::ThievesHideout,
+        Self::IceRuins,
+        Self::TurtleRock,
+        Self::LoruleCastle,
+    ];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::EasternPalace => "Eastern Palace",
+            Self::HouseOfGales => "House of Gales",
+            Self::TowerOfHera => "Tower of Hera",
+            Self::HyruleCastle => "Hyrule Castle",
+            Self::DesertPalace => "Desert Palace",
+            Self::DarkPalace => "Dark Palace",
+            Self::SwampPalace => "Swamp Palace",
+            Self::SkullWoods => "Skull Woods",
+            Self::ThievesHideout => "Thieves' Hideout",
+            Self::IceRuins => "Ice Ruins",
+            Self::TurtleRock => "Turtle Rock",
+            Self::LoruleCastle => "Lorule Castle",
+        }
+    }
+
+    /// The world the door is in.
+    pub fn get_world(self) -> game::World {
+        match self {
+            Self::EasternPalace | Self::HouseOfGales | Self::TowerOfHera | Self::HyruleCastle | Self::DesertPalace => {
+                game::World::Hyrule
+            },
+            Self::DarkPalace
+            | Self::SwampPalace
+            | Self::SkullWoods
+            | Self::ThievesHideout
+            | Self::IceRuins
+            | Self::TurtleRock
+            | Self::LoruleCastle => game::World::Lorule,
+        }
+    }
+
+    /// The first Location inside the dungeon.
+    pub fn get_foyer(self) -> Location {
+        match self {
+            Self::EasternPalace => Location::EasternPalaceFoyer,
+            Self::HouseOfGales => Location::HouseOfGalesFoyer,
+            Self::TowerOfHera => Location::TowerOfHeraFoyer,
+            Self::HyruleCastle => Location::HyruleCastleDungeon,
+            Self::DesertPalace => Location::DesertPalaceFoyer,
+            Self::DarkPalace => Location::DarkPalaceFoyer,
+            Self::SwampPalace => Location::SwampPalaceFoyer,
+            Self::SkullWoods => Location::SkullWoodsFoyer,
+            Self::ThievesHideout => Location::ThievesHideoutFoyer,
+            Self::IceRuins => Location::IceRuinsFoyer,
+            Self::TurtleRock => Location::TurtleRockFoyer,
+            Self::LoruleCastle => Location::LoruleCastle1F,
+        }
+    }
+
+    /// The Location outside the door, where leaving the dungeon behind it comes out.
+    pub fn get_outside(self) -> Location {
+        match self {
+            Self::EasternPalace => Location::EasternRuinsUpper,
+            Self::HouseOfGales => Location::HouseOfGalesIsland,
+            Self::TowerOfHera => Location::DeathMountainWestTop,
+            Self::HyruleCastle => Location::HyruleCastleRoof,
+            Self::DesertPalace => Location::DesertPalaceWeatherVane,
+            Self::DarkPalace => Location::DarkRuins,
+            Self::SwampPalace => Location::SwampPalaceAntechamber,
+            Self::SkullWoods => Location::SkullWoodsOverworld,
+            Self::ThievesHideout => Location::LoruleCastleArea,
+            Self::IceRuins => Location::LoruleDeathEastTop,
+            Self::TurtleRock => Location::TurtleRockFrontDoor,
+            Self::LoruleCastle => Location::LoruleCastleArea,
+        }
+    }
+
+    /// The overworld scene holding the door.
+    pub fn get_door_scene(self) -> (Course, u16) {
+        match self {
+            Self::EasternPalace => (FieldLight, 20),
+            Self::HouseOfGales => (FieldLight, 35),
+            Self::TowerOfHera => (FieldLight, 3),
+            Self::HyruleCastle => (FieldLight, 18),
+            Self::DesertPalace => (FieldLight, 31),
+            Self::DarkPalace => (FieldDark, 20),
+            Self::SwampPalace => (FieldDark, 33),
+            Self::SkullWoods => (FieldDark, 1),
+            Self::ThievesHideout => (FieldDark, 16),
+            Self::IceRuins => (FieldDark, 5),
+            Self::TurtleRock => (FieldDark, 35),
+            Self::LoruleCastle => (FieldDark, 18),
+        }
+    }
+
+    /// Where entering the dungeon through its main entrance spawns the player. Its exits are in the same scene.
+    pub fn get_entry_spawn_point(self) -> SpawnPoint {
+        match self {
+            Self::EasternPalace => SpawnPoint::new(DungeonEast, 1, 0),
+            Self::HouseOfGales => SpawnPoint::new(DungeonWind, 1, 0),
+            Self::TowerOfHera => SpawnPoint::new(DungeonHera, 1, 0),
+            Self::HyruleCastle => SpawnPoint::new(DungeonCastle, 1, 0),
+            Self::DesertPalace => SpawnPoint::new(DungeonSand, 1, 0),
+            Self::DarkPalace => SpawnPoint::new(DungeonDark, 1, 0),
+            Self::SwampPalace => SpawnPoint::new(DungeonWater, 1, 0),
+            Self::SkullWoods => SpawnPoint::new(DungeonDokuro, 1, 0),
+            Self::ThievesHideout => SpawnPoint::new(DungeonHagure, 1, 0),
+            Self::IceRuins => SpawnPoint::new(DungeonIce, 1, 0),
+            Self::TurtleRock => SpawnPoint::new(DungeonKame, 1, 0),
+            Self::LoruleCastle => SpawnPoint::new(DungeonGanon, 1, 0),
+        }
+    }
+
+    /// Where leaving the dungeon through its main entrance spawns the player, in front of the door.
+    pub fn get_exit_spawn_point(self) -> SpawnPoint {
+        match self {
+            Self::EasternPalace => SpawnPoint::new(FieldLight, 20, 0),
+            Self::HouseOfGales => SpawnPoint::new(FieldLight, 35, 0),
+            Self::TowerOfHera => SpawnPoint::new(FieldLight, 3, 3),
+            Self::HyruleCastle => SpawnPoint::new(FieldLight, 18, 0),
+            Self::DesertPalace => SpawnPoint::new(FieldLight, 31, 2),
+            Self::DarkPalace => SpawnPoint::new(FieldDark, 20, 5),
+            Self::SwampPalace => SpawnPoint::new(FieldDark, 33, 0),
+            Self::SkullWoods => SpawnPoint::new(FieldDark, 1, 10),
+            Self::ThievesHideout => SpawnPoint::new(FieldDark, 16, 14),
+            Self::IceRuins => SpawnPoint::new(FieldDark, 5, 0),
+            Self::TurtleRock => SpawnPoint::new(FieldDark, 35, 6),
+            Self::LoruleCastle => SpawnPoint::new(FieldDark, 18, 0),
+        }
+    }
+}
+
+impl Display for DungeonEntrance {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl Serialize for DungeonEntrance {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+/// Builds the [`DungeonEntranceMap`], shuffling which dungeon each door leads to if Dungeon Entrances are enabled.
+///
+/// Nothing is drawn from the `rng` when they're not, so the rest of the seed is the same as before the setting existed.
+pub fn build_dungeon_entrance_map(settings: &Settings, rng: &mut StdRng) -> DungeonEntranceMap {
+    info!("Building Dungeon Entrance Map...");
+    let (hyrule, lorule): (Vec<_>, Vec<_>) =
+        DungeonEntrance::ALL.into_iter().partition(|entrance| entrance.get_world() == game::World::Hyrule);
+
+    let pools = match settings.dungeon_entrances {
+        DungeonEntrances::Off => return DungeonEntrance::ALL.into_iter().map(|entrance| (entrance, entrance)).collect(),
+        DungeonEntrances::SameWorld => vec![hyrule, lorule],
+        DungeonEntrances::AnyWorld => vec![DungeonEntrance::ALL.to_vec()],
+    };
+
+    let mut dungeon_entrance_map = DungeonEntranceMap::new();
+    for doors in pools {
+        let dungeons = filler::util::shuffle(rng, doors.clone());
+        dungeon_entrance_map.extend(doors.into_iter().zip(dungeons));
+    }
+    dungeon_entrance_map
+}
+
+/// Whether every door leads to its vanilla dungeon.
+pub fn is_vanilla(dungeon_entrance_map: &DungeonEntranceMap) -> bool {
+    dungeon_entrance_map.iter().all(|(door, dungeon)| door == dungeon)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{Rng, SeedableRng};
+
+    fn build(dungeon_entrances: DungeonEntrances, seed: u64) -> DungeonEntranceMap {
+        let settings = Settings { dungeon_entrances, ..Default::default() };
+        build_dungeon_entrance_map(&settings, &mut StdRng::seed_from_u64(seed))
+    }
+
+    #[test]
+    fn every_door_leads_to_a_different_dungeon() {
+        for mode in [DungeonEntrances::Off, DungeonEntrances::SameWorld, DungeonEntrances::AnyWorld] {
+            for seed in 0..50 {
+                let map = build(mode, seed);
+                assert!(map.keys().copied().eq(DungeonEntrance::ALL), "{:?} seed {} is missing doors", mode, seed);
+
+                let mut dungeons = map.values().copied().collect::<Vec<_>>();
+                dungeons.sort();
+                assert_eq!(dungeons, DungeonEntrance::ALL, "{:?} seed {} is not a bijection", mode, seed);
+            }
+        }
+    }
+
+    #[test]
+    fn same_world_keeps_dungeons_in_their_world() {
+        for seed in 0..50 {
+            for (door, dungeon) in build(DungeonEntrances::SameWorld, seed) {
+                assert!(door.get_world() == dungeon.get_world(), "Seed {}: {} leads to {}", seed, door, dungeon);
+            }
+        }
+    }
+
+    #[test]
+    fn any_world_can_cross_worlds() {
+        let crosses =
+            |map: DungeonEntranceMap| map.into_iter().any(|(door, dungeon)| door.get_world() != dungeon.get_world());
+        assert!((0..50).any(|seed| crosses(build(DungeonEntrances::AnyWorld, seed))));
+    }
+
+    #[test]
+    fn off_is_vanilla_and_draws_nothing_from_the_rng() {
+        let settings = Settings::default();
+        let rng = &mut StdRng::seed_from_u64(7);
+        assert!(is_vanilla(&build_dungeon_entrance_map(&settings, rng)));
+        assert_eq!(rng.gen::<u64>(), StdRng::seed_from_u64(7).gen::<u64>());
+    }
+}
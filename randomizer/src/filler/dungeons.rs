@@ -0,0 +1,264 @@
+use crate::filler::filler_item::Item::{self, *};
+use crate::regions;
+use lazy_static::lazy_static;
+use modinfo::settings::keysy::Keysy;
+use modinfo::Settings;
+use std::collections::{HashMap, HashSet};
+
+/// A dungeon that holds Small Keys, a Big Key or a Compass of its own.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Dungeon {
+    // --- Hyrule --- //
+    HyruleSanctuary,
+    EasternPalace,
+    HouseOfGales,
+    TowerOfHera,
+
+    // --- Lorule --- //
+    LoruleSanctuary,
+    DarkPalace,
+    SwampPalace,
+    SkullWoods,
+    ThievesHideout,
+    IceRuins,
+    DesertPalace,
+    TurtleRock,
+    LoruleCastle,
+}
+
+lazy_static! {
+    /// The dungeon each dungeon check belongs to, keyed by check name.
+    static ref CHECK_DUNGEONS: HashMap<&'static str, Dungeon> = Dungeon::ALL
+        .into_iter()
+        .flat_map(|dungeon| dungeon.checks().iter().map(move |&check_name| (check_name, dungeon)))
+        .collect();
+
+    /// The checks whose item sits in a chest or stands freely, recording its pickup in a flag the patcher can reassign.
+    static ref PICKUP_FLAG_CHECKS: HashSet<&'static str> = regions::patches()
+        .into_iter()
+        .filter(|(_, patch)| patch.has_pickup_flag())
+        .map(|(check_name, _)| check_name)
+        .collect();
+}
+
+impl Dungeon {
+    pub const ALL: [Self; 13] = [
+        Self::HyruleSanctuary,
+        Self::EasternPalace,
+        Self::HouseOfGales,
+        Self::TowerOfHera,
+        Self::LoruleSanctuary,
+        Self::DarkPalace,
+        Self::SwampPalace,
+        Self::SkullWoods,
+        Self::ThievesHideout,
+        Self::IceRuins,
+        Self::DesertPalace,
+        Self::TurtleRock,
+        Self::LoruleCastle,
+    ];
+
+    /// The dungeon the `item` belongs to, if it is a dungeon item.
+    pub fn of_item(item: Item) -> Option<Self> {
+        Self::ALL.into_iter().find(|dungeon| dungeon.items().any(|dungeon_item| dungeon_item == item))
+    }
+
+    /// The dungeon the check with the given name belongs to, if any.
+    pub fn of_check(check_name: &str) -> Option<Self> {
+        CHECK_DUNGEONS.get(check_name).copied()
+    }
+
+    /// Whether this dungeon counts as part of Hyrule, rather than Lorule. Desert Palace is reached through Lorule, so
+    /// it counts as a Lorule dungeon.
+    pub fn is_hyrule(self) -> bool {
+        matches!(self, Self::HyruleSanctuary | Self::EasternPalace | Self::HouseOfGales | Self::TowerOfHera)
+    }
+
+    /// Every dungeon item of this dungeon, Key Ring included.
+    pub fn items(self) -> impl Iterator<Item = Item> {
+        self.small_keys().iter().copied().chain(self.key_ring()).chain(self.big_key()).chain(self.compass())
+    }
+
+    pub fn small_keys(self) -> &'static [Item] {
+        match self {
+            Self::HyruleSanctuary => &[HyruleSanctuaryKey],
+            Self::EasternPalace => &[EasternKeySmall01, EasternKeySmall02],
+            Self::HouseOfGales => &[GalesKeySmall01, GalesKeySmall02, GalesKeySmall03, GalesKeySmall04],
+            Self::TowerOfHera => &[HeraKeySmall01, HeraKeySmall02],
+            Self::LoruleSanctuary => &[LoruleSanctuaryKey],
+            Self::DarkPalace => &[DarkKeySmall01, DarkKeySmall02, DarkKeySmall03, DarkKeySmall04],
+            Self::SwampPalace => &[SwampKeySmall01, SwampKeySmall02, SwampKeySmall03, SwampKeySmall04],
+            Self::SkullWoods => &[SkullKeySmall01, SkullKeySmall02, SkullKeySmall03],
+            Self::ThievesHideout => &[ThievesKeySmall],
+            Self::IceRuins => &[IceKeySmall01, IceKeySmall02, IceKeySmall03],
+            Self::DesertPalace => {
+                &[DesertKeySmall01, DesertKeySmall02, DesertKeySmall03, DesertKeySmall04, DesertKeySmall05]
+            },
+            Self::TurtleRock => &[TurtleKeySmall01, TurtleKeySmall02, TurtleKeySmall03],
+            Self::LoruleCastle => &[
+                LoruleCastleKeySmall01,
+                LoruleCastleKeySmall02,
+                LoruleCastleKeySmall03,
+                LoruleCastleKeySmall04,
+                LoruleCastleKeySmall05,
+            ],
+        }
+    }
+
+    /// The Key Ring replacing this dungeon's Small Keys. The Sanctuaries and Thieves' Hideout only have a single Small
+    /// Key, so they have none.
+    pub fn key_ring(self) -> Option<Item> {
+        match self {
+            Self::HyruleSanctuary | Self::LoruleSanctuary | Self::ThievesHideout => None,
+            Self::EasternPalace => Some(EasternKeyRing),
+            Self::HouseOfGales => Some(GalesKeyRing),
+            Self::TowerOfHera => Some(HeraKeyRing),
+            Self::DarkPalace => Some(DarkKeyRing),
+            Self::SwampPalace => Some(SwampKeyRing),
+            Self::SkullWoods => Some(SkullKeyRing),
+            Self::IceRuins => Some(IceKeyRing),
+            Self::DesertPalace => Some(DesertKeyRing),
+            Self::TurtleRock => Some(TurtleKeyRing),
+            Self::LoruleCastle => Some(LoruleCastleKeyRing),
+        }
+    }
+
+    pub fn big_key(self) -> Option<Item> {
+        match self {
+            Self::HyruleSanctuary | Self::LoruleSanctuary | Self::LoruleCastle => None,
+            Self::EasternPalace => Some(EasternKeyBig),
+            Self::HouseOfGales => Some(GalesKeyBig),
+            Self::TowerOfHera => Some(HeraKeyBig),
+            Self::DarkPalace => Some(DarkKeyBig),
+            Self::SwampPalace => Some(SwampKeyBig),
+            Self::SkullWoods => Some(SkullKeyBig),
+            Self::ThievesHideout => Some(ThievesKeyBig),
+            Self::IceRuins => Some(IceKeyBig),
+            Self::DesertPalace => Some(DesertKeyBig),
+            Self::TurtleRock => Some(TurtleKeyBig),
+        }
+    }
+
+    pub fn compass(self) -> Option<Item> {
+        match self {
+            Self::HyruleSanctuary | Self::LoruleSanctuary => None,
+            Self::EasternPalace => Some(EasternCompass),
+            Self::HouseOfGales => Some(GalesCompass),
+            Self::TowerOfHera => Some(HeraCompass),
+            Self::DarkPalace => Some(DarkCompass),
+            Self::SwampPalace => Some(SwampCompass),
+            Self::SkullWoods => Some(SkullCompass),
+            Self::ThievesHideout => Some(ThievesCompass),
+            Self::IceRuins => Some(IceCompass),
+            Self::DesertPalace => Some(DesertCompass),
+            Self::TurtleRock => Some(TurtleCompass),
+            Self::LoruleCastle => Some(LoruleCastleCompass),
+        }
+    }
+
+    /// The checks of this dungeon, where its own dungeon items may be placed.
+    pub fn checks(self) -> &'static [&'static str] {
+        match self {
+            Self::HyruleSanctuary => &["[HS] Entrance", "[HS] Ledge", "[HS] Lower Chest", "[HS] Upper Chest"],
+            Self::LoruleSanctuary => &["[LS] Entrance Chest", "[LS] Ledge", "[LS] Lower Chest", "[LS] Upper Chest"],
+            Self::EasternPalace => &[
+                "[EP] (1F) Merge Chest", "[EP] (1F) Left Door Chest", "[EP] (1F) Popo Room", "[EP] (1F) Secret Room",
+                "[EP] (1F) Switch Room", "[EP] (2F) Ball Room", "[EP] (2F) Defeat Popos", "[EP] (2F) Switch Room",
+                "[EP] (2F) Big Chest", "[EP] Yuga (1)", "[EP] Yuga (2)", "[EP] (3F) Escape Chest",
+                "[EP] (1F) Escape Chest",
+            ],
+            Self::HouseOfGales => &[
+                "[HG] (1F) Torches", "[HG] (1F) Switch Room", "[HG] (1F) Fire Bubbles", "[HG] (1F) West Room",
+                "[HG] (1F) West Room Secret", "[HG] (2F) Big Chest", "[HG] (2F) Narrow Ledge", "[HG] (2F) Fire Ring",
+                "[HG] (3F) Rat Room", "[HG] (3F) Fire Bubbles", "[HG] Margomill",
+            ],
+            Self::TowerOfHera => &[
+                "[TH] (1F) Outside", "[TH] (1F) Center", "[TH] (3F) Platform", "[TH] (5F) Red/Blue Switches",
+                "[TH] (6F) Left Mole", "[TH] (6F) Right Mole", "[TH] (7F) Outside (Ledge)", "[TH] (8F) Fairy Room",
+                "[TH] (11F) Big Chest", "[TH] Moldorm",
+            ],
+            Self::DarkPalace => &[
+                "[PD] (1F) Right Pit", "[PD] (1F) Left Pit", "[PD] (1F) Switch Puzzle", "[PD] (1F) Hidden Room (Upper)",
+                "[PD] (1F) Hidden Room (Lower)", "[PD] (B1) Fall From 1F", "[PD] (B1) Glow-in-the-Dark Maze",
+                "[PD] (B1) Helmasaur Room", "[PD] (B1) Helmasaur Room (Fall)", "[PD] (2F) Big Chest (Hidden)",
+                "[PD] (2F) Alcove", "[PD] (1F) Fall From 2F", "[PD] (2F) South Hidden Room", "[PD] (B1) Bomb Bowling",
+                "[PD] Gemesaur King",
+            ],
+            Self::SwampPalace => &[
+                "[SP] (B1) Center", "[SP] (B1) Raft Room (Left)", "[SP] (B1) Raft Room (Right)",
+                "[SP] (B1) Raft Room (Pillar)", "[SP] (B1) Gyorm", "[SP] (B1) Waterfall Room",
+                "[SP] (B1) Big Chest (Secret)", "[SP] (1F) Water Puzzle", "[SP] (1F) East Room", "[SP] (1F) West Room",
+                "[SP] (1F) Big Chest (Fire)", "[SP] Arrghus",
+            ],
+            Self::SkullWoods => &[
+                "[SW] (B1) Gibdo Room (Lower)", "[SW] (B1) South Chest", "[SW] (B1) Gibdo Room (Hole)",
+                "[SW] (B1) Grate Room", "[SW] (B2) Moving Platform Room", "[SW] (B1) Big Chest (Upper)",
+                // "[SW] Outdoor Chest",
+                "[SW] (B1) Big Chest (Eyes)", "[SW] Knucklemaster",
+            ],
+            Self::ThievesHideout => &[
+                "[TT] (B1) Jail Cell", "[TT] (B1) Grate Chest", "[TT] (B2) Grate Chest (Fall)",
+                "[TT] (B2) Switch Puzzle Room", "[TT] (B2) Jail Cell", "[TT] (B2) Eyegores", "[TT] (B1) Behind Wall",
+                "[TT] (B1) Big Chest (Entrance)", "[TT] (B3) Underwater", "[TT] (B3) Big Chest (Hidden)",
+                // "[TT] Stalblind",
+            ],
+            Self::IceRuins => &[
+                "[IR] (1F) Hidden Chest", "[IR] (B3) Grate Chest (Left)", "[IR] (B3) Grate Chest (Right)",
+                "[IR] (B2) Ice Pillar", "[IR] (B5) Big Chest", "[IR] (B1) East Chest", "[IR] (B1) Narrow Ledge",
+                "[IR] (B1) Upper Chest", "[IR] (B3) Big Chest (Puzzle)", "[IR] (B4) Switches",
+                "[IR] (B4) Southwest Chest (Fall)", "[IR] (B4) Narrow Platform", "[IR] (B2) Long Merge Chest",
+                "[IR] (B4) Southeast Chest (Fall)", "[IR] Dharkstare",
+            ],
+            Self::DesertPalace => &[
+                "[DP] (1F) Entrance", "[DP] (1F) Sand Room (South)", "[DP] (1F) Sand Switch Room",
+                "[DP] (1F) Sand Room (North)", "[DP] (1F) Behind Rocks", "[DP] (1F) Big Chest (Behind Wall)",
+                "[DP] (2F) Under Rock (Left)", "[DP] (2F) Beamos Room", "[DP] (2F) Under Rock (Right)",
+                "[DP] (2F) Under Rock (Ball Room)", "[DP] (2F) Big Chest (Puzzle)", "[DP] (2F) Red/Blue Switches",
+                "[DP] (2F) Leever Room", "[DP] (3F) Behind Falling Sand", "[DP] (3F) Armos Room",
+                // "[DP] Zaganaga",
+            ],
+            Self::TurtleRock => &[
+                "[TR] (1F) Center", "[TR] (1F) Grate Chest", "[TR] (1F) Northwest Room", "[TR] (1F) Northeast Ledge",
+                "[TR] (1F) Southeast Chest", "[TR] (1F) Defeat Flamolas",
+                // "[TR] Left Balcony",
+                "[TR] (1F) Under Center", "[TR] (B1) Under Center", "[TR] (B1) Northeast Room", "[TR] (B1) Platform",
+                "[TR] (B1) Grate Chest (Small)", "[TR] (B1) Big Chest (Center)", "[TR] (B1) Big Chest (Top)",
+                "[TR] Grinexx",
+            ],
+            Self::LoruleCastle => &[
+                "[LC] (1F) Ledge", "[LC] (1F) Center", "[LC] (2F) Near Torches", "[LC] (2F) Hidden Path",
+                "[LC] (2F) Ledge", "[LC] (4F) Center", "[LC] (4F) Hidden Path", "[LC] Bomb Trial (1)",
+                "[LC] Bomb Trial (2)", "[LC] Tile Trial (1)", "[LC] Tile Trial (2)", "[LC] Lamp Trial",
+                "[LC] Hook Trial (1)", "[LC] Hook Trial (2)",
+                // "[LC] Zelda",
+            ],
+        }
+    }
+}
+
+/// Whether the patcher has to record the pickup of the dungeon `item` in a flag of its own, as it may be found outside
+/// its dungeon. The game credits a Small Key or Big Key to the dungeon it is found in, so the patcher instead opens the
+/// doors of its dungeon with that flag. A Key Ring always needs one, as the game would only credit it a single key.
+///
+/// Compasses are never shuffled, so they need no flag.
+pub fn needs_pickup_flag(settings: &Settings, item: Item) -> bool {
+    let Some(dungeon) = Dungeon::of_item(item) else {
+        return false;
+    };
+
+    if dungeon.key_ring() == Some(item) {
+        settings.has_key_rings()
+    } else if dungeon.small_keys().contains(&item) {
+        settings.small_key_shuffle.is_shuffled() && !matches!(settings.keysy, Keysy::SmallKeysy | Keysy::AllKeysy)
+    } else if dungeon.big_key() == Some(item) {
+        settings.big_key_shuffle.is_shuffled() && !matches!(settings.keysy, Keysy::BigKeysy | Keysy::AllKeysy)
+    } else {
+        false
+    }
+}
+
+/// Whether the item of the check with the given name sits in a chest or stands freely, so its pickup can be recorded
+/// in a flag, see [`needs_pickup_flag`].
+pub fn has_pickup_flag(check_name: &str) -> bool {
+    PICKUP_FLAG_CHECKS.contains(check_name)
+}
@@ -0,0 +1,129 @@
+use crate::filler::filler_item::{Item, Randomizable};
+use crate::filler::frontier::Frontier;
+use crate::filler::location::Location;
+use crate::filler::logic::Logic;
+use crate::filler::progress::Progress;
+use crate::SeedInfo;
+use serde::Serialize;
+use std::fmt::{self, Display, Formatter};
+
+/// Why the filler could not produce a completable seed, and what the world graph looked like when it gave up.
+///
+/// Returned inside an [`Unbeatable`] error, see [`Error::failure_report`], and written next to where the spoiler log
+/// would have gone.
+///
+/// [`Unbeatable`]: crate::ErrorKind::Unbeatable
+/// [`Error::failure_report`]: crate::Error::failure_report
+#[derive(Debug, Serialize)]
+pub struct FailureReport {
+    seed: u32,
+    settings_string: String,
+    reason: String,
+    /// The item that could not be placed, if the failure happened while placing one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    placing: Option<Item>,
+    /// Every item logic considered the player to hold at the point of failure.
+    items: Vec<Randomizable>,
+    /// Locations in the world graph that were never reached.
+    unvisited_locations: Vec<Location>,
+    /// Paths out of reached locations that logic wouldn't let through, leading somewhere not reached, with the logic
+    /// that wasn't met.
+    blocked_paths: Vec<BlockedPath>,
+    /// Checks that were never reached, either because their location wasn't or because of their own logic.
+    unreachable_checks: Vec<UnreachableCheck>,
+}
+
+#[derive(Debug, Serialize)]
+struct BlockedPath {
+    from: Location,
+    to: Location,
+    logic: Logic,
+}
+
+#[derive(Debug, Serialize)]
+struct UnreachableCheck {
+    name: &'static str,
+    location: Location,
+}
+
+impl FailureReport {
+    pub(crate) fn new(
+        seed_info: &SeedInfo, reason: String, placing: Option<Item>, frontier: &Frontier, progress: &Progress,
+    ) -> Self {
+        let mut items = progress.get_items().collect::<Vec<_>>();
+        items.sort_by_key(|item| item.as_str());
+
+        let mut unvisited_locations = Vec::new();
+        let mut unreachable_checks = frontier
+            .blocked_checks()
+            .map(|(location, check)| UnreachableCheck { name: check.get_name(), location: *location })
+            .collect::<Vec<_>>();
+
+        for (location, location_node) in seed_info.world_graph.iter() {
+            if frontier.is_visited(location) {
+                continue;
+            }
+
+            unvisited_locations.push(*location);
+            unreachable_checks.extend(
+                location_node
+                    .get_checks()
+                    .iter()
+                    .flatten()
+                    .map(|check| UnreachableCheck { name: check.get_name(), location: *location }),
+            );
+        }
+
+        let mut blocked_paths = frontier
+            .blocked_paths()
+            .map(|(from, to, logic)| BlockedPath { from, to, logic: logic.clone() })
+            .collect::<Vec<_>>();
+
+        // The world graph is a hash map, sort everything so reports are stable and easy to read
+        unvisited_locations.sort_by_key(|location| format!("{:?}", location));
+        blocked_paths.sort_by_key(|path| format!("{:?} {:?}", path.from, path.to));
+        unreachable_checks.sort_by_key(|check| check.name);
+
+        Self {
+            seed: seed_info.seed,
+            settings_string: seed_info.settings_string.clone(),
+            reason,
+            placing,
+            items,
+            unvisited_locations,
+            blocked_paths,
+            unreachable_checks,
+        }
+    }
+
+    /// The seed that failed to generate.
+    pub fn seed(&self) -> u32 {
+        self.seed
+    }
+}
+
+impl Display for FailureReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.reason)
+    }
+}
+
+impl std::error::Error for FailureReport {}
+
+#[cfg(test)]
+mod tests {
+    use modinfo::settings::cracksanity::Cracksanity;
+    use modinfo::Settings;
+
+    #[test]
+    fn blocked_paths_carry_their_logic() {
+        let settings = Settings { cracksanity: Cracksanity::AnyWorldPairs, ..Default::default() };
+        let err = crate::generate_seed_info(2, settings).unwrap_err();
+        let report = serde_json::to_value(err.failure_report().expect("seed 2 should be unbeatable")).unwrap();
+
+        // Paths only opened up by tricks that aren't in logic have no logic left to show
+        let blocked_paths = report["blocked_paths"].as_array().unwrap();
+        assert!(blocked_paths.iter().all(|path| path["logic"].is_object()));
+        assert!(blocked_paths.iter().any(|path| path["logic"]["normal"].is_string()));
+    }
+}
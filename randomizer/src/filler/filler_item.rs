@@ -4,8 +4,9 @@ use crate::patch::lms::msbf::MsbfKey;
 use crate::Result;
 use game::ghosts::HintGhost;
 use rom::flag::Flag;
-use serde::{Serialize, Serializer};
+use serde::{Deserialize, Serialize, Serializer};
 use std::fmt::Debug;
+use strum::EnumIter;
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum Randomizable {
@@ -118,49 +119,59 @@ impl Randomizable {
                     | Item::EasternKeyBig
                     | Item::EasternKeySmall01
                     | Item::EasternKeySmall02
+                    | Item::EasternKeyRing
                     | Item::GalesKeyBig
                     | Item::GalesKeySmall01
                     | Item::GalesKeySmall02
                     | Item::GalesKeySmall03
                     | Item::GalesKeySmall04
+                    | Item::GalesKeyRing
                     | Item::HeraKeyBig
                     | Item::HeraKeySmall01
                     | Item::HeraKeySmall02
+                    | Item::HeraKeyRing
                     | Item::DarkKeyBig
                     | Item::DarkKeySmall01
                     | Item::DarkKeySmall02
                     | Item::DarkKeySmall03
                     | Item::DarkKeySmall04
+                    | Item::DarkKeyRing
                     | Item::SwampKeyBig
                     | Item::SwampKeySmall01
                     | Item::SwampKeySmall02
                     | Item::SwampKeySmall03
                     | Item::SwampKeySmall04
+                    | Item::SwampKeyRing
                     | Item::SkullKeyBig
                     | Item::SkullKeySmall01
                     | Item::SkullKeySmall02
                     | Item::SkullKeySmall03
+                    | Item::SkullKeyRing
                     | Item::ThievesKeyBig
                     | Item::ThievesKeySmall
                     | Item::IceKeyBig
                     | Item::IceKeySmall01
                     | Item::IceKeySmall02
                     | Item::IceKeySmall03
+                    | Item::IceKeyRing
                     | Item::DesertKeyBig
                     | Item::DesertKeySmall01
                     | Item::DesertKeySmall02
                     | Item::DesertKeySmall03
                     | Item::DesertKeySmall04
                     | Item::DesertKeySmall05
+                    | Item::DesertKeyRing
                     | Item::TurtleKeyBig
                     | Item::TurtleKeySmall01
                     | Item::TurtleKeySmall02
                     | Item::TurtleKeySmall03
+                    | Item::TurtleKeyRing
                     | Item::LoruleCastleKeySmall01
                     | Item::LoruleCastleKeySmall02
                     | Item::LoruleCastleKeySmall03
                     | Item::LoruleCastleKeySmall04
                     | Item::LoruleCastleKeySmall05
+                    | Item::LoruleCastleKeyRing
                     | Item::Charm
                     | Item::PendantOfPower
                     | Item::PendantOfWisdom
@@ -254,49 +265,59 @@ impl Randomizable {
                 | EasternKeyBig
                 | EasternKeySmall01
                 | EasternKeySmall02
+                | EasternKeyRing
                 | GalesKeyBig
                 | GalesKeySmall01
                 | GalesKeySmall02
                 | GalesKeySmall03
                 | GalesKeySmall04
+                | GalesKeyRing
                 | HeraKeyBig
                 | HeraKeySmall01
                 | HeraKeySmall02
+                | HeraKeyRing
                 | DarkKeyBig
                 | DarkKeySmall01
                 | DarkKeySmall02
                 | DarkKeySmall03
                 | DarkKeySmall04
+                | DarkKeyRing
                 | SwampKeyBig
                 | SwampKeySmall01
                 | SwampKeySmall02
                 | SwampKeySmall03
                 | SwampKeySmall04
+                | SwampKeyRing
                 | SkullKeyBig
                 | SkullKeySmall01
                 | SkullKeySmall02
                 | SkullKeySmall03
+                | SkullKeyRing
                 | ThievesKeyBig
                 | ThievesKeySmall
                 | IceKeyBig
                 | IceKeySmall01
                 | IceKeySmall02
                 | IceKeySmall03
+                | IceKeyRing
                 | DesertKeyBig
                 | DesertKeySmall01
                 | DesertKeySmall02
                 | DesertKeySmall03
                 | DesertKeySmall04
                 | DesertKeySmall05
+                | DesertKeyRing
                 | TurtleKeyBig
                 | TurtleKeySmall01
                 | TurtleKeySmall02
                 | TurtleKeySmall03
+                | TurtleKeyRing
                 | LoruleCastleKeySmall01
                 | LoruleCastleKeySmall02
                 | LoruleCastleKeySmall03
                 | LoruleCastleKeySmall04
                 | LoruleCastleKeySmall05
+                | LoruleCastleKeyRing
                 | PendantOfPower
                 | PendantOfWisdom
                 | Charm
@@ -336,7 +357,7 @@ impl Randomizable {
             Item(SageRosso) => Some(MsbfKey::Ice),
             Item(SageImpa) => None, // Impa special
             Item(PendantOfPower) | Item(PendantOfWisdom) | Item(PendantOfCourage) => None,
-            _ => macros::fail!("Not an MSBF Key: {:?}", self),
+            _ => unreachable!("Not an MSBF Key: {:?}", self),
         }
     }
 
@@ -397,7 +418,7 @@ impl Serialize for Randomizable {
     }
 }
 
-#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Ord, PartialOrd)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Ord, PartialOrd, Deserialize, EnumIter)]
 pub enum Item {
     Empty,
 
@@ -718,6 +739,7 @@ pub enum Item {
     EasternKeyBig,
     EasternKeySmall01,
     EasternKeySmall02,
+    EasternKeyRing,
 
     // House of Gales
     GalesCompass,
@@ -726,12 +748,14 @@ pub enum Item {
     GalesKeySmall02,
     GalesKeySmall03,
     GalesKeySmall04,
+    GalesKeyRing,
 
     // Tower of Hera
     HeraCompass,
     HeraKeyBig,
     HeraKeySmall01,
     HeraKeySmall02,
+    HeraKeyRing,
 
     // Dark Palace
     DarkCompass,
@@ -740,6 +764,7 @@ pub enum Item {
     DarkKeySmall02,
     DarkKeySmall03,
     DarkKeySmall04,
+    DarkKeyRing,
 
     // Swamp Palace
     SwampCompass,
@@ -748,6 +773,7 @@ pub enum Item {
     SwampKeySmall02,
     SwampKeySmall03,
     SwampKeySmall04,
+    SwampKeyRing,
 
     // Skull Woods
     SkullCompass,
@@ -755,6 +781,7 @@ pub enum Item {
     SkullKeySmall01,
     SkullKeySmall02,
     SkullKeySmall03,
+    SkullKeyRing,
 
     // Thieves' Hideout
     ThievesCompass,
@@ -767,6 +794,7 @@ pub enum Item {
     IceKeySmall01,
     IceKeySmall02,
     IceKeySmall03,
+    IceKeyRing,
 
     // Desert Palace
     DesertCompass,
@@ -776,6 +804,7 @@ pub enum Item {
     DesertKeySmall03,
     DesertKeySmall04,
     DesertKeySmall05,
+    DesertKeyRing,
 
     // Turtle Rock
     TurtleCompass,
@@ -783,6 +812,7 @@ pub enum Item {
     TurtleKeySmall01,
     TurtleKeySmall02,
     TurtleKeySmall03,
+    TurtleKeyRing,
 
     // Lorule Castle
     LoruleCastleCompass,
@@ -791,6 +821,7 @@ pub enum Item {
     LoruleCastleKeySmall03,
     LoruleCastleKeySmall04,
     LoruleCastleKeySmall05,
+    LoruleCastleKeyRing,
 
     // Dungeon Prizes
     PendantOfPower,
@@ -931,6 +962,10 @@ impl Item {
             | LoruleCastleKeySmall04
             | LoruleCastleKeySmall05 => game::Item::KeySmall,
 
+            // Key Rings
+            EasternKeyRing | GalesKeyRing | HeraKeyRing | DarkKeyRing | SwampKeyRing | SkullKeyRing | IceKeyRing
+            | DesertKeyRing | TurtleKeyRing | LoruleCastleKeyRing => game::Item::KeySmall,
+
             // Big Keys
             EasternKeyBig | GalesKeyBig | HeraKeyBig | DarkKeyBig | SwampKeyBig | SkullKeyBig | ThievesKeyBig
             | IceKeyBig | DesertKeyBig | TurtleKeyBig => game::Item::KeyBoss,
@@ -1123,6 +1158,9 @@ impl Item {
             | LoruleCastleKeySmall04
             | LoruleCastleKeySmall05 => "a",
 
+            EasternKeyRing | GalesKeyRing | HeraKeyRing | DarkKeyRing | SwampKeyRing | SkullKeyRing | IceKeyRing
+            | DesertKeyRing | TurtleKeyRing | LoruleCastleKeyRing => "the",
+
             PendantOfPower | PendantOfWisdom | PendantOfCourage => "the",
 
             Charm => "a",
@@ -1253,6 +1291,16 @@ impl Item {
             | LoruleCastleKeySmall03
             | LoruleCastleKeySmall04
             | LoruleCastleKeySmall05 => "Lorule Castle Small Key",
+            EasternKeyRing => "Eastern Palace Key Ring",
+            GalesKeyRing => "House of Gales Key Ring",
+            HeraKeyRing => "Tower of Hera Key Ring",
+            DarkKeyRing => "Dark Palace Key Ring",
+            SwampKeyRing => "Swamp Palace Key Ring",
+            SkullKeyRing => "Skull Woods Key Ring",
+            IceKeyRing => "Ice Ruins Key Ring",
+            DesertKeyRing => "Desert Palace Key Ring",
+            TurtleKeyRing => "Turtle Rock Key Ring",
+            LoruleCastleKeyRing => "Lorule Castle Key Ring",
             PendantOfPower => "Pendant of Power",
             PendantOfWisdom => "Pendant of Wisdom",
             PendantOfCourage => "Pendant of Courage",
@@ -1298,7 +1346,7 @@ impl Serialize for Item {
 }
 
 // Quest Items ---------------------------------------------------------------------------------
-#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Deserialize, EnumIter)]
 pub enum Goal {
     // Bosses -------
     Yuga,
@@ -1411,7 +1459,7 @@ impl Serialize for Goal {
 }
 
 /// Weather Vane Item
-#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Ord, PartialOrd)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Ord, PartialOrd, Deserialize, EnumIter)]
 pub enum Vane {
     BlacksmithWV,
     DarkPalaceWV,
@@ -0,0 +1,111 @@
+use crate::filler::check::Check;
+use crate::filler::location::Location;
+use crate::filler::logic::Logic;
+use crate::filler::path::Path;
+use crate::filler::progress::Progress;
+use crate::world::{Transitions, WorldGraph};
+use crate::SeedInfo;
+use std::collections::{HashSet, VecDeque};
+
+/// An incrementally expanded search of the world graph.
+///
+/// Logic never requires *not* having an item, as [`Requirement`] parsing only lets `!` negate settings, so anything
+/// reachable stays reachable as more items are collected. A [`Frontier`] remembers the paths and checks that were
+/// blocked so far, and only retries those when expanded with more items, instead of searching the whole world graph
+/// again.
+///
+/// [`Requirement`]: crate::filler::requirement::Requirement
+pub(crate) struct Frontier<'w> {
+    world_graph: &'w WorldGraph,
+    transitions: Transitions<'w>,
+    visited: HashSet<Location>,
+    queue: VecDeque<Location>,
+    blocked_paths: Vec<(Location, Path)>,
+    blocked_checks: Vec<(Location, Check)>,
+}
+
+impl<'w> Frontier<'w> {
+    pub fn new(seed_info: &'w SeedInfo) -> Self {
+        let start_node = Location::RavioShop;
+        Self {
+            world_graph: &seed_info.world_graph,
+            transitions: seed_info.transitions(),
+            visited: HashSet::from([start_node]),
+            queue: VecDeque::from([start_node]),
+            blocked_paths: Vec::new(),
+            blocked_checks: Vec::new(),
+        }
+    }
+
+    /// Expands the frontier with the given `progress`, returning the checks that became reachable.
+    pub fn expand(&mut self, progress: &Progress) -> crate::Result<Vec<Check>> {
+        let mut new_checks = Vec::new();
+
+        self.blocked_checks.retain(|(_, check)| {
+            let reachable = check.can_access(progress);
+            if reachable {
+                new_checks.push(check.clone());
+            }
+            !reachable
+        });
+
+        for (origin, path) in std::mem::take(&mut self.blocked_paths) {
+            self.follow(origin, path, progress);
+        }
+
+        while let Some(location) = self.queue.pop_front() {
+            let location_node = self
+                .world_graph
+                .get(&location)
+                .ok_or_else(|| crate::Error::internal(format!("Location Undefined: {:?}", location)))?;
+
+            for check in location_node.get_checks().iter().flatten() {
+                if check.can_access(progress) {
+                    new_checks.push(check.clone());
+                } else {
+                    self.blocked_checks.push((location, check.clone()));
+                }
+            }
+
+            for path in location_node.get_paths().iter().flatten() {
+                self.follow(location, path.clone(), progress);
+            }
+        }
+
+        Ok(new_checks)
+    }
+
+    /// Whether the `location` has been reached.
+    pub fn is_visited(&self, location: &Location) -> bool {
+        self.visited.contains(location)
+    }
+
+    /// The origin, destination and logic of paths out of reached locations whose logic is not yet satisfied, and that
+    /// lead somewhere not yet reached.
+    pub fn blocked_paths(&self) -> impl Iterator<Item = (Location, Location, &Logic)> + '_ {
+        self.blocked_paths
+            .iter()
+            .map(|(origin, path)| (*origin, self.transitions.resolve(path.get_destination()), path.get_logic()))
+            .filter(|(_, destination, _)| !self.is_visited(destination))
+    }
+
+    /// The checks at reached locations whose logic is not yet satisfied.
+    pub fn blocked_checks(&self) -> impl Iterator<Item = &(Location, Check)> {
+        self.blocked_checks.iter()
+    }
+
+    /// Queues the `path`'s destination if it's accessible and not yet visited, or remembers the path as blocked.
+    fn follow(&mut self, origin: Location, path: Path, progress: &Progress) {
+        let destination = self.transitions.resolve(path.get_destination());
+        if self.visited.contains(&destination) {
+            return;
+        }
+
+        if path.can_access(progress) {
+            self.visited.insert(destination);
+            self.queue.push_back(destination);
+        } else {
+            self.blocked_paths.push((origin, path));
+        }
+    }
+}
@@ -1,16 +1,22 @@
 use crate::filler::cracks::Crack;
+use crate::filler::dungeons::Dungeon;
 use crate::filler::filler_item::Item;
 use crate::filler::filler_item::Item::*;
 use crate::filler::filler_item::Vane;
 use crate::filler::util::shuffle;
+use crate::world::tricks;
 use crate::SeedInfo;
 use modinfo::settings::cracks::Cracks;
+use modinfo::settings::item_pool::ItemPool;
+use modinfo::settings::junk_items::JunkItem;
 use modinfo::settings::keysy::Keysy;
-use modinfo::settings::logic::LogicMode;
 use modinfo::settings::nice_items::NiceItems;
+use modinfo::settings::starting_items::StartingItem;
 use modinfo::Settings;
+use rand::distributions::{Distribution, WeightedIndex};
 use rand::{rngs::StdRng, Rng};
 use std::cmp::Ordering;
+use std::collections::BTreeMap;
 use std::iter::repeat;
 
 pub type Pool = Vec<Item>;
@@ -22,15 +28,18 @@ pub type Pool = Vec<Item>;
  * in the world graph, including locations that statically set their contents.
  */
 pub(crate) fn get_item_pools(rng: &mut StdRng, SeedInfo { settings, .. }: &SeedInfo) -> (Pool, Pool) {
-    let mut progression_items = get_base_progression_pool();
-    let minor_progression = get_minor_progression_pool();
+    let mut progression_items = get_base_progression_pool(settings);
+    let mut minor_progression = get_minor_progression_pool(settings);
     let dungeon_prizes = get_dungeon_prize_pool();
-    let big_keys = get_big_key_pool(settings);
-    let small_keys = get_small_key_pool(settings);
+    let mut big_keys = get_big_key_pool(settings);
+    let mut small_keys = get_small_key_pool(settings);
     let compasses = get_compass_pool();
 
     let mut delta_junk_items = 0;
 
+    // Key Rings leave fewer Small Keys to place, backfill their slots with junk
+    delta_junk_items += (SMALL_KEYS.len() - small_keys.len()) as i32;
+
     progression_items.push(if settings.progressive_bow_of_light { Bow03 } else { BowOfLight });
 
     // Choose either Letter in a Bottle or Premium Milk to include in the seed
@@ -72,8 +81,8 @@ pub(crate) fn get_item_pools(rng: &mut StdRng, SeedInfo { settings, .. }: &SeedI
         progression_items.push(Pouch);
     }
 
-    // Remove the Bee Badge from Hell Logic to keep Bee Boosting viable
-    if settings.logic_mode == LogicMode::Hell {
+    // Remove the Bee Badge when Bee Boosting is in logic to keep it viable
+    if tricks::is_in_logic(settings, "bee_boosts") {
         delta_junk_items += 1;
     } else {
         progression_items.push(BeeBadge);
@@ -86,12 +95,39 @@ pub(crate) fn get_item_pools(rng: &mut StdRng, SeedInfo { settings, .. }: &SeedI
         progression_items.extend_from_slice(&[Sword01, Sword02, Sword03, Sword04]);
     }
 
+    // Item Pool
+    match settings.item_pool {
+        ItemPool::Plentiful => {
+            let extra_items = get_plentiful_pool();
+            delta_junk_items -= extra_items.len() as i32;
+            progression_items.extend(extra_items);
+        },
+        ItemPool::Balanced => {},
+        ItemPool::Scarce | ItemPool::Minimal => {
+            // Replace the Bottles and hearts left out of the pool with junk
+            delta_junk_items += (get_bottles().len() - get_bottle_pool(settings).len()) as i32;
+            delta_junk_items += (get_heart_containers().len() - get_heart_container_pool(settings).len()) as i32;
+            delta_junk_items += (get_heart_pieces().len() - get_heart_piece_pool(settings).len()) as i32;
+        },
+    }
+
+    // Starting Items, each copy is replaced by junk
+    for item in get_starting_items(settings) {
+        for pool in [&mut progression_items, &mut minor_progression, &mut small_keys, &mut big_keys] {
+            if let Some(index) = pool.iter().position(|&pool_item| pool_item == item) {
+                pool.remove(index);
+                delta_junk_items += 1;
+                break;
+            }
+        }
+    }
+
     // Junk Pool. Add or remove elements from the junk pool based on chosen settings.
-    let junk_pool = get_base_junk_pool(rng);
+    let junk_pool = get_base_junk_pool(rng, settings);
     let mut junk_pool = shuffle(rng, junk_pool);
 
     match delta_junk_items.cmp(&0) {
-        Ordering::Greater => (0..delta_junk_items).for_each(|_| add_random_junk_item(rng, &mut junk_pool)),
+        Ordering::Greater => (0..delta_junk_items).for_each(|_| add_random_junk_item(rng, settings, &mut junk_pool)),
         Ordering::Less => (0..-delta_junk_items).for_each(|_| {
             junk_pool.pop();
         }),
@@ -107,6 +143,62 @@ pub(crate) fn get_item_pools(rng: &mut StdRng, SeedInfo { settings, .. }: &SeedI
     )
 }
 
+/// The pool items the player starts the game with, one for each copy counted in [`Settings::starting_items`].
+///
+/// Copies of an item are granted in a fixed order, e.g. a single Lamp is the regular one and a second the Super Lamp.
+/// With Key Rings, any number of Small Keys of a dungeon grants its Key Ring instead.
+pub(crate) fn get_starting_items(settings: &Settings) -> Vec<Item> {
+    settings
+        .starting_items
+        .iter()
+        .flat_map(|(&item, &count)| get_starting_item_copies(settings, item).into_iter().take(count as usize))
+        .collect()
+}
+
+/// Every copy of the pool item the given [`StartingItem`] stands for, in the order they are granted.
+fn get_starting_item_copies(settings: &Settings, item: StartingItem) -> Vec<Item> {
+    let small_keys = |dungeon: Dungeon| match dungeon.key_ring().filter(|_| settings.has_key_rings()) {
+        Some(key_ring) => vec![key_ring],
+        None => dungeon.small_keys().to_vec(),
+    };
+    let big_key = |dungeon: Dungeon| dungeon.big_key().into_iter().collect();
+
+    match item {
+        StartingItem::Quake => vec![Quake],
+        StartingItem::PegasusBoots => vec![PegasusBoots],
+        StartingItem::Flippers => vec![Flippers],
+        StartingItem::Bell => vec![Bell],
+        StartingItem::HintGlasses => vec![HintGlasses],
+        StartingItem::Lamp => vec![Lamp01, Lamp02],
+        StartingItem::Net => vec![Net01, Net02],
+        StartingItem::Glove => vec![Glove01, Glove02],
+        StartingItem::HeartContainer => get_heart_containers(),
+        StartingItem::HyruleSanctuaryKey => small_keys(Dungeon::HyruleSanctuary),
+        StartingItem::EasternKeySmall => small_keys(Dungeon::EasternPalace),
+        StartingItem::GalesKeySmall => small_keys(Dungeon::HouseOfGales),
+        StartingItem::HeraKeySmall => small_keys(Dungeon::TowerOfHera),
+        StartingItem::LoruleSanctuaryKey => small_keys(Dungeon::LoruleSanctuary),
+        StartingItem::DarkKeySmall => small_keys(Dungeon::DarkPalace),
+        StartingItem::SwampKeySmall => small_keys(Dungeon::SwampPalace),
+        StartingItem::SkullKeySmall => small_keys(Dungeon::SkullWoods),
+        StartingItem::ThievesKeySmall => small_keys(Dungeon::ThievesHideout),
+        StartingItem::IceKeySmall => small_keys(Dungeon::IceRuins),
+        StartingItem::DesertKeySmall => small_keys(Dungeon::DesertPalace),
+        StartingItem::TurtleKeySmall => small_keys(Dungeon::TurtleRock),
+        StartingItem::LoruleCastleKeySmall => small_keys(Dungeon::LoruleCastle),
+        StartingItem::EasternKeyBig => big_key(Dungeon::EasternPalace),
+        StartingItem::GalesKeyBig => big_key(Dungeon::HouseOfGales),
+        StartingItem::HeraKeyBig => big_key(Dungeon::TowerOfHera),
+        StartingItem::DarkKeyBig => big_key(Dungeon::DarkPalace),
+        StartingItem::SwampKeyBig => big_key(Dungeon::SwampPalace),
+        StartingItem::SkullKeyBig => big_key(Dungeon::SkullWoods),
+        StartingItem::ThievesKeyBig => big_key(Dungeon::ThievesHideout),
+        StartingItem::IceKeyBig => big_key(Dungeon::IceRuins),
+        StartingItem::DesertKeyBig => big_key(Dungeon::DesertPalace),
+        StartingItem::TurtleKeyBig => big_key(Dungeon::TurtleRock),
+    }
+}
+
 /**
  * Shuffle item categories amongst themselves, then order them as follows:
  * - Dungeon Prizes
@@ -127,23 +219,47 @@ fn choose_trade_item(rng: &mut StdRng) -> Item {
     [LetterInABottle, PremiumMilk][rng.gen_range(0..2)]
 }
 
-fn get_base_progression_pool() -> Vec<Item> {
-    let progression_pool = vec![
+fn get_base_progression_pool(settings: &Settings) -> Vec<Item> {
+    let mut progression_pool = vec![
         GreatSpin, Lamp01, Bow01, Boomerang01, Hookshot01, Hammer01, Bombs01, FireRod01, IceRod01, TornadoRod01,
-        SandRod01, Net01, HintGlasses, Bottle01, Bottle02, Bottle03, Bottle04, Bell, StaminaScroll, PegasusBoots,
-        Flippers, HylianShield, SmoothGem, Glove01, Glove02, Mail01, Mail02, OreYellow, OreGreen, OreBlue, OreRed,
-        ScootFruit01, ScootFruit02, FoulFruit01, FoulFruit02, Shield01, Shield02, Shield03, Shield04, GoldBee01, Charm,
+        SandRod01, Net01, HintGlasses, Bell, StaminaScroll, PegasusBoots, Flippers, HylianShield, SmoothGem, Glove01,
+        Glove02, Mail01, Mail02, OreYellow, OreGreen, OreBlue, OreRed, ScootFruit01, ScootFruit02, FoulFruit01,
+        FoulFruit02, Shield01, Shield02, Shield03, Shield04, GoldBee01, Charm,
     ];
 
+    progression_pool.extend(get_bottle_pool(settings));
     progression_pool
 }
 
-pub(crate) fn get_minor_progression_pool() -> Vec<Item> {
+/// A second copy of each non-progressive major item, for [`ItemPool::Plentiful`]. Logic only counts on one of them.
+///
+/// The game upgrades an item it already holds when picking up another copy, so the progressive families (Sword, Lamp,
+/// Net, Bow, Power Glove and the rentable items) are left out.
+fn get_plentiful_pool() -> Vec<Item> {
+    vec![HintGlasses, Bell, PegasusBoots, Flippers]
+}
+
+/// The Empty Bottles shuffled into the pool. Bouldering Guy's Bottle is an event, not part of the pool.
+fn get_bottles() -> Vec<Item> {
+    vec![Bottle01, Bottle02, Bottle03, Bottle04]
+}
+
+fn get_bottle_pool(settings: &Settings) -> Vec<Item> {
+    let mut bottles = get_bottles();
+    match settings.item_pool {
+        ItemPool::Plentiful | ItemPool::Balanced => {},
+        ItemPool::Scarce => bottles.truncate(2),
+        ItemPool::Minimal => bottles.truncate(1),
+    }
+    bottles
+}
+
+pub(crate) fn get_minor_progression_pool(settings: &Settings) -> Vec<Item> {
     let mut minor_progression_pool = vec![];
 
     minor_progression_pool.extend(get_maiamai_pool());
-    minor_progression_pool.extend(get_heart_containers());
-    minor_progression_pool.extend(get_heart_pieces());
+    minor_progression_pool.extend(get_heart_container_pool(settings));
+    minor_progression_pool.extend(get_heart_piece_pool(settings));
     minor_progression_pool.extend(get_gold_rupee_pool());
     minor_progression_pool.extend(get_silver_rupee_pool());
     minor_progression_pool.extend(get_purple_rupee_pool());
@@ -218,22 +334,33 @@ fn get_big_key_pool(settings: &Settings) -> Vec<Item> {
     }
 }
 
+const SMALL_KEYS: [Item; 38] = [
+    HyruleSanctuaryKey, LoruleSanctuaryKey, EasternKeySmall01, EasternKeySmall02, GalesKeySmall01, GalesKeySmall02,
+    GalesKeySmall03, GalesKeySmall04, HeraKeySmall01, HeraKeySmall02, DarkKeySmall01, DarkKeySmall02, DarkKeySmall03,
+    DarkKeySmall04, SwampKeySmall01, SwampKeySmall02, SwampKeySmall03, SwampKeySmall04, SkullKeySmall01,
+    SkullKeySmall02, SkullKeySmall03, ThievesKeySmall, IceKeySmall01, IceKeySmall02, IceKeySmall03, DesertKeySmall01,
+    DesertKeySmall02, DesertKeySmall03, DesertKeySmall04, DesertKeySmall05, TurtleKeySmall01, TurtleKeySmall02,
+    TurtleKeySmall03, LoruleCastleKeySmall01, LoruleCastleKeySmall02, LoruleCastleKeySmall03, LoruleCastleKeySmall04,
+    LoruleCastleKeySmall05,
+];
+
 fn get_small_key_pool(settings: &Settings) -> Vec<Item> {
-    let small_keys = vec![
-        HyruleSanctuaryKey, LoruleSanctuaryKey, EasternKeySmall01, EasternKeySmall02, GalesKeySmall01, GalesKeySmall02,
-        GalesKeySmall03, GalesKeySmall04, HeraKeySmall01, HeraKeySmall02, DarkKeySmall01, DarkKeySmall02,
-        DarkKeySmall03, DarkKeySmall04, SwampKeySmall01, SwampKeySmall02, SwampKeySmall03, SwampKeySmall04,
-        SkullKeySmall01, SkullKeySmall02, SkullKeySmall03, ThievesKeySmall, IceKeySmall01, IceKeySmall02,
-        IceKeySmall03, DesertKeySmall01, DesertKeySmall02, DesertKeySmall03, DesertKeySmall04, DesertKeySmall05,
-        TurtleKeySmall01, TurtleKeySmall02, TurtleKeySmall03, LoruleCastleKeySmall01, LoruleCastleKeySmall02,
-        LoruleCastleKeySmall03, LoruleCastleKeySmall04, LoruleCastleKeySmall05,
-    ];
     match settings.keysy {
-        Keysy::SmallKeysy | Keysy::AllKeysy => repeat(RupeeBlue).take(small_keys.len()).collect(),
-        _ => small_keys,
+        Keysy::SmallKeysy | Keysy::AllKeysy => repeat(RupeeBlue).take(SMALL_KEYS.len()).collect(),
+        _ if settings.has_key_rings() => {
+            let mut key_rings = SMALL_KEYS.into_iter().map(to_key_ring).collect::<Vec<_>>();
+            key_rings.dedup();
+            key_rings
+        },
+        _ => SMALL_KEYS.to_vec(),
     }
 }
 
+/// The Key Ring replacing the given Small Key. Dungeons with only a single Small Key keep it as is.
+fn to_key_ring(small_key: Item) -> Item {
+    Dungeon::of_item(small_key).and_then(Dungeon::key_ring).unwrap_or(small_key)
+}
+
 fn get_compass_pool() -> Vec<Item> {
     vec![
         EasternCompass, GalesCompass, HeraCompass, DarkCompass, SwampCompass, SkullCompass, ThievesCompass,
@@ -288,8 +415,31 @@ pub fn get_heart_containers() -> Vec<Item> {
     ]
 }
 
+/// The Heart Containers shuffled into the pool.
+///
+/// Lorule Castle requires 13 hearts. Alongside every Heart Piece, [`ItemPool::Scarce`] keeps just the 3 Heart Containers
+/// needed to reach that.
+pub fn get_heart_container_pool(settings: &Settings) -> Vec<Item> {
+    let mut heart_containers = get_heart_containers();
+    if settings.item_pool == ItemPool::Scarce {
+        heart_containers.truncate(3);
+    }
+    heart_containers
+}
+
+/// The Heart Pieces shuffled into the pool.
+///
+/// [`ItemPool::Minimal`] reaches Lorule Castle's 13 hearts with the Heart Containers alone, and only keeps the Heart
+/// Piece always found at Fortune's Choice.
+pub fn get_heart_piece_pool(settings: &Settings) -> Vec<Item> {
+    match settings.item_pool {
+        ItemPool::Minimal => vec![HeartPiece28],
+        _ => get_heart_pieces(),
+    }
+}
+
 /// Junk Pool
-fn get_base_junk_pool(rng: &mut StdRng) -> Vec<Item> {
+fn get_base_junk_pool(rng: &mut StdRng, settings: &Settings) -> Vec<Item> {
     const BLUES: usize = 8;
     const REDS: usize = 20;
     const TAILS: usize = 4;
@@ -299,23 +449,73 @@ fn get_base_junk_pool(rng: &mut StdRng) -> Vec<Item> {
 
     let mut junk = Vec::with_capacity(BLUES + REDS + TAILS + HORNS + GUTS + EXTRAS);
 
-    junk.extend(repeat(RupeeBlue).take(BLUES));
-    junk.extend(repeat(RupeeRed).take(REDS));
-    junk.extend(repeat(MonsterTail).take(TAILS));
-    junk.extend(repeat(MonsterHorn).take(HORNS));
-    junk.extend(repeat(MonsterGuts).take(GUTS));
+    if let Some(junk_weights) = get_junk_weights(settings) {
+        // Every slot of the standard pool is drawn from the weights instead
+        junk.extend((0..BLUES + REDS + TAILS + HORNS + GUTS).map(|_| junk_weights.sample(rng)));
+    } else {
+        junk.extend(repeat(RupeeBlue).take(BLUES));
+        junk.extend(repeat(RupeeRed).take(REDS));
+        junk.extend(repeat(MonsterTail).take(TAILS));
+        junk.extend(repeat(MonsterHorn).take(HORNS));
+        junk.extend(repeat(MonsterGuts).take(GUTS));
+    }
 
-    add_random_junk_item(rng, &mut junk); // replaces Captain's Sword
-    add_random_junk_item(rng, &mut junk); // replaces Bouldering Guy Extra
+    add_random_junk_item(rng, settings, &mut junk); // replaces Captain's Sword
+    add_random_junk_item(rng, settings, &mut junk); // replaces Bouldering Guy Extra
 
     junk
 }
 
-fn add_random_junk_item(rng: &mut StdRng, junk_pool: &mut Vec<Item>) {
+fn add_random_junk_item(rng: &mut StdRng, settings: &Settings, junk_pool: &mut Vec<Item>) {
+    if let Some(junk_weights) = get_junk_weights(settings) {
+        junk_pool.push(junk_weights.sample(rng));
+        return;
+    }
+
     const POSSIBLE_EXTRA_ITEMS: [Item; 3] = [MonsterTail, MonsterHorn, MonsterGuts];
     junk_pool.push(POSSIBLE_EXTRA_ITEMS[rng.gen_range(0..POSSIBLE_EXTRA_ITEMS.len())]);
 }
 
+/// Junk items drawn according to the user's `junk_weights`.
+struct JunkWeights {
+    items: Vec<Item>,
+    index: WeightedIndex<usize>,
+}
+
+impl JunkWeights {
+    fn sample(&self, rng: &mut StdRng) -> Item {
+        self.items[self.index.sample(rng)]
+    }
+}
+
+/// The user's `junk_weights`, or [`None`] to use the standard junk pool if no junk item has a weight.
+fn get_junk_weights(settings: &Settings) -> Option<JunkWeights> {
+    let (items, weights): (Vec<_>, Vec<_>) =
+        settings.junk_weights.iter().map(|(&junk_item, &weight)| (to_junk_item(junk_item), weight)).unzip();
+    let index = WeightedIndex::new(weights).ok()?;
+    Some(JunkWeights { items, index })
+}
+
+fn to_junk_item(junk_item: JunkItem) -> Item {
+    match junk_item {
+        JunkItem::RupeeGreen => RupeeGreen,
+        JunkItem::RupeeBlue => RupeeBlue,
+        JunkItem::RupeeRed => RupeeRed,
+        JunkItem::MonsterTail => MonsterTail,
+        JunkItem::MonsterHorn => MonsterHorn,
+        JunkItem::MonsterGuts => MonsterGuts,
+    }
+}
+
+/// Counts each item in the junk pool by name, as a preview for the Spoiler Log.
+pub(crate) fn count_junk(junk_pool: &Pool) -> BTreeMap<String, usize> {
+    let mut junk_counts = BTreeMap::new();
+    for item in junk_pool {
+        *junk_counts.entry(item.as_str().to_owned()).or_default() += 1;
+    }
+    junk_counts
+}
+
 pub fn get_maiamai_pool() -> Vec<Item> {
     vec![
         Maiamai001, Maiamai002, Maiamai003, Maiamai004, Maiamai005, Maiamai006, Maiamai007, Maiamai008, Maiamai009,
@@ -332,3 +532,150 @@ pub fn get_maiamai_pool() -> Vec<Item> {
         Maiamai100,
     ]
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use modinfo::settings::metadata::Choices;
+    use rand::SeedableRng;
+
+    fn key_ring_settings() -> Settings {
+        Settings { key_rings: true, ..Default::default() }
+    }
+
+    #[test]
+    fn key_rings_replace_small_keys() {
+        let pool = get_small_key_pool(&key_ring_settings());
+
+        for dungeon in Dungeon::ALL {
+            match dungeon.key_ring() {
+                Some(key_ring) => {
+                    assert_eq!(pool.iter().filter(|&&item| item == key_ring).count(), 1, "{:?}", dungeon);
+                    assert!(dungeon.small_keys().iter().all(|key| !pool.contains(key)), "{:?}", dungeon);
+                },
+                None => assert!(dungeon.small_keys().iter().all(|key| pool.contains(key)), "{:?}", dungeon),
+            }
+        }
+        assert_eq!(pool.len(), 13);
+    }
+
+    fn pools(settings: Settings) -> (Pool, Pool) {
+        let seed_info = SeedInfo { settings, ..Default::default() };
+        get_item_pools(&mut StdRng::seed_from_u64(1), &seed_info)
+    }
+
+    fn pool_with(item_pool: ItemPool) -> (Pool, Pool) {
+        pools(Settings { item_pool, ..Default::default() })
+    }
+
+    fn count(pool: &Pool, items: &[Item]) -> usize {
+        pool.iter().filter(|item| items.contains(item)).count()
+    }
+
+    #[test]
+    fn key_rings_are_backfilled_with_junk() {
+        let (progression, junk) = pools(Settings::default());
+        let (ring_progression, ring_junk) = pools(key_ring_settings());
+
+        let removed = SMALL_KEYS.len() - get_small_key_pool(&key_ring_settings()).len();
+        assert_eq!(ring_progression.len(), progression.len() - removed);
+        assert_eq!(ring_junk.len(), junk.len() + removed);
+    }
+
+    #[test]
+    fn plentiful_pool_adds_only_non_progressive_copies() {
+        let (progression, junk) = pool_with(ItemPool::Balanced);
+        let (plentiful, plentiful_junk) = pool_with(ItemPool::Plentiful);
+
+        assert_eq!(plentiful.len(), progression.len() + 4);
+        assert_eq!(plentiful_junk.len(), junk.len() - 4);
+        for item in [HintGlasses, Bell, PegasusBoots, Flippers] {
+            assert_eq!(count(&plentiful, &[item]), 2, "{}", item.as_str());
+        }
+        for item in [Lamp01, Net01, Bow01, Glove01, Sword01, Boomerang01, Hookshot01, FireRod01] {
+            assert_eq!(count(&plentiful, &[item]), count(&progression, &[item]), "{}", item.as_str());
+        }
+    }
+
+    #[test]
+    fn scarce_pool_drops_heart_containers_and_bottles() {
+        let (progression, junk) = pool_with(ItemPool::Balanced);
+        let (scarce, scarce_junk) = pool_with(ItemPool::Scarce);
+
+        assert_eq!(count(&scarce, &get_heart_containers()), 3);
+        assert_eq!(count(&scarce, &get_heart_pieces()), 28);
+        assert_eq!(count(&scarce, &get_bottles()), 2);
+        assert_eq!(scarce.len(), progression.len() - 9);
+        assert_eq!(scarce_junk.len(), junk.len() + 9);
+    }
+
+    #[test]
+    fn minimal_pool_keeps_just_enough_hearts() {
+        let (progression, junk) = pool_with(ItemPool::Minimal);
+        let (balanced, balanced_junk) = pool_with(ItemPool::Balanced);
+
+        let containers = count(&progression, &get_heart_containers());
+        let pieces = count(&progression, &get_heart_pieces());
+        assert_eq!((containers, pieces), (10, 1));
+        assert!(progression.contains(&HeartPiece28));
+        assert_eq!(count(&progression, &get_bottles()), 1);
+
+        // Link starts with 3 hearts, and Lorule Castle requires 13
+        assert!(3 + containers + pieces / 4 >= 13);
+        assert_eq!(progression.len() + junk.len(), balanced.len() + balanced_junk.len());
+        assert_eq!(junk.len(), balanced_junk.len() + 30);
+    }
+
+    #[test]
+    fn every_starting_item_copy_exists() {
+        for choice in StartingItem::CHOICES {
+            let item = StartingItem::try_from(choice.index).unwrap();
+            let copies = get_starting_item_copies(&Settings::default(), item);
+            assert_eq!(copies.len(), item.max_count() as usize, "{}", item);
+        }
+    }
+
+    #[test]
+    fn starting_items_are_replaced_by_junk() {
+        let (progression, junk) = pools(Settings::default());
+        let starting_items = [
+            (StartingItem::PegasusBoots, 1),
+            (StartingItem::HeartContainer, 2),
+            (StartingItem::Lamp, 1),
+            (StartingItem::EasternKeySmall, 2),
+        ];
+        let (started, started_junk) = pools(Settings { starting_items: starting_items.into(), ..Default::default() });
+
+        assert_eq!(started.len(), progression.len() - 6);
+        assert_eq!(started_junk.len(), junk.len() + 6);
+        assert_eq!(count(&started, &[PegasusBoots, Lamp01, EasternKeySmall01, EasternKeySmall02]), 0);
+        assert_eq!(count(&started, &get_heart_containers()), 8);
+        assert_eq!(count(&started, &[Lamp02, Net01]), count(&progression, &[Lamp02, Net01]));
+    }
+
+    fn weighted_settings() -> Settings {
+        let junk_weights = [(JunkItem::RupeeGreen, 1), (JunkItem::RupeeRed, 3), (JunkItem::MonsterGuts, 0)].into();
+        Settings { junk_weights, ..Default::default() }
+    }
+
+    #[test]
+    fn junk_weights_never_draw_unweighted_items() {
+        let (_, junk) = pools(weighted_settings());
+
+        assert!(junk.iter().all(|item| [RupeeGreen, RupeeRed].contains(item)), "{:?}", junk);
+        assert!(junk.contains(&RupeeGreen) && junk.contains(&RupeeRed));
+    }
+
+    #[test]
+    fn junk_weights_keep_the_pool_size() {
+        let (progression, junk) = pools(Settings::default());
+        let (weighted_progression, weighted_junk) = pools(weighted_settings());
+
+        assert_eq!(weighted_progression.len(), progression.len());
+        assert_eq!(weighted_junk.len(), junk.len());
+
+        let mut rng = StdRng::seed_from_u64(1);
+        let base_junk = get_base_junk_pool(&mut rng, &Settings::default());
+        assert_eq!(get_base_junk_pool(&mut rng, &weighted_settings()).len(), base_junk.len());
+    }
+}
@@ -6,7 +6,7 @@ use LoadingZoneId::*;
 /**
 
 Desired Groups:
-- Dungeon Entrances
+- Dungeon Entrances (see `dungeon_entrances`)
 - Cave Entrances
 - Cracks
 
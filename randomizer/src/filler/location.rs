@@ -1,6 +1,6 @@
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Deserialize, Serialize)]
 pub enum Location {
     // Hyrule -------------------
     HyruleBellTravel,
@@ -231,6 +231,7 @@ pub enum Location {
     SkullWoodsBossRoom,
     SkullWoodsSeresGrove,
 
+    ThievesHideoutFoyer,
     ThievesHideoutB1,
     ThievesBoss,
     ThievesPostBoss,
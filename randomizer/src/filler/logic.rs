@@ -1,98 +1,56 @@
-use serde::{Serialize, Serializer};
+use serde::{ser::SerializeMap, Serialize, Serializer};
 use std::fmt::{Debug, Formatter};
 
-use modinfo::settings::logic::LogicMode::*;
+use modinfo::settings::logic::LogicMode::{self, *};
 
-use crate::filler::progress::Progress;
+use crate::filler::{progress::Progress, requirement::Requirement};
 
-// TODO I'd eventually like to externalize the logic, both for organization purposes and to allow users to write custom logic. But this is fine for now.
-
-#[derive(Copy, Clone, Default)]
+/// The requirements of a [`Check`] or [`Path`], as defined by the logic files: a `normal` requirement plus one per
+/// trick that's in logic.
+///
+/// [`Check`]: crate::filler::check::Check
+/// [`Path`]: crate::filler::path::Path
+#[derive(Clone, Default)]
 pub struct Logic {
-    pub normal: Option<fn(&Progress) -> bool>,
-    pub hard: Option<fn(&Progress) -> bool>,
-    pub glitched: Option<fn(&Progress) -> bool>,
-    pub adv_glitched: Option<fn(&Progress) -> bool>,
-    pub hell: Option<fn(&Progress) -> bool>,
+    pub normal: Option<Requirement>,
+    /// Requirements paired with the name of the trick they need, only including tricks that are in logic.
+    pub tricks: Vec<(&'static str, Requirement)>,
 }
 
 impl Logic {
     pub fn new() -> Self {
-        Self { normal: None, hard: None, glitched: None, adv_glitched: None, hell: None }
+        Self { normal: None, tricks: Vec::new() }
     }
 
-    pub fn config<L>(normal: L, hard: L, glitched: L, adv_glitched: L, hell: L) -> Self
-    where
-        L: Into<Option<fn(&Progress) -> bool>>,
-    {
-        Self {
-            normal: normal.into(),
-            hard: hard.into(),
-            glitched: glitched.into(),
-            adv_glitched: adv_glitched.into(),
-            hell: hell.into(),
+    pub fn can_access(&self, progress: &Progress) -> bool {
+        // Progression is available if the normal logic or the logic of any trick passes
+        if NoLogic.eq(&progress.get_settings().logic_mode) {
+            return true;
         }
-    }
 
-    pub fn normal(&mut self, logic: fn(&Progress) -> bool) -> &mut Self {
-        self.normal = logic.into();
-        self
+        self.requirements().any(|requirement| requirement.is_met(progress))
     }
 
-    pub fn hard(&mut self, logic: fn(&Progress) -> bool) -> &mut Self {
-        self.hard = logic.into();
-        self
-    }
-
-    pub fn glitched(&mut self, logic: fn(&Progress) -> bool) -> &mut Self {
-        self.glitched = logic.into();
-        self
-    }
-
-    pub fn adv_glitched(&mut self, logic: fn(&Progress) -> bool) -> &mut Self {
-        self.adv_glitched = logic.into();
-        self
-    }
-
-    pub fn hell(&mut self, logic: fn(&Progress) -> bool) -> &mut Self {
-        self.hell = logic.into();
-        self
-    }
-
-    pub fn can_access(self, progress: &Progress) -> bool {
-        // Progression is available if the current logic or a lower tiered logic passes
-        for logic in match progress.get_settings().logic_mode {
-            Normal => Vec::from([self.normal]),
-            Hard => Vec::from([self.normal, self.hard]),
-            Glitched => Vec::from([self.normal, self.hard, self.glitched]),
-            AdvGlitched => Vec::from([self.normal, self.hard, self.glitched, self.adv_glitched]),
-            Hell => Vec::from([self.normal, self.hard, self.glitched, self.adv_glitched, self.hell]),
-            NoLogic => {
-                return true;
-            },
-        } {
-            if logic.is_some() && (logic.unwrap())(progress) {
-                return true;
-            }
-        }
-
-        false
+    /// Whether any logic is defined for the `logic_mode`, i.e. whether this can ever be accessed.
+    pub fn is_defined(&self, logic_mode: LogicMode) -> bool {
+        NoLogic.eq(&logic_mode) || self.requirements().next().is_some()
     }
 
-    pub fn free() -> Self {
-        Self {
-            normal: accessible(),
-            hard: accessible(),
-            glitched: accessible(),
-            adv_glitched: accessible(),
-            hell: accessible(),
-        }
+    fn requirements(&self) -> impl Iterator<Item = &Requirement> {
+        self.normal.iter().chain(self.tricks.iter().map(|(_, requirement)| requirement))
     }
 }
 
 impl Debug for Logic {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        f.write_str("[logic Logic]") // todo
+        let mut map = f.debug_map();
+        if let Some(normal) = &self.normal {
+            map.entry(&"normal", &format_args!("{}", normal));
+        }
+        for (trick, requirement) in &self.tricks {
+            map.entry(trick, &format_args!("{}", requirement));
+        }
+        map.finish()
     }
 }
 
@@ -101,10 +59,29 @@ impl Serialize for Logic {
     where
         S: Serializer,
     {
-        serializer.serialize_str("[logic]")
+        let mut map = serializer.serialize_map(None)?;
+        if let Some(normal) = &self.normal {
+            map.serialize_entry("normal", &normal.to_string())?;
+        }
+        if !self.tricks.is_empty() {
+            map.serialize_entry("tricks", &Tricks(&self.tricks))?;
+        }
+        map.end()
     }
 }
 
-pub fn accessible() -> Option<fn(&Progress) -> bool> {
-    Some(|_| true)
+/// Serializes trick requirements as a map, keyed by trick name.
+struct Tricks<'a>(&'a [(&'static str, Requirement)]);
+
+impl Serialize for Tricks<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut map = serializer.serialize_map(Some(self.0.len()))?;
+        for (trick, requirement) in self.0 {
+            map.serialize_entry(trick, &requirement.to_string())?;
+        }
+        map.end()
+    }
 }
@@ -1,30 +1,43 @@
 use crate::filler::check::Check;
+use crate::filler::dungeons::Dungeon;
+use crate::filler::failure::FailureReport;
 use crate::filler::filler_item::Randomizable::{Crack, Vane};
 use crate::filler::filler_item::{Item, Randomizable};
+use crate::filler::frontier::Frontier;
 use crate::filler::item_pools::{get_maiamai_pool, Pool};
 use crate::filler::location::Location;
+use crate::filler::path::Destination;
 use crate::filler::progress::Progress;
-use crate::{world::WorldGraph, CheckMap, DashMap, SeedInfo};
-use log::{error, info, warn};
-use macros::fail;
+use crate::filler::util::remove_first;
+use crate::regions::World;
+use crate::{world::WorldGraph, CheckMap, DashMap, DashSet, SeedInfo};
+use log::{info, warn};
+use modinfo::settings::dungeon_items::DungeonItems;
 use modinfo::settings::logic::LogicMode;
 use modinfo::settings::nice_items::NiceItems;
-use path::Path;
+use modinfo::settings::starting_items::StartingItem;
+use modinfo::Settings;
 use queue::Queue;
 use rand::{rngs::StdRng, Rng};
 use rom::Error;
-use std::collections::HashSet;
+use std::collections::{BTreeSet, HashSet};
 
 pub mod check;
 pub mod cracks;
+pub mod dungeon_entrances;
+pub mod dungeons;
+pub mod failure;
 pub mod filler_item;
+mod frontier;
 pub mod item_pools;
 mod loading_zone_pair;
 pub mod location;
 pub mod location_node;
 pub mod logic;
 pub mod path;
+mod plando;
 pub mod progress;
+pub mod requirement;
 pub(crate) mod text;
 pub mod tower_stage;
 pub(crate) mod treacherous_tower;
@@ -39,17 +52,20 @@ pub fn fill_all_locations_reachable(
     rng: &mut StdRng, seed_info: &mut SeedInfo, check_map: &mut CheckMap,
 ) -> crate::Result<()> {
     let (mut progression_pool, mut junk_pool) = item_pools::get_item_pools(rng, seed_info);
+    seed_info.junk_counts = item_pools::count_junk(&junk_pool);
 
     place_cracks(seed_info, check_map);
     place_weather_vanes(seed_info, check_map);
 
     verify_all_locations_accessible(seed_info, check_map, &mut progression_pool)?;
+    plando::validate_restrictions(seed_info, &progression_pool)?;
 
-    preplace_items(rng, seed_info, check_map, &mut progression_pool);
-    handle_exclusions(rng, seed_info, check_map, &mut junk_pool);
+    preplace_items(rng, seed_info, check_map, &mut progression_pool, &mut junk_pool)?;
+    handle_exclusions(rng, seed_info, check_map, &mut junk_pool)?;
+    handle_priorities(seed_info, check_map)?;
 
     assumed_fill(rng, seed_info, check_map, &mut progression_pool, &mut junk_pool)?;
-    fill_junk(rng, check_map, &mut junk_pool);
+    fill_junk(rng, check_map, &mut junk_pool)?;
 
     build_layout(seed_info, check_map)?;
 
@@ -159,8 +175,10 @@ fn place_weather_vanes(SeedInfo { vane_map, .. }: &SeedInfo, check_map: &mut Che
 
 /// Place static items ahead of the randomly filled ones
 fn preplace_items(
-    rng: &mut StdRng, SeedInfo { settings, .. }: &SeedInfo, check_map: &mut CheckMap, progression: &mut Vec<Item>,
-) {
+    rng: &mut StdRng, seed_info: &SeedInfo, check_map: &mut CheckMap, progression: &mut Vec<Item>, junk: &mut Vec<Item>,
+) -> crate::Result<()> {
+    let settings = &seed_info.settings;
+
     // Vanilla Dungeon Prizes
     if !&settings.dungeon_prize_shuffle {
         place_static(check_map, progression, Item::PendantOfCourage, "[EP] Prize");
@@ -230,21 +248,25 @@ fn preplace_items(
         "[LC] Zelda",
     ];
 
+    // Leave checks with forced placements for the plando
+    shop_positions.retain(|&check| !settings.plando.checks.contains_key(check));
+    bow_light_positions.retain(|&check| !settings.plando.checks.contains_key(check));
+
     if !settings.progressive_bow_of_light && settings.bow_of_light_in_castle {
         check_map.insert(
             String::from(bow_light_positions.remove(rng.gen_range(0..bow_light_positions.len()))),
             Some(Item::BowOfLight.into()),
         );
-        progression.retain(|x| *x != Item::BowOfLight);
+        remove_first(progression, &Item::BowOfLight);
     }
 
     // Bell in Shop
-    if settings.bell_in_shop {
+    if settings.bell_in_shop && settings.starting_count(StartingItem::Bell) == 0 {
         check_map.insert(
             String::from(shop_positions.remove(rng.gen_range(0..shop_positions.len()))),
             Some(Item::Bell.into()),
         );
-        progression.retain(|x| *x != Item::Bell);
+        remove_first(progression, &Item::Bell);
     }
 
     // Pouch in Shop
@@ -259,16 +281,16 @@ fn preplace_items(
             String::from(shop_positions.remove(rng.gen_range(0..shop_positions.len()))),
             Some(Item::Sword01.into()),
         );
-        progression.retain(|x| *x != Item::Sword01);
+        remove_first(progression, &Item::Sword01);
     }
 
     // Boots in Shop
-    if settings.boots_in_shop {
+    if settings.boots_in_shop && settings.starting_count(StartingItem::PegasusBoots) == 0 {
         check_map.insert(
             String::from(shop_positions.remove(rng.gen_range(0..shop_positions.len()))),
             Some(Item::PegasusBoots.into()),
         );
-        progression.retain(|x| *x != Item::PegasusBoots);
+        remove_first(progression, &Item::PegasusBoots);
     }
 
     // Assures a weapon will be available in Ravio's Shop
@@ -298,7 +320,7 @@ fn preplace_items(
 
         check_map
             .insert(String::from(shop_positions.remove(rng.gen_range(0..shop_positions.len()))), Some(weapon.into()));
-        progression.retain(|x| *x != weapon);
+        remove_first(progression, &weapon);
     }
 
     // For non-Maiamai Madness seeds, default them to Maiamai
@@ -316,10 +338,16 @@ fn preplace_items(
             place_static(check_map, progression, maiamai_items.remove(0), &check_name);
         }
     }
+
+    // Partial Plando
+    plando::place_forced_items(seed_info, check_map, progression, junk)?;
+    plando::verify_forced_items_reachable(seed_info, check_map, progression)
 }
 
 /// Handle Exclusions
-fn handle_exclusions(rng: &mut StdRng, seed_info: &mut SeedInfo, check_map: &mut CheckMap, junk: &mut Vec<Item>) {
+fn handle_exclusions(
+    rng: &mut StdRng, seed_info: &mut SeedInfo, check_map: &mut CheckMap, junk: &mut Vec<Item>,
+) -> crate::Result<()> {
     seed_info.full_exclusions = seed_info.settings.user_exclusions.clone();
 
     if seed_info.settings.maiamai_limit < 100 {
@@ -356,23 +384,74 @@ fn handle_exclusions(rng: &mut StdRng, seed_info: &mut SeedInfo, check_map: &mut
             let index = rng.gen_range(0..junk.len());
             check_map.insert(check_name, Some(junk.remove(index).into()));
         } else {
-            println!();
-            error!("Could not exclude \"{}\", no matching check found with that name.", exclusion);
-            fail!("Consult a spoiler log for a list of valid check names.");
+            return Err(crate::Error::unknown_check(format!(
+                "Could not exclude \"{}\", no matching check found with that name.\n\
+                Consult a spoiler log for a list of valid check names.",
+                exclusion
+            )));
         }
     }
+
+    Ok(())
+}
+
+/// Handle Priorities
+///
+/// Expands the user's priorities into the full list of checks that must hold progression. Subregions stand for each of
+/// their checks, other than those already excluded.
+fn handle_priorities(seed_info: &mut SeedInfo, check_map: &CheckMap) -> crate::Result<()> {
+    let mut full_priorities = BTreeSet::new();
+
+    for priority in &seed_info.settings.user_priorities {
+        if check_map.contains_key(priority) {
+            full_priorities.insert(priority.to_owned());
+            continue;
+        }
+
+        let subregion_checks = seed_info
+            .world_graph
+            .values()
+            .flat_map(|node| node.clone().get_checks().iter().flatten().cloned().collect::<Vec<_>>())
+            .filter(|check| check.get_location_info().is_some_and(|info| info.region() == priority))
+            .map(|check| check.get_name().to_owned())
+            .filter(|check_name| check_map.contains_key(check_name) && !seed_info.is_excluded(check_name))
+            .collect::<Vec<_>>();
+
+        if subregion_checks.is_empty() {
+            return Err(crate::Error::unknown_check(format!(
+                "Could not prioritize \"{}\", no matching check or region found with that name.\n\
+                Consult a spoiler log for a list of valid check and region names.",
+                priority
+            )));
+        }
+
+        full_priorities.extend(subregion_checks);
+    }
+
+    full_priorities.retain(|check_name| match check_map.get(check_name) {
+        Some(Some(item)) if !is_priority_item(*item) => {
+            println!();
+            warn!("Other settings prevented prioritizing: \"{}\"\n", check_name);
+            false
+        },
+        _ => true,
+    });
+
+    seed_info.full_priorities = full_priorities;
+
+    Ok(())
 }
 
 // Statically place an item in a given location, then remove it from the item pool provided
 fn place_static(check_map: &mut CheckMap, pool: &mut Pool, item: Item, check_name: &str) {
     check_map.insert(check_name.to_owned(), Some(item.into()));
-    pool.retain(|x| *x != item);
+    remove_first(pool, &item);
 }
 
 /// Super dirty mapping I hate it
 fn build_layout(SeedInfo { layout, world_graph, .. }: &mut SeedInfo, check_map: &mut CheckMap) -> Result<(), Error> {
     for location_node in world_graph.values() {
-        for check in location_node.clone().get_checks().iter().flatten().collect::<Vec<&Check>>() {
+        for check in location_node.get_checks().iter().flatten() {
             if let Some(loc_info) = check.get_location_info() {
                 let item = check_map.get(check.get_name()).unwrap().unwrap();
                 layout.set(loc_info, item);
@@ -408,33 +487,39 @@ fn is_dungeon_item(item: Item) -> bool {
             | Item::EasternKeyBig
             | Item::EasternKeySmall01
             | Item::EasternKeySmall02
+            | Item::EasternKeyRing
             | Item::GalesCompass
             | Item::GalesKeyBig
             | Item::GalesKeySmall01
             | Item::GalesKeySmall02
             | Item::GalesKeySmall03
             | Item::GalesKeySmall04
+            | Item::GalesKeyRing
             | Item::HeraCompass
             | Item::HeraKeyBig
             | Item::HeraKeySmall01
             | Item::HeraKeySmall02
+            | Item::HeraKeyRing
             | Item::DarkCompass
             | Item::DarkKeyBig
             | Item::DarkKeySmall01
             | Item::DarkKeySmall02
             | Item::DarkKeySmall03
             | Item::DarkKeySmall04
+            | Item::DarkKeyRing
             | Item::SwampCompass
             | Item::SwampKeyBig
             | Item::SwampKeySmall01
             | Item::SwampKeySmall02
             | Item::SwampKeySmall03
             | Item::SwampKeySmall04
+            | Item::SwampKeyRing
             | Item::SkullCompass
             | Item::SkullKeyBig
             | Item::SkullKeySmall01
             | Item::SkullKeySmall02
             | Item::SkullKeySmall03
+            | Item::SkullKeyRing
             | Item::ThievesCompass
             | Item::ThievesKeyBig
             | Item::ThievesKeySmall
@@ -443,6 +528,7 @@ fn is_dungeon_item(item: Item) -> bool {
             | Item::IceKeySmall01
             | Item::IceKeySmall02
             | Item::IceKeySmall03
+            | Item::IceKeyRing
             | Item::DesertCompass
             | Item::DesertKeyBig
             | Item::DesertKeySmall01
@@ -450,21 +536,24 @@ fn is_dungeon_item(item: Item) -> bool {
             | Item::DesertKeySmall03
             | Item::DesertKeySmall04
             | Item::DesertKeySmall05
+            | Item::DesertKeyRing
             | Item::TurtleCompass
             | Item::TurtleKeyBig
             | Item::TurtleKeySmall01
             | Item::TurtleKeySmall02
             | Item::TurtleKeySmall03
+            | Item::TurtleKeyRing
             | Item::LoruleCastleCompass
             | Item::LoruleCastleKeySmall01
             | Item::LoruleCastleKeySmall02
             | Item::LoruleCastleKeySmall03
             | Item::LoruleCastleKeySmall04
             | Item::LoruleCastleKeySmall05
+            | Item::LoruleCastleKeyRing
     )
 }
 
-fn fill_junk(rng: &mut StdRng, check_map: &mut CheckMap, junk_items: &mut Pool) {
+fn fill_junk(rng: &mut StdRng, check_map: &mut CheckMap, junk_items: &mut Pool) -> crate::Result<()> {
     info!("Placing Junk Items...");
 
     let mut empty_check_keys = Vec::new();
@@ -475,19 +564,21 @@ fn fill_junk(rng: &mut StdRng, check_map: &mut CheckMap, junk_items: &mut Pool)
     }
 
     if empty_check_keys.len() != junk_items.len() {
-        fail!(
+        return Err(crate::Error::internal(format!(
             "Number of empty checks: {} does not match available junk items: {}",
             empty_check_keys.len(),
             junk_items.len()
-        );
+        )));
     }
 
     for junk in junk_items {
         check_map.insert(empty_check_keys.remove(rng.gen_range(0..empty_check_keys.len())), Some((*junk).into()));
     }
+
+    Ok(())
 }
 
-fn filter_checks(item: Item, checks: &[Check], check_map: &mut CheckMap) -> Vec<Check> {
+fn filter_checks(seed_info: &SeedInfo, item: Item, checks: &[Check], check_map: &mut CheckMap) -> Vec<Check> {
     // Filter out non-empty checks
     let mut filtered_checks =
         checks.iter().filter(|&x| check_map.get(x.get_name()).unwrap().is_none()).cloned().collect::<Vec<_>>();
@@ -496,149 +587,99 @@ fn filter_checks(item: Item, checks: &[Check], check_map: &mut CheckMap) -> Vec<
     if is_dungeon_prize(item) {
         filtered_checks = filter_dungeon_prize_checks(&filtered_checks);
     } else if is_dungeon_item(item) {
-        let is_keysanity = false; // No keysanity yet, hardcode to false
-        if !is_keysanity {
-            filtered_checks = filter_dungeon_checks(item, filtered_checks);
-        }
+        filtered_checks.retain(|check| is_dungeon_item_allowed(seed_info, item, check));
     }
 
     filtered_checks
 }
 
-fn filter_dungeon_prize_checks(eligible_checks: &[Check]) -> Vec<Check> {
-    let dungeon_prize_checks = [
-        "[EP] Prize", "[HG] Prize", "[TH] Prize", "[PD] Prize", "[SP] Prize", "[SW] Prize", "[TT] Prize", "[TR] Prize",
-        "[DP] Prize", "[IR] Prize",
-    ];
+/// Whether the dungeon `item` may be placed on the `check`, per the shuffle setting of its category.
+fn is_dungeon_item_allowed(SeedInfo { settings, .. }: &SeedInfo, item: Item, check: &Check) -> bool {
+    let check_name = check.get_name();
+    if dungeons::needs_pickup_flag(settings, item) && !dungeons::has_pickup_flag(check_name) {
+        return false;
+    }
 
-    eligible_checks
-        .iter()
-        .filter_map(|check| if dungeon_prize_checks.contains(&check.get_name()) { Some(*check) } else { None })
-        .collect()
+    let item_dungeon = Dungeon::of_item(item);
+    let check_dungeon = Dungeon::of_check(check_name);
+
+    match dungeon_item_shuffle(settings, item) {
+        DungeonItems::OwnDungeon => check_dungeon.is_some() && check_dungeon == item_dungeon,
+        DungeonItems::AnyDungeon => check_dungeon.is_some(),
+        DungeonItems::OwnWorld => {
+            let is_hyrule_item = item_dungeon.is_some_and(Dungeon::is_hyrule);
+            match check_dungeon {
+                Some(dungeon) => dungeon.is_hyrule() == is_hyrule_item,
+                None => check.get_location_info().is_some_and(|info| match info.world() {
+                    World::Hyrule => is_hyrule_item,
+                    World::Lorule => !is_hyrule_item,
+                    World::Dungeons => false,
+                }),
+            }
+        },
+        DungeonItems::Overworld => {
+            check_dungeon.is_none() && check.get_location_info().is_some_and(|info| info.world() != World::Dungeons)
+        },
+        DungeonItems::Anywhere => true,
+    }
+}
+
+/// Whether the `item` is a dungeon item that may be placed outside its own dungeon.
+pub(crate) fn is_shuffled_dungeon_item(settings: &Settings, item: Item) -> bool {
+    is_dungeon_item(item) && dungeon_item_shuffle(settings, item).is_shuffled()
 }
 
-fn filter_dungeon_checks(item: Item, eligible_checks: Vec<Check>) -> Vec<Check> {
+/// The shuffle setting that applies to the given dungeon `item`.
+fn dungeon_item_shuffle(settings: &Settings, item: Item) -> DungeonItems {
     use Item::*;
+    match item {
+        // The game credits a Compass to the dungeon it is found in, which only its own dungeon's map would show
+        EasternCompass | GalesCompass | HeraCompass | DarkCompass | SwampCompass | SkullCompass | ThievesCompass
+        | IceCompass | DesertCompass | TurtleCompass | LoruleCastleCompass => DungeonItems::OwnDungeon,
+        EasternKeyBig | GalesKeyBig | HeraKeyBig | DarkKeyBig | SwampKeyBig | SkullKeyBig | ThievesKeyBig
+        | IceKeyBig | DesertKeyBig | TurtleKeyBig => settings.big_key_shuffle,
+        _ => settings.small_key_shuffle,
+    }
+}
 
-    let dungeon_checks = match item {
-        HyruleSanctuaryKey => vec!["[HS] Entrance", "[HS] Ledge", "[HS] Lower Chest", "[HS] Upper Chest"],
-        LoruleSanctuaryKey => vec!["[LS] Entrance Chest", "[LS] Ledge", "[LS] Lower Chest", "[LS] Upper Chest"],
-        EasternCompass | EasternKeyBig | EasternKeySmall01 | EasternKeySmall02 => vec![
-            "[EP] (1F) Merge Chest", "[EP] (1F) Left Door Chest", "[EP] (1F) Popo Room", "[EP] (1F) Secret Room",
-            "[EP] (1F) Switch Room", "[EP] (2F) Ball Room", "[EP] (2F) Defeat Popos", "[EP] (2F) Switch Room",
-            "[EP] (2F) Big Chest", "[EP] Yuga (1)", "[EP] Yuga (2)", "[EP] (3F) Escape Chest",
-            "[EP] (1F) Escape Chest",
-        ],
-        GalesCompass | GalesKeyBig | GalesKeySmall01 | GalesKeySmall02 | GalesKeySmall03 | GalesKeySmall04 => vec![
-            "[HG] (1F) Torches", "[HG] (1F) Switch Room", "[HG] (1F) Fire Bubbles", "[HG] (1F) West Room",
-            "[HG] (1F) West Room Secret", "[HG] (2F) Big Chest", "[HG] (2F) Narrow Ledge", "[HG] (2F) Fire Ring",
-            "[HG] (3F) Rat Room", "[HG] (3F) Fire Bubbles", "[HG] Margomill",
-        ],
-        HeraCompass | HeraKeyBig | HeraKeySmall01 | HeraKeySmall02 => vec![
-            "[TH] (1F) Outside", "[TH] (1F) Center", "[TH] (3F) Platform", "[TH] (5F) Red/Blue Switches",
-            "[TH] (6F) Left Mole", "[TH] (6F) Right Mole", "[TH] (7F) Outside (Ledge)", "[TH] (8F) Fairy Room",
-            "[TH] (11F) Big Chest", "[TH] Moldorm",
-        ],
-        DarkCompass | DarkKeyBig | DarkKeySmall01 | DarkKeySmall02 | DarkKeySmall03 | DarkKeySmall04 => vec![
-            "[PD] (1F) Right Pit", "[PD] (1F) Left Pit", "[PD] (1F) Switch Puzzle", "[PD] (1F) Hidden Room (Upper)",
-            "[PD] (1F) Hidden Room (Lower)", "[PD] (B1) Fall From 1F", "[PD] (B1) Glow-in-the-Dark Maze",
-            "[PD] (B1) Helmasaur Room", "[PD] (B1) Helmasaur Room (Fall)", "[PD] (2F) Big Chest (Hidden)",
-            "[PD] (2F) Alcove", "[PD] (1F) Fall From 2F", "[PD] (2F) South Hidden Room", "[PD] (B1) Bomb Bowling",
-            "[PD] Gemesaur King",
-        ],
-        SwampCompass | SwampKeyBig | SwampKeySmall01 | SwampKeySmall02 | SwampKeySmall03 | SwampKeySmall04 => vec![
-            "[SP] (B1) Center", "[SP] (B1) Raft Room (Left)", "[SP] (B1) Raft Room (Right)",
-            "[SP] (B1) Raft Room (Pillar)", "[SP] (B1) Gyorm", "[SP] (B1) Waterfall Room",
-            "[SP] (B1) Big Chest (Secret)", "[SP] (1F) Water Puzzle", "[SP] (1F) East Room", "[SP] (1F) West Room",
-            "[SP] (1F) Big Chest (Fire)", "[SP] Arrghus",
-        ],
-        SkullCompass | SkullKeyBig | SkullKeySmall01 | SkullKeySmall02 | SkullKeySmall03 => vec![
-            "[SW] (B1) Gibdo Room (Lower)", "[SW] (B1) South Chest", "[SW] (B1) Gibdo Room (Hole)",
-            "[SW] (B1) Grate Room", "[SW] (B2) Moving Platform Room", "[SW] (B1) Big Chest (Upper)",
-            // "[SW] Outdoor Chest",
-            "[SW] (B1) Big Chest (Eyes)", "[SW] Knucklemaster",
-        ],
-        ThievesCompass | ThievesKeyBig | ThievesKeySmall => vec![
-            "[TT] (B1) Jail Cell", "[TT] (B1) Grate Chest", "[TT] (B2) Grate Chest (Fall)",
-            "[TT] (B2) Switch Puzzle Room", "[TT] (B2) Jail Cell", "[TT] (B2) Eyegores", "[TT] (B1) Behind Wall",
-            "[TT] (B1) Big Chest (Entrance)", "[TT] (B3) Underwater",
-            "[TT] (B3) Big Chest (Hidden)",
-            // "[TT] Stalblind",
-        ],
-        IceCompass | IceKeyBig | IceKeySmall01 | IceKeySmall02 | IceKeySmall03 => vec![
-            "[IR] (1F) Hidden Chest", "[IR] (B3) Grate Chest (Left)", "[IR] (B3) Grate Chest (Right)",
-            "[IR] (B2) Ice Pillar", "[IR] (B5) Big Chest", "[IR] (B1) East Chest", "[IR] (B1) Narrow Ledge",
-            "[IR] (B1) Upper Chest", "[IR] (B3) Big Chest (Puzzle)", "[IR] (B4) Switches",
-            "[IR] (B4) Southwest Chest (Fall)", "[IR] (B4) Narrow Platform", "[IR] (B2) Long Merge Chest",
-            "[IR] (B4) Southeast Chest (Fall)", "[IR] Dharkstare",
-        ],
-        DesertCompass | DesertKeyBig | DesertKeySmall01 | DesertKeySmall02 | DesertKeySmall03 | DesertKeySmall04
-        | DesertKeySmall05 => vec![
-            "[DP] (1F) Entrance", "[DP] (1F) Sand Room (South)", "[DP] (1F) Sand Switch Room",
-            "[DP] (1F) Sand Room (North)", "[DP] (1F) Behind Rocks", "[DP] (1F) Big Chest (Behind Wall)",
-            "[DP] (2F) Under Rock (Left)", "[DP] (2F) Beamos Room", "[DP] (2F) Under Rock (Right)",
-            "[DP] (2F) Under Rock (Ball Room)", "[DP] (2F) Big Chest (Puzzle)", "[DP] (2F) Red/Blue Switches",
-            "[DP] (2F) Leever Room", "[DP] (3F) Behind Falling Sand", "[DP] (3F) Armos Room",
-            // "[DP] Zaganaga",
-        ],
-        TurtleCompass | TurtleKeyBig | TurtleKeySmall01 | TurtleKeySmall02 | TurtleKeySmall03 => vec![
-            "[TR] (1F) Center", "[TR] (1F) Grate Chest", "[TR] (1F) Northwest Room", "[TR] (1F) Northeast Ledge",
-            "[TR] (1F) Southeast Chest", "[TR] (1F) Defeat Flamolas",
-            // "[TR] Left Balcony",
-            "[TR] (1F) Under Center", "[TR] (B1) Under Center", "[TR] (B1) Northeast Room", "[TR] (B1) Platform",
-            "[TR] (B1) Grate Chest (Small)", "[TR] (B1) Big Chest (Center)", "[TR] (B1) Big Chest (Top)",
-            "[TR] Grinexx",
-        ],
-        LoruleCastleCompass
-        | LoruleCastleKeySmall01
-        | LoruleCastleKeySmall02
-        | LoruleCastleKeySmall03
-        | LoruleCastleKeySmall04
-        | LoruleCastleKeySmall05 => vec![
-            "[LC] (1F) Ledge", "[LC] (1F) Center", "[LC] (2F) Near Torches", "[LC] (2F) Hidden Path",
-            "[LC] (2F) Ledge", "[LC] (4F) Center", "[LC] (4F) Hidden Path", "[LC] Bomb Trial (1)",
-            "[LC] Bomb Trial (2)", "[LC] Tile Trial (1)", "[LC] Tile Trial (2)", "[LC] Lamp Trial",
-            "[LC] Hook Trial (1)", "[LC] Hook Trial (2)",
-            // "[LC] Zelda",
-        ],
-
-        _ => {
-            fail!("Item {:?} is not a dungeon item", item);
-        },
-    };
+fn filter_dungeon_prize_checks(eligible_checks: &[Check]) -> Vec<Check> {
+    let dungeon_prize_checks = [
+        "[EP] Prize", "[HG] Prize", "[TH] Prize", "[PD] Prize", "[SP] Prize", "[SW] Prize", "[TT] Prize", "[TR] Prize",
+        "[DP] Prize", "[IR] Prize",
+    ];
 
     eligible_checks
         .iter()
-        .filter_map(|check| if dungeon_checks.contains(&check.get_name()) { Some(*check) } else { None })
+        .filter_map(|check| if dungeon_prize_checks.contains(&check.get_name()) { Some(check.clone()) } else { None })
         .collect()
 }
 
-fn exist_empty_reachable_check(checks: &Vec<Check>, check_map: &mut CheckMap) -> bool {
-    for check in checks {
-        match check_map.get(check.get_name()).unwrap() {
-            None => {
-                return true;
-            },
-            Some(_) => {},
-        }
-    }
-
-    false
-}
-
 /// Prefills a map with all checks as defined by the world graph with no values yet assigned
-pub fn prefill_check_map(world_graph: &mut WorldGraph) -> CheckMap {
+///
+/// Also verifies the integrity of the world graph: check names must be unique, and every path must lead to a defined
+/// location. Paths through shuffled transitions are checked when resolved instead.
+pub fn prefill_check_map(world_graph: &WorldGraph) -> crate::Result<CheckMap> {
     let mut check_map: DashMap<_, _> = Default::default();
 
-    for location_node in world_graph.values_mut() {
-        for check in location_node.clone().get_checks().iter().flatten().collect::<Vec<&Check>>() {
+    for location_node in world_graph.values() {
+        for check in location_node.get_checks().iter().flatten() {
             if check_map.insert(check.get_name().to_owned(), check.get_quest()).is_some() {
-                fail!("Multiple checks have duplicate name: {}", check.get_name());
+                return Err(crate::Error::internal(format!(
+                    "Multiple checks have duplicate name: {}",
+                    check.get_name()
+                )));
+            }
+        }
+
+        let destinations = location_node.get_paths().iter().flatten().map(|path| path.get_destination());
+        for destination in destinations.filter_map(Destination::fixed) {
+            if !world_graph.contains_key(&destination) {
+                return Err(crate::Error::internal(format!("Location Undefined: {:?}", destination)));
             }
         }
     }
 
-    check_map
+    Ok(check_map)
 }
 
 /// This translation is probably adding unnecessary overhead, oh well
@@ -656,52 +697,68 @@ fn build_progress_from_items<'s>(items: &Pool, seed_info: &'s SeedInfo) -> Progr
 /// randomization resulting in a layout that renders certain locations inaccessible.
 fn verify_all_locations_accessible(
     seed_info: &SeedInfo, check_map: &mut CheckMap, progression_pool: &mut Pool,
-) -> Result<(), Error> {
+) -> crate::Result<()> {
     if LogicMode::NoLogic.eq(&seed_info.settings.logic_mode) {
         return Ok(()); // Skip this check on No Logic
     }
 
     info!("Verifying all locations accessible...");
-    let reachable_checks = assumed_search(seed_info, progression_pool, check_map); //find_reachable_checks(loc_map, &everything, &mut check_map); //
-
-    const STANDARD_CHECKS: usize = 264;
-    const MAIAMAI: usize = 100;
-    const DUNGEON_PRIZES: usize = 10;
-    const STATIC_ITEMS: usize = 20;
-    const PROGRESSION_EVENTS: usize = 36; // "Progression Events" (non-item checks that are still progression)
-    const WEATHER_VANES: usize = 22;
-    const HINT_GHOSTS_OW: usize = 58; // Hint Ghosts (Overworld)
-
-    // (2 Golden Bees for 9,999 Rupees NOT included)
-    const IN_LOGIC_CHECKS: usize = STANDARD_CHECKS + MAIAMAI + DUNGEON_PRIZES + STATIC_ITEMS;
-
-    /// Total count of expected, reachable checks
-    const EXPECTED_REACHABLE: usize = IN_LOGIC_CHECKS + PROGRESSION_EVENTS + WEATHER_VANES + HINT_GHOSTS_OW;
-
-    if reachable_checks.len() != EXPECTED_REACHABLE {
-        // let reachable_check_names: Vec<&str> = reachable_checks.iter().map(|c| c.get_name()).collect();
-        // for check in check_map.keys() {
-        //     if reachable_check_names.contains(&check.as_str()) {
-        //         // info!("Reachable Check: {}", check);
-        //     } else {
-        //         if !check.contains("Crack") {
-        //             info!("Unreachable Check: {}", check);
-        //         }
-        //     }
-        // }
-
-        Err(Error::new(format!(
-            "Only {}/{} checks were reachable in the world graph",
-            reachable_checks.len(),
-            EXPECTED_REACHABLE
-        )))
-    } else {
-        Ok(())
+    let reachable_checks = assumed_search(seed_info, progression_pool, check_map)?;
+
+    let reachable = reachable_checks.iter().map(Check::get_name).collect::<BTreeSet<_>>();
+    let expected = expected_reachable_checks(seed_info);
+
+    if reachable == expected {
+        return Ok(());
     }
+
+    let reason = describe_unexpected_reachability(&reachable, &expected);
+
+    Err(unbeatable(seed_info, progression_pool, check_map, reason, None))
+}
+
+/// Describes how the `reachable` checks differ from the `expected` ones, listing the checks that couldn't be reached
+/// and those that were reached despite having no logic for the logic mode.
+fn describe_unexpected_reachability(reachable: &BTreeSet<&str>, expected: &BTreeSet<&str>) -> String {
+    let mut problems = Vec::new();
+
+    let missing = expected.difference(reachable).copied().collect::<Vec<_>>();
+    if !missing.is_empty() {
+        problems.push(format!(
+            "Only {}/{} checks were reachable in the world graph\nMissing: {}",
+            expected.len() - missing.len(),
+            expected.len(),
+            missing.join(", ")
+        ));
+    }
+
+    let extra = reachable.difference(expected).copied().collect::<Vec<_>>();
+    if !extra.is_empty() {
+        problems.push(format!(
+            "{} checks without logic for this logic mode were reachable in the world graph\nUnexpected: {}",
+            extra.len(),
+            extra.join(", ")
+        ));
+    }
+
+    problems.join("\n")
+}
+
+/// The names of every check in the world graph that should be reachable with all progression under the current logic
+/// mode, which is every check with logic defined for it. Checks that are permanently out of logic, like the Golden Bees
+/// sold for 9,999 Rupees, are left out.
+fn expected_reachable_checks(SeedInfo { world_graph, settings, .. }: &SeedInfo) -> BTreeSet<&'static str> {
+    world_graph
+        .values()
+        .flat_map(|location_node| location_node.get_checks().iter().flatten())
+        .filter(|check| check.get_logic().is_defined(settings.logic_mode))
+        .map(Check::get_name)
+        .collect()
 }
 
 /// Find all checks reachable with the given Progress
-pub(crate) fn find_reachable_checks(SeedInfo { world_graph, .. }: &SeedInfo, progress: &Progress) -> Vec<Check> {
+pub(crate) fn find_reachable_checks(seed_info: &SeedInfo, progress: &Progress) -> crate::Result<Vec<Check>> {
+    let transitions = seed_info.transitions();
     let start_node = Location::RavioShop;
     let mut loc_queue: Queue<Location> = Queue::from(vec![start_node]);
     let mut visited: HashSet<Location> = HashSet::new();
@@ -712,24 +769,22 @@ pub(crate) fn find_reachable_checks(SeedInfo { world_graph, .. }: &SeedInfo, pro
     while !loc_queue.is_empty() {
         let location = loc_queue.dequeue().unwrap();
 
-        // Grab the location from the map, verify it is defined
-        let location_node = match world_graph.get(&location) {
-            Some(loc) => loc,
-            None => {
-                fail!("Location Undefined: {:?}", location);
-            },
-        };
+        // Grab the location from the map, already verified to be defined by prefill_check_map()
+        let location_node = seed_info
+            .world_graph
+            .get(&location)
+            .ok_or_else(|| crate::Error::internal(format!("Location Undefined: {:?}", location)))?;
 
         // Iterate over the location's checks
-        for check in location_node.clone().get_checks().iter().flatten().collect::<Vec<&Check>>() {
+        for check in location_node.get_checks().iter().flatten() {
             if check.can_access(progress) {
-                reachable_checks.push(*check);
+                reachable_checks.push(check.clone());
             }
         }
 
         // Queue new paths reachable from this location
-        for path in location_node.clone().get_paths().iter().flatten().collect::<Vec<&Path>>() {
-            let destination = path.get_destination();
+        for path in location_node.get_paths().iter().flatten() {
+            let destination = transitions.resolve(path.get_destination());
             if !visited.contains(&destination) && path.can_access(progress) {
                 loc_queue.queue(destination).expect("TODO: panic message");
                 visited.insert(destination);
@@ -737,7 +792,7 @@ pub(crate) fn find_reachable_checks(SeedInfo { world_graph, .. }: &SeedInfo, pro
         }
     }
 
-    reachable_checks
+    Ok(reachable_checks)
 }
 
 pub(crate) fn get_items_from_reachable_checks<'s>(
@@ -746,26 +801,41 @@ pub(crate) fn get_items_from_reachable_checks<'s>(
     let mut progress = Progress::new(seed_info);
 
     for check in reachable_checks {
-        // Items already placed in the world that can be picked up
-        let placed_item = check_map.get(check.get_name()).unwrap();
-        match placed_item {
-            None => {},
-            Some(item) => match item {
-                Vane(vane) => progress.add_item(*seed_info.vane_map.get(vane).unwrap()),
-                _ => progress.add_item(*item),
-            },
+        for item in get_items_from_check(seed_info, check, check_map) {
+            progress.add_item(item);
         }
+    }
 
-        // Quest items that will always be at a given check
-        if let Some(quest) = check.get_quest() {
-            match quest {
-                Vane(vane) => progress.add_item(*seed_info.vane_map.get(&vane).unwrap()),
-                _ => progress.add_item(quest),
+    progress
+}
+
+/// The starting items and every item at the `reachable_checks`, as a set listing them in the order that Hint Ghosts
+/// have always been chosen in, which a [`Progress`] doesn't keep.
+pub(crate) fn get_item_set_from_reachable_checks(
+    seed_info: &SeedInfo, reachable_checks: &[Check], check_map: &CheckMap,
+) -> DashSet<Randomizable> {
+    let mut items = Progress::starting_items(&seed_info.settings);
+    for check in reachable_checks {
+        for item in get_items_from_check(seed_info, check, check_map) {
+            // Even inserting an item already in the set can grow it, which changes the order
+            if !items.contains(&item) {
+                items.insert(item);
             }
         }
     }
+    items
+}
 
-    progress
+/// The items that can be picked up at a `check`.
+fn get_items_from_check<'s>(
+    seed_info: &'s SeedInfo, check: &Check, check_map: &CheckMap,
+) -> impl Iterator<Item = Randomizable> + 's {
+    // Items already placed in the world, and quest items that will always be at a given check
+    let placed_item = *check_map.get(check.get_name()).unwrap();
+    [placed_item, check.get_quest()].into_iter().flatten().map(|item| match item {
+        Vane(vane) => (*seed_info.vane_map.get(&vane).unwrap()).into(),
+        _ => item,
+    })
 }
 
 /// The Assumed Fill algorithm
@@ -789,28 +859,75 @@ fn assumed_fill(
 ) -> crate::Result<()> {
     info!("Placing Progression Items...");
 
-    let mut reachable_checks = assumed_search(seed_info, items_owned, check_map);
+    plando::prioritize_restricted_items(seed_info, items_owned);
+
     let mut major_maiamai_items = 0;
 
-    while exist_empty_reachable_check(&reachable_checks, check_map) && !items_owned.is_empty() {
+    // Searched anew for every item, as the item placed last may have opened up more checks. Stopping once the checks
+    // found for the previous item are full would silently leave the remaining items out of the seed.
+    while !items_owned.is_empty() {
         let item = items_owned.remove(0);
 
-        reachable_checks = assumed_search(seed_info, items_owned, check_map);
+        let reachable_checks = assumed_search(seed_info, items_owned, check_map)?;
 
-        let filtered_checks = filter_checks(item, &reachable_checks, check_map);
+        let mut filtered_checks = filter_checks(seed_info, item, &reachable_checks, check_map);
+        let regions = plando::filter_restricted_checks(seed_info, item, &mut filtered_checks);
+        filter_priority_checks(seed_info, item, &mut filtered_checks);
 
         if filtered_checks.is_empty() {
             // info!("item:            {:?}", item);
             // info!("filtered_checks: {:?}", filtered_checks);
             // info!("check_map:       {:?}", check_map);
 
-            return Err(crate::Error::game(format!("No reachable checks found to place: {:?}", item)));
+            let reason = match regions {
+                Some(regions) => format!(
+                    "No reachable checks found in {} to place: {:?}",
+                    regions.iter().cloned().collect::<Vec<_>>().join(", "),
+                    item
+                ),
+                None => format!("No reachable checks found to place: {:?}", item),
+            };
+            return Err(unbeatable(seed_info, items_owned, check_map, reason, Some(item)));
         }
 
         let chosen_check_name = place_item_randomly(item, &filtered_checks, check_map, rng);
         handle_maiamai_limit(item.into(), chosen_check_name, &mut major_maiamai_items, seed_info, check_map, junk);
     }
 
+    verify_priorities(seed_info, check_map)
+}
+
+/// Whether the `item` counts as progression for the priority checks.
+fn is_priority_item(item: Randomizable) -> bool {
+    item.is_major_item() || matches!(item, Randomizable::Item(item) if is_dungeon_prize(item) || is_dungeon_item(item))
+}
+
+/// Narrows the `checks` for a progression `item` down to the empty priority checks, if any are available. Any other
+/// item is kept off the priority checks, unless there is nowhere else to put it.
+fn filter_priority_checks(seed_info: &SeedInfo, item: Item, checks: &mut Vec<Check>) {
+    if seed_info.full_priorities.is_empty() {
+        return;
+    }
+
+    let is_priority = |check: &Check| seed_info.full_priorities.contains(check.get_name());
+    let wants_priority = is_priority_item(item.into());
+
+    if checks.iter().any(|check| is_priority(check) == wants_priority) {
+        checks.retain(|check| is_priority(check) == wants_priority);
+    }
+}
+
+/// Verifies every priority check ended up with progression.
+fn verify_priorities(seed_info: &SeedInfo, check_map: &CheckMap) -> crate::Result<()> {
+    for check_name in &seed_info.full_priorities {
+        if !check_map.get(check_name).copied().flatten().is_some_and(is_priority_item) {
+            return Err(crate::Error::unbeatable(format!(
+                "No progression item could be placed on priority check: \"{}\"",
+                check_name
+            )));
+        }
+    }
+
     Ok(())
 }
 
@@ -858,26 +975,252 @@ fn handle_maiamai_limit(
 ///
 /// A loop is performed to expand the considered items to include not just the `items_owned` but
 /// also all items already placed that are reachable with the currently considered items, until
-/// all such items have been exhausted.
+/// all such items have been exhausted. Each round only expands the [`Frontier`] of the previous one.
 ///
-fn assumed_search(seed_info: &SeedInfo, items_owned: &Pool, check_map: &mut CheckMap) -> Vec<Check> {
+/// The reachable checks are returned in the order of a fresh search with all considered items, so placements don't
+/// depend on the order items were found in.
+fn assumed_search(seed_info: &SeedInfo, items_owned: &Pool, check_map: &mut CheckMap) -> crate::Result<Vec<Check>> {
+    let (_, considered_items) = expand_assumed_frontier(seed_info, items_owned, check_map)?;
+    find_reachable_checks(seed_info, &considered_items)
+}
+
+/// Expands a [`Frontier`] from the `items_owned` until no more placed items are found, returning it along with every
+/// item considered to be held.
+fn expand_assumed_frontier<'s>(
+    seed_info: &'s SeedInfo, items_owned: &Pool, check_map: &CheckMap,
+) -> crate::Result<(Frontier<'s>, Progress<'s>)> {
     let mut considered_items = build_progress_from_items(items_owned, seed_info);
-    let mut reachable_checks: Vec<Check>;
+    let mut frontier = Frontier::new(seed_info);
 
     loop {
-        reachable_checks = find_reachable_checks(seed_info, &considered_items);
-        let reachable_items = get_items_from_reachable_checks(seed_info, &reachable_checks, check_map);
+        let mut found_new_items = false;
 
-        let new_items = reachable_items.difference(&considered_items);
+        for check in frontier.expand(&considered_items)? {
+            for item in get_items_from_check(seed_info, &check, check_map) {
+                if !considered_items.has(item) {
+                    considered_items.add_item(item);
+                    found_new_items = true;
+                }
+            }
+        }
 
-        if new_items.is_empty() {
-            break;
+        if !found_new_items {
+            return Ok((frontier, considered_items));
         }
+    }
+}
 
-        for new_item in new_items {
-            considered_items.add_item(new_item);
+/// Builds an [`Unbeatable`] error for the `reason`, carrying a [`FailureReport`] of an assumed search with the
+/// `items_owned`.
+///
+/// [`Unbeatable`]: crate::ErrorKind::Unbeatable
+fn unbeatable(
+    seed_info: &SeedInfo, items_owned: &Pool, check_map: &CheckMap, reason: String, placing: Option<Item>,
+) -> crate::Error {
+    match expand_assumed_frontier(seed_info, items_owned, check_map) {
+        Ok((frontier, considered_items)) => {
+            crate::Error::unbeatable(FailureReport::new(seed_info, reason, placing, &frontier, &considered_items))
+        },
+        Err(err) => err,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use modinfo::Settings;
+    use std::collections::BTreeMap;
+
+    fn settings(user_priorities: &[&str], user_exclusions: &[&str]) -> Settings {
+        Settings {
+            user_priorities: user_priorities.iter().map(|&check| check.to_owned()).collect(),
+            user_exclusions: user_exclusions.iter().map(|&check| check.to_owned()).collect(),
+            ..Default::default()
+        }
+    }
+
+    /// Every item placed in the `layout`, keyed by check name.
+    fn placed_items(layout: &crate::Layout) -> BTreeMap<&'static str, Randomizable> {
+        [&layout.hyrule, &layout.lorule, &layout.dungeons]
+            .into_iter()
+            .flat_map(|category| category.values().flat_map(|region| region.iter().map(|(&name, &item)| (name, item))))
+            .collect()
+    }
+
+    #[test]
+    fn places_progression_on_priorities() {
+        // The Southern Ruins' Crack and Maiamai are placed by other settings, and the Pillar Cave is excluded
+        let expected = ["Bird Lover", "Dampe", "Flippers Mini-Dungeon", "Runaway Item Seller", "Southern Ruins Ledge"];
+
+        for seed in 1..=3 {
+            let settings = settings(&["Dampe", "Bird Lover", "Southern Ruins"], &["Southern Ruins Pillar Cave"]);
+            let seed_info = crate::generate_seed_info(seed, settings).unwrap();
+            let placed = placed_items(&seed_info.layout);
+
+            assert_eq!(seed_info.full_priorities, expected.into_iter().map(str::to_owned).collect());
+            for check_name in expected {
+                let item = placed[check_name];
+                assert!(is_priority_item(item), "{} holds {}", check_name, item.as_str());
+            }
+        }
+    }
+
+    #[test]
+    fn places_dungeon_items_where_allowed() {
+        use DungeonItems::*;
+
+        for mode in [OwnDungeon, AnyDungeon, OwnWorld, Overworld, Anywhere] {
+            let settings = Settings { small_key_shuffle: mode, big_key_shuffle: mode, ..Default::default() };
+            let seed_info = crate::generate_seed_info(2, settings).unwrap();
+            let layout = &seed_info.layout;
+
+            // Keysy is off, so every dungeon's Small Keys or Key Ring, Big Key and Compass is placed
+            let expected: usize = Dungeon::ALL
+                .into_iter()
+                .map(|dungeon| {
+                    let key_ring = dungeon.key_ring().filter(|_| seed_info.settings.has_key_rings());
+                    let small_keys = if key_ring.is_some() { 1 } else { dungeon.small_keys().len() };
+                    small_keys + dungeon.big_key().iter().len() + dungeon.compass().iter().len()
+                })
+                .sum();
+
+            let mut placed = 0;
+            for (world, category) in
+                [(World::Hyrule, &layout.hyrule), (World::Lorule, &layout.lorule), (World::Dungeons, &layout.dungeons)]
+            {
+                for (&check_name, &item) in category.values().flat_map(|region| region.iter()) {
+                    let Some(item_dungeon) = item.as_item().and_then(Dungeon::of_item) else {
+                        continue;
+                    };
+                    let check_dungeon = Dungeon::of_check(check_name);
+                    let is_compass = item_dungeon.compass() == item.as_item();
+                    let item_mode = if is_compass { OwnDungeon } else { mode };
+                    let allowed = match item_mode {
+                        OwnDungeon => check_dungeon == Some(item_dungeon),
+                        AnyDungeon => check_dungeon.is_some(),
+                        OwnWorld => match check_dungeon {
+                            Some(dungeon) => dungeon.is_hyrule() == item_dungeon.is_hyrule(),
+                            None => (world == World::Hyrule) == item_dungeon.is_hyrule() && world != World::Dungeons,
+                        },
+                        Overworld => world != World::Dungeons,
+                        Anywhere => true,
+                    };
+                    assert!(allowed, "{:?}: {} on {}", mode, item.as_str(), check_name);
+
+                    if item_mode.is_shuffled() {
+                        assert!(dungeons::has_pickup_flag(check_name), "{} on {}", item.as_str(), check_name);
+                    }
+                    placed += 1;
+                }
+            }
+            assert_eq!(placed, expected, "{:?}", mode);
+        }
+    }
+
+    #[test]
+    fn starting_items_are_not_placed() {
+        let starting_items =
+            [(StartingItem::PegasusBoots, 1), (StartingItem::Lamp, 2), (StartingItem::HeartContainer, 2)].into();
+        let settings = Settings { super_items: true, starting_items, ..Default::default() };
+        let seed_info = crate::generate_seed_info(1, settings).unwrap();
+        let placed = placed_items(&seed_info.layout);
+
+        for item in [Item::PegasusBoots, Item::Lamp01, Item::Lamp02, Item::HeartContainer01, Item::HeartContainer02] {
+            assert!(placed.values().all(|&placed| placed != item.into()), "{} was placed", item.as_str());
         }
+        assert!(placed.values().any(|&placed| placed == Item::HeartContainer03.into()));
     }
 
-    reachable_checks
+    #[test]
+    fn fails_rather_than_drop_items_when_checks_run_out() {
+        use rand::SeedableRng;
+
+        let seed_info = crate::generate_world_graph(1, Settings::default()).unwrap();
+        let mut check_map = prefill_check_map(&seed_info.world_graph).unwrap();
+
+        // Leave a single check empty, reachable without any items
+        let reachable_checks = assumed_search(&seed_info, &vec![], &mut check_map).unwrap();
+        let open_check = reachable_checks.iter().find(|check| check_map[check.get_name()].is_none()).unwrap();
+        for (check_name, item) in check_map.iter_mut() {
+            if item.is_none() && check_name != open_check.get_name() {
+                *item = Some(Item::RupeeGreen.into());
+            }
+        }
+
+        let mut items_owned = vec![Item::Bell, Item::HintGlasses];
+        let rng = &mut StdRng::seed_from_u64(1);
+        assert!(assumed_fill(rng, &seed_info, &mut check_map, &mut items_owned, &mut vec![]).is_err());
+        assert_eq!(check_map[open_check.get_name()], Some(Item::Bell.into()));
+    }
+
+    #[test]
+    fn places_items_as_before_for_the_same_seed() {
+        use game::ghosts::HintGhost::*;
+        use Item::*;
+
+        // Pinned from before the search became incremental. A different search order moves nearly every item and
+        // changes the ghosts picked for the Path Hints, so the equipment and the Path Hints are enough to notice.
+        let seed_info = crate::generate_seed_info(11, Settings::default()).unwrap();
+        let placed = placed_items(&seed_info.layout);
+        for (check_name, item) in [
+            ("Bee Guy (1)", Charm),
+            ("Death Mountain Fairy Cave", Sword04),
+            ("Dodge the Cuccos", SmoothGem),
+            ("Donkey Cave", Bow01),
+            ("Fire Cave Pillar", Pouch),
+            ("Fortune-Teller", Mail01),
+            ("Hinox (4)", Sword03),
+            ("Lake Hylia Dark Cave", RaviosBracelet02),
+            ("Lorule Mountain W Ledge", Glove01),
+            ("Maiamai Sand Rod Upgrade", Hammer01),
+            ("Maiamai Tornado Rod Upgrade", Boomerang01),
+            ("Queen Oren", PremiumMilk),
+            ("Ravio's Shop (5)", SandRod01),
+            ("Ravio's Shop (7)", Flippers),
+            ("Ravio's Shop (8)", Bombs01),
+            ("Rosso Cave", Mail02),
+            ("Rupee Rush (Hyrule)", TornadoRod01),
+            ("Southern Ruins Ledge", Sword02),
+            ("Southern Ruins Pillar Cave", Lamp01),
+            ("Street Merchant (Right)", IceRod01),
+            ("Swamp Cave (Right)", Bottle03),
+            ("Thief Girl", FireRod01),
+            ("Treacherous Tower", HintGlasses),
+        ] {
+            assert_eq!(placed[check_name], item.into(), "{}", check_name);
+        }
+
+        let path_hints = seed_info.hints.path_hints.iter().map(|hint| (hint.check.get_name(), hint.ghosts.clone()));
+        assert_eq!(path_hints.collect::<Vec<_>>(), [
+            ("Ravio's Shop (7)", vec![LostWoodsMaze1, DarkPalaceOutside]),
+            ("Ravio's Shop (5)", vec![TurtleWall, LostWoodsMaze2]),
+            ("Rupee Rush (Hyrule)", vec![Letter, SkullWoodsCuccos]),
+            ("[HG] Margomill", vec![ZorasDomain, GraveyardHyrule]),
+            ("Ravio's Shop (8)", vec![Well, WaterfallCave]),
+            ("[HS] Entrance", vec![HyruleCastleRocks, VacantHouse]),
+            ("Lorule Mountain W Ledge", vec![FireCave, StylishWoman]),
+        ]);
+    }
+
+    #[test]
+    fn describes_only_the_reachability_problems_found() {
+        let set = |checks: &[&'static str]| checks.iter().copied().collect::<BTreeSet<_>>();
+
+        let missing = describe_unexpected_reachability(&set(&["A"]), &set(&["A", "B"]));
+        assert_eq!(missing, "Only 1/2 checks were reachable in the world graph\nMissing: B");
+
+        let extra = describe_unexpected_reachability(&set(&["A", "B", "C"]), &set(&["A"]));
+        assert!(extra.starts_with("2 checks without logic for this logic mode were reachable"), "{}", extra);
+        assert!(extra.ends_with("Unexpected: B, C"), "{}", extra);
+
+        let both = describe_unexpected_reachability(&set(&["A", "C"]), &set(&["A", "B"]));
+        assert_eq!(both.lines().filter(|line| line.starts_with("Missing: ")).count(), 1);
+        assert_eq!(both.lines().filter(|line| line.starts_with("Unexpected: ")).count(), 1);
+    }
+
+    #[test]
+    fn rejects_unknown_priorities() {
+        let err = crate::generate_seed_info(1, settings(&["Nowhere"], &[])).unwrap_err();
+        assert!(err.to_string().contains("Could not prioritize \"Nowhere\""));
+    }
 }
@@ -1,26 +1,80 @@
-use crate::filler::{location::Location, logic::Logic, progress::Progress};
-use serde::Serialize;
+use crate::filler::{
+    cracks::Crack, dungeon_entrances::DungeonEntrance, location::Location, logic::Logic, progress::Progress,
+};
+use serde::{Serialize, Serializer};
+use std::fmt::{Display, Formatter};
 
-#[derive(Copy, Clone, Debug, Serialize)]
+#[derive(Clone, Debug, Serialize)]
 pub struct Path {
-    destination: Location,
+    destination: Destination,
     logic: Logic,
 }
 
 impl Path {
-    pub fn new(destination: Location, logic: Logic) -> Self {
+    pub fn new(destination: Destination, logic: Logic) -> Self {
         Self { destination, logic }
     }
 
-    pub fn get_destination(self) -> Location {
+    /// Where the path leads, which for a shuffled transition must be resolved with the seed's [`Transitions`].
+    ///
+    /// [`Transitions`]: crate::world::Transitions
+    pub fn get_destination(&self) -> Destination {
         self.destination
     }
 
-    pub fn get_logic(&self) -> Logic {
-        self.logic
+    pub fn get_logic(&self) -> &Logic {
+        &self.logic
     }
 
-    pub fn can_access(self, progress: &Progress) -> bool {
+    pub fn can_access(&self, progress: &Progress) -> bool {
         self.logic.can_access(progress)
     }
 }
+
+/// The destination of a [`Path`]: either a fixed [`Location`], or a transition shuffled by the seed.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Destination {
+    Location(Location),
+    /// Through the left side of a Crack, coming out of the right side of the Crack it's paired with.
+    CrackLeft(Crack),
+    /// Through the right side of a Crack, coming out of the left side of the Crack it's paired with.
+    CrackRight(Crack),
+    /// Through a dungeon entrance door, into whichever dungeon it leads to.
+    DungeonEntrance(DungeonEntrance),
+    /// Out of a dungeon's main entrance, to the outside of whichever door leads to it.
+    DungeonExit(DungeonEntrance),
+}
+
+impl Destination {
+    /// The Location, if the destination doesn't depend on the seed.
+    pub fn fixed(self) -> Option<Location> {
+        match self {
+            Destination::Location(location) => Some(location),
+            _ => None,
+        }
+    }
+}
+
+/// Formats the destination as in logic files and [`LogicOverrides`] edge keys, e.g. `crack_left HyruleCastle`.
+///
+/// [`LogicOverrides`]: modinfo::settings::LogicOverrides
+impl Display for Destination {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Destination::Location(location) => write!(f, "{:?}", location),
+            Destination::CrackLeft(crack) => write!(f, "crack_left {:?}", crack),
+            Destination::CrackRight(crack) => write!(f, "crack_right {:?}", crack),
+            Destination::DungeonEntrance(entrance) => write!(f, "dungeon_entrance {:?}", entrance),
+            Destination::DungeonExit(entrance) => write!(f, "dungeon_exit {:?}", entrance),
+        }
+    }
+}
+
+impl Serialize for Destination {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
@@ -0,0 +1,291 @@
+use crate::filler::check::Check;
+use crate::filler::filler_item::Item;
+use crate::filler::item_pools::Pool;
+use crate::regions::World;
+use crate::{CheckMap, SeedInfo};
+use modinfo::settings::logic::LogicMode;
+use std::collections::BTreeSet;
+
+const WORLDS: [World; 3] = [World::Hyrule, World::Lorule, World::Dungeons];
+
+/// Places the items the user forced onto specific checks, taking them from the progression pool or, failing that, the
+/// junk pool.
+pub(crate) fn place_forced_items(
+    SeedInfo { settings, .. }: &SeedInfo, check_map: &mut CheckMap, progression: &mut Pool, junk: &mut Pool,
+) -> crate::Result<()> {
+    for (check_name, item_name) in &settings.plando.checks {
+        match check_map.get(check_name) {
+            None => {
+                return Err(crate::Error::unknown_check(format!(
+                    "Could not place \"{}\", no matching check found with the name \"{}\".",
+                    item_name, check_name
+                )));
+            },
+            Some(Some(existing)) => {
+                return Err(crate::Error::invalid_settings(format!(
+                    "Could not place \"{}\" on \"{}\", other settings already placed {} there.",
+                    item_name,
+                    check_name,
+                    existing.as_str()
+                )));
+            },
+            Some(None) => {},
+        }
+
+        let item = take_item(item_name, progression).or_else(|| take_item(item_name, junk)).ok_or_else(|| {
+            crate::Error::invalid_settings(format!(
+                "Could not place \"{}\" on \"{}\", no such item is left in the item pool.",
+                item_name, check_name
+            ))
+        })?;
+        check_map.insert(check_name.clone(), Some(item.into()));
+    }
+
+    Ok(())
+}
+
+/// Verifies the item names and regions of the user's region restrictions, given the full progression pool.
+///
+/// Only progression items can be restricted, as junk is placed without logic after everything else.
+pub(crate) fn validate_restrictions(
+    SeedInfo { settings, world_graph, .. }: &SeedInfo, progression: &Pool,
+) -> crate::Result<()> {
+    let region_names = world_graph
+        .values()
+        .flat_map(|node| node.clone().get_checks().iter().flatten().cloned().collect::<Vec<_>>())
+        .filter_map(|check| check.get_location_info())
+        .map(|info| info.region())
+        .collect::<BTreeSet<_>>();
+
+    for (item_name, regions) in &settings.plando.items {
+        if !progression.iter().any(|&item| matches_name(item, item_name)) {
+            return Err(crate::Error::invalid_settings(format!(
+                "Could not restrict \"{}\", no such item is in the progression item pool.",
+                item_name
+            )));
+        }
+
+        for region in regions {
+            if !region_names.contains(region.as_str()) && !WORLDS.iter().any(|&world| world_name(world) == region) {
+                return Err(crate::Error::invalid_settings(format!(
+                    "Could not restrict \"{}\" to \"{}\", no world or region found with that name.",
+                    item_name, region
+                )));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Verifies every item forced onto a check can be reached, assuming all unplaced `progression` items are available.
+///
+/// A forced item that can't be reached would lock itself (or something it depends on) away, so the filler can't fix
+/// the seed by trying again.
+pub(crate) fn verify_forced_items_reachable(
+    seed_info: &SeedInfo, check_map: &mut CheckMap, progression: &Pool,
+) -> crate::Result<()> {
+    if seed_info.settings.logic_mode == LogicMode::NoLogic || seed_info.settings.plando.checks.is_empty() {
+        return Ok(());
+    }
+
+    let reachable = super::assumed_search(seed_info, progression, check_map)?
+        .iter()
+        .map(|check| check.get_name())
+        .collect::<BTreeSet<_>>();
+
+    for (check_name, item_name) in &seed_info.settings.plando.checks {
+        if !reachable.contains(check_name.as_str()) {
+            return Err(crate::Error::invalid_settings(format!(
+                "The forced placements make the seed unbeatable: \"{}\" on \"{}\" can never be reached.",
+                item_name, check_name
+            )));
+        }
+    }
+
+    Ok(())
+}
+
+/// Moves items restricted to certain regions to the front of the pool, right after the Dungeon Prizes, so they are
+/// placed while their regions still have empty checks.
+pub(crate) fn prioritize_restricted_items(SeedInfo { settings, .. }: &SeedInfo, pool: &mut Pool) {
+    if settings.plando.items.is_empty() {
+        return;
+    }
+
+    pool.sort_by_key(|&item| {
+        if super::is_dungeon_prize(item) {
+            0
+        } else if restricted_regions(settings, item).is_some() {
+            1
+        } else {
+            2
+        }
+    });
+}
+
+/// Removes the checks outside the regions the `item` is restricted to, if any. Returns the allowed regions.
+pub(crate) fn filter_restricted_checks<'a>(
+    SeedInfo { settings, .. }: &'a SeedInfo, item: Item, checks: &mut Vec<Check>,
+) -> Option<&'a BTreeSet<String>> {
+    let regions = restricted_regions(settings, item)?;
+    checks.retain(|check| {
+        check.get_location_info().is_some_and(|info| {
+            regions.iter().any(|region| region == info.region() || region == world_name(info.world()))
+        })
+    });
+    Some(regions)
+}
+
+fn restricted_regions(settings: &modinfo::Settings, item: Item) -> Option<&BTreeSet<String>> {
+    settings.plando.items.iter().find(|(name, _)| matches_name(item, name)).map(|(_, regions)| regions)
+}
+
+/// Removes the first item matching `name` from the `pool`.
+fn take_item(name: &str, pool: &mut Pool) -> Option<Item> {
+    let index = pool.iter().position(|&item| matches_name(item, name))?;
+    Some(pool.remove(index))
+}
+
+/// Whether the `item` has the given name, as used by the spoiler log. The `+` of progressive items is optional.
+fn matches_name(item: Item, name: &str) -> bool {
+    item.as_str().trim_end_matches('+').eq_ignore_ascii_case(name.trim().trim_end_matches('+'))
+}
+
+fn world_name(world: World) -> &'static str {
+    match world {
+        World::Hyrule => "Hyrule",
+        World::Lorule => "Lorule",
+        World::Dungeons => "Dungeons",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::filler::filler_item::Randomizable;
+    use crate::filler::{item_pools, prefill_check_map};
+    use modinfo::settings::plando::Plando;
+    use modinfo::Settings;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    fn settings(checks: &[(&str, &str)], items: &[(&str, &[&str])]) -> Settings {
+        let plando = Plando {
+            checks: checks.iter().map(|&(check, item)| (check.to_owned(), item.to_owned())).collect(),
+            items: items
+                .iter()
+                .map(|&(item, regions)| (item.to_owned(), regions.iter().map(|&region| region.to_owned()).collect()))
+                .collect(),
+        };
+        Settings { plando, ..Default::default() }
+    }
+
+    fn setup(settings: Settings) -> (SeedInfo, CheckMap, Pool, Pool) {
+        let seed_info = crate::generate_world_graph(1, settings).unwrap();
+        let check_map = prefill_check_map(&seed_info.world_graph).unwrap();
+        let (progression, junk) = item_pools::get_item_pools(&mut StdRng::seed_from_u64(1), &seed_info);
+        (seed_info, check_map, progression, junk)
+    }
+
+    fn placed(check_map: &CheckMap, check_name: &str) -> Option<Randomizable> {
+        *check_map.get(check_name).unwrap()
+    }
+
+    #[test]
+    fn places_forced_items() {
+        let (seed_info, mut check_map, mut progression, mut junk) =
+            setup(settings(&[("Dampe", "zora's flippers"), ("Irene", "Bow"), ("Bird Lover", "Blue Rupee")], &[]));
+        let blue_rupees = junk.iter().filter(|&&item| item == Item::RupeeBlue).count();
+
+        place_forced_items(&seed_info, &mut check_map, &mut progression, &mut junk).unwrap();
+
+        assert_eq!(placed(&check_map, "Dampe"), Some(Item::Flippers.into()));
+        assert_eq!(placed(&check_map, "Irene"), Some(Item::Bow01.into()));
+        assert_eq!(placed(&check_map, "Bird Lover"), Some(Item::RupeeBlue.into()));
+        assert!(!progression.contains(&Item::Flippers));
+        assert!(!progression.contains(&Item::Bow01));
+        assert_eq!(junk.iter().filter(|&&item| item == Item::RupeeBlue).count(), blue_rupees - 1);
+    }
+
+    #[test]
+    fn rejects_invalid_forced_items() {
+        // Unknown check names are normally caught by settings validation, before the World Graph is built
+        for (check_name, item_name) in [("Nowhere", "Bow"), ("Dampe", "Nothing"), ("Dampe", "Hookshot")] {
+            let (mut seed_info, mut check_map, mut progression, mut junk) = setup(Default::default());
+            seed_info.settings = settings(&[(check_name, item_name)], &[]);
+            progression.retain(|&item| item != Item::Hookshot01);
+
+            assert!(place_forced_items(&seed_info, &mut check_map, &mut progression, &mut junk).is_err());
+        }
+
+        let (seed_info, mut check_map, mut progression, mut junk) = setup(settings(&[("Dampe", "Bow")], &[]));
+        check_map.insert("Dampe".to_owned(), Some(Item::Lamp01.into()));
+        assert!(place_forced_items(&seed_info, &mut check_map, &mut progression, &mut junk).is_err());
+    }
+
+    #[test]
+    fn rejects_unreachable_forced_items() {
+        // Bird Lover can only be reached with the Flippers, which would be locked behind itself
+        let (seed_info, mut check_map, mut progression, mut junk) =
+            setup(settings(&[("Bird Lover", "Zora's Flippers")], &[]));
+        place_forced_items(&seed_info, &mut check_map, &mut progression, &mut junk).unwrap();
+
+        let err = verify_forced_items_reachable(&seed_info, &mut check_map, &progression).unwrap_err();
+        assert!(err.to_string().contains("\"Zora's Flippers\" on \"Bird Lover\" can never be reached"));
+    }
+
+    #[test]
+    fn accepts_reachable_forced_items() {
+        let (seed_info, mut check_map, mut progression, mut junk) =
+            setup(settings(&[("Bird Lover", "Pegasus Boots")], &[]));
+        place_forced_items(&seed_info, &mut check_map, &mut progression, &mut junk).unwrap();
+
+        verify_forced_items_reachable(&seed_info, &mut check_map, &progression).unwrap();
+    }
+
+    #[test]
+    fn filters_restricted_checks() {
+        let (seed_info, ..) = setup(settings(&[], &[("Hookshot", &["Eastern Palace", "Lorule"])]));
+        let all_checks = seed_info
+            .world_graph
+            .values()
+            .flat_map(|node| node.clone().get_checks().iter().flatten().cloned().collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+
+        let mut checks = all_checks.clone();
+        assert!(filter_restricted_checks(&seed_info, Item::Hookshot02, &mut checks).is_some());
+        assert!(!checks.is_empty());
+        assert!(checks.iter().all(|check| {
+            let info = check.get_location_info().unwrap();
+            info.region() == "Eastern Palace" || info.world() == World::Lorule
+        }));
+
+        let mut checks = all_checks.clone();
+        assert!(filter_restricted_checks(&seed_info, Item::Lamp01, &mut checks).is_none());
+        assert_eq!(checks.len(), all_checks.len());
+    }
+
+    #[test]
+    fn rejects_invalid_restrictions() {
+        for (item_name, region) in [("Hookshot", "Nowhere"), ("Nothing", "Hyrule"), ("Blue Rupee", "Hyrule")] {
+            let (seed_info, _, progression, _) = setup(settings(&[], &[(item_name, &[region])]));
+            assert!(validate_restrictions(&seed_info, &progression).is_err());
+        }
+    }
+
+    #[test]
+    fn places_restricted_items_in_their_regions() {
+        let seed_info =
+            crate::generate_seed_info(1, settings(&[("Dampe", "Lamp")], &[("Hookshot", &["Lorule"])])).unwrap();
+
+        let lamp = seed_info.layout.find_single(Item::Lamp01).or_else(|| seed_info.layout.find_single(Item::Lamp02));
+        assert_eq!(lamp.map(|(_, check_name)| check_name), Some("Dampe"));
+
+        let hookshots = [&seed_info.layout.hyrule, &seed_info.layout.lorule, &seed_info.layout.dungeons]
+            .map(|category| {
+                category.values().flat_map(|region| region.values()).filter(|item| item.as_str() == "Hookshot+").count()
+            });
+        assert_eq!(hookshots, [0, 1, 0]);
+    }
+}
@@ -1,8 +1,10 @@
+use crate::filler::cracks::Crack;
 use crate::filler::filler_item::Item::Quake;
 use crate::filler::filler_item::Vane;
 use crate::filler::filler_item::{Goal, Item, Randomizable};
 use crate::filler::item_pools;
 use crate::{DashSet, SeedInfo};
+use game::ghosts::HintGhost;
 use modinfo::settings::cracks::Cracks;
 use modinfo::settings::cracksanity::Cracksanity;
 use modinfo::settings::keysy::Keysy;
@@ -11,31 +13,59 @@ use modinfo::settings::ravios_shop::RaviosShop;
 use modinfo::settings::trials_door::TrialsDoor;
 use modinfo::settings::weather_vanes::WeatherVanes;
 use modinfo::settings::{pedestal::PedestalSetting, Settings};
+use strum::IntoEnumIterator;
 
+/// The items the player has collected, as far as logic is concerned.
+///
+/// Items are kept in a bitset, as logic checks for items far more often than items are added or listed.
 #[derive(Clone, Debug)]
 pub struct Progress<'s> {
-    items: DashSet<Randomizable>,
+    bits: ItemBits,
     seed_info: &'s SeedInfo,
 }
 
 impl<'s> Progress<'s> {
+    /// Constructs a new [`Progress`] instance that has only the player's starting items.
     pub fn new(seed_info: &'s SeedInfo) -> Progress<'s> {
-        Self { items: Default::default(), seed_info }
+        Self::from_items(Self::starting_items(&seed_info.settings), seed_info)
+    }
+
+    fn from_items(items: DashSet<Randomizable>, seed_info: &'s SeedInfo) -> Progress<'s> {
+        let mut bits = ItemBits::default();
+        for item in items {
+            bits.insert(item);
+        }
+        Self { bits, seed_info }
     }
 
     /// Constructs a new [`Progress`] instance that already has all minor progression items.
     pub fn nothing_but_hearts_and_rupees(seed_info: &'s SeedInfo) -> Progress<'s> {
-        let mut items = DashSet::default();
-        items.extend(item_pools::get_heart_pieces().iter().map(|&i| i.into()).collect::<DashSet<Randomizable>>());
-        items.extend(item_pools::get_heart_containers().iter().map(|&i| i.into()).collect::<DashSet<Randomizable>>());
+        let mut items = Self::starting_items(&seed_info.settings);
+        let settings = &seed_info.settings;
+        items.extend(item_pools::get_heart_piece_pool(settings).into_iter().map(Randomizable::from));
+        items.extend(item_pools::get_heart_container_pool(settings).into_iter().map(Randomizable::from));
         items.extend(item_pools::get_gold_rupee_pool().iter().map(|&i| i.into()).collect::<DashSet<Randomizable>>());
         items.extend(item_pools::get_silver_rupee_pool().iter().map(|&i| i.into()).collect::<DashSet<Randomizable>>());
         items.extend(item_pools::get_purple_rupee_pool().iter().map(|&i| i.into()).collect::<DashSet<Randomizable>>());
-        Self { items, seed_info }
+        Self::from_items(items, seed_info)
+    }
+
+    /// The items the player starts the game with, which [`item_pools`] leaves out of the pools.
+    pub(crate) fn starting_items(settings: &Settings) -> DashSet<Randomizable> {
+        let mut items = DashSet::default();
+        if settings.start_with_merge {
+            items.insert(Item::RaviosBracelet01.into());
+            items.insert(Item::RaviosBracelet02.into());
+        }
+        if settings.start_with_pouch {
+            items.insert(Item::Pouch.into());
+        }
+        items.extend(item_pools::get_starting_items(settings).into_iter().map(Randomizable::from));
+        items
     }
 
-    pub fn get_items(&self) -> &DashSet<Randomizable> {
-        &self.items
+    pub fn get_items(&self) -> impl Iterator<Item = Randomizable> + '_ {
+        self.bits.iter()
     }
 
     pub fn get_settings(&self) -> &Settings {
@@ -43,15 +73,15 @@ impl<'s> Progress<'s> {
     }
 
     pub fn add_item(&mut self, item: impl Into<Randomizable>) {
-        self.items.insert(item.into());
+        self.bits.insert(item.into());
     }
 
     pub fn difference(&self, other: &Progress) -> DashSet<Randomizable> {
         let mut new_items: DashSet<Randomizable> = Default::default();
 
-        for item in &self.items {
-            if !other.items.contains(item) {
-                new_items.insert(*item);
+        for item in self.get_items() {
+            if !other.has(item) {
+                new_items.insert(item);
             }
         }
 
@@ -59,15 +89,15 @@ impl<'s> Progress<'s> {
     }
 
     pub fn has(&self, item: impl Into<Randomizable>) -> bool {
-        self.items.contains(&item.into())
+        self.bits.contains(item.into())
     }
 
     fn has_either(&self, item1: impl Into<Randomizable>, item2: impl Into<Randomizable>) -> bool {
-        self.items.contains(&item1.into()) || self.items.contains(&item2.into())
+        self.has(item1) || self.has(item2)
     }
 
     fn has_both(&self, item1: impl Into<Randomizable>, item2: impl Into<Randomizable>) -> bool {
-        self.items.contains(&item1.into()) && self.items.contains(&item2.into())
+        self.has(item1) && self.has(item2)
     }
 
     fn has_any<T>(&self, items: impl IntoIterator<Item = T>) -> bool
@@ -95,8 +125,7 @@ impl<'s> Progress<'s> {
     {
         let mut sum: u8 = 0;
         for item in items {
-            // fixme expensive clone
-            if self.clone().has(item.into()) {
+            if self.has(item) {
                 sum += 1;
             }
         }
@@ -294,7 +323,7 @@ impl<'s> Progress<'s> {
     }
 
     pub fn can_merge(&self) -> bool {
-        self.seed_info.settings.start_with_merge || self.has_both(Item::RaviosBracelet01, Item::RaviosBracelet02)
+        self.has_both(Item::RaviosBracelet01, Item::RaviosBracelet02)
     }
 
     pub fn has_mail(&self) -> bool {
@@ -556,7 +585,9 @@ impl<'s> Progress<'s> {
     }
 
     pub fn has_eastern_keys(&self, amount: u8) -> bool {
-        self.is_small_keysy() || self.has_amount(amount, [Item::EasternKeySmall01, Item::EasternKeySmall02])
+        self.is_small_keysy()
+            || self.has(Item::EasternKeyRing)
+            || self.has_amount(amount, [Item::EasternKeySmall01, Item::EasternKeySmall02])
     }
 
     pub fn has_eastern_big_key(&self) -> bool {
@@ -565,6 +596,7 @@ impl<'s> Progress<'s> {
 
     pub fn has_gales_keys(&self, amount: u8) -> bool {
         self.is_small_keysy()
+            || self.has(Item::GalesKeyRing)
             || self.has_amount(
                 amount,
                 [Item::GalesKeySmall01, Item::GalesKeySmall02, Item::GalesKeySmall03, Item::GalesKeySmall04],
@@ -576,7 +608,9 @@ impl<'s> Progress<'s> {
     }
 
     pub fn has_hera_keys(&self, amount: u8) -> bool {
-        self.is_small_keysy() || self.has_amount(amount, [Item::HeraKeySmall01, Item::HeraKeySmall02])
+        self.is_small_keysy()
+            || self.has(Item::HeraKeyRing)
+            || self.has_amount(amount, [Item::HeraKeySmall01, Item::HeraKeySmall02])
     }
 
     pub fn has_hera_big_key(&self) -> bool {
@@ -585,6 +619,7 @@ impl<'s> Progress<'s> {
 
     pub fn has_dark_keys(&self, amount: u8) -> bool {
         self.is_small_keysy()
+            || self.has(Item::DarkKeyRing)
             || self.has_amount(
                 amount,
                 [Item::DarkKeySmall01, Item::DarkKeySmall02, Item::DarkKeySmall03, Item::DarkKeySmall04],
@@ -597,6 +632,7 @@ impl<'s> Progress<'s> {
 
     pub fn has_swamp_keys(&self, amount: u8) -> bool {
         self.is_small_keysy()
+            || self.has(Item::SwampKeyRing)
             || self.has_amount(
                 amount,
                 [Item::SwampKeySmall01, Item::SwampKeySmall02, Item::SwampKeySmall03, Item::SwampKeySmall04],
@@ -609,6 +645,7 @@ impl<'s> Progress<'s> {
 
     pub fn has_skull_keys(&self, amount: u8) -> bool {
         self.is_small_keysy()
+            || self.has(Item::SkullKeyRing)
             || self.has_amount(amount, [Item::SkullKeySmall01, Item::SkullKeySmall02, Item::SkullKeySmall03])
     }
 
@@ -630,6 +667,7 @@ impl<'s> Progress<'s> {
 
     pub fn has_ice_keys(&self, amount: u8) -> bool {
         self.is_small_keysy()
+            || self.has(Item::IceKeyRing)
             || self.has_amount(amount, [Item::IceKeySmall01, Item::IceKeySmall02, Item::IceKeySmall03])
     }
 
@@ -639,6 +677,7 @@ impl<'s> Progress<'s> {
 
     pub fn has_desert_keys(&self, amount: u8) -> bool {
         self.is_small_keysy()
+            || self.has(Item::DesertKeyRing)
             || self.has_amount(
                 amount,
                 [
@@ -657,6 +696,7 @@ impl<'s> Progress<'s> {
 
     pub fn has_turtle_keys(&self, amount: u8) -> bool {
         self.is_small_keysy()
+            || self.has(Item::TurtleKeyRing)
             || self.has_amount(amount, [Item::TurtleKeySmall01, Item::TurtleKeySmall02, Item::TurtleKeySmall03])
     }
 
@@ -666,6 +706,7 @@ impl<'s> Progress<'s> {
 
     pub fn has_lorule_keys(&self, amount: u8) -> bool {
         self.is_small_keysy()
+            || self.has(Item::LoruleCastleKeyRing)
             || self.has_amount(
                 amount,
                 [
@@ -882,3 +923,46 @@ impl<'s> Progress<'s> {
         self.has(vane)
     }
 }
+
+/// A growable set of [`Randomizable`]s, with one bit for each.
+#[derive(Clone, Debug, Default)]
+struct ItemBits(Vec<u64>);
+
+impl ItemBits {
+    /// Interleaves the kinds of [`Randomizable`], so the bit index only depends on the variant's discriminant.
+    fn index(item: Randomizable) -> usize {
+        const KINDS: usize = 5;
+        match item {
+            Randomizable::Item(item) => item as usize * KINDS,
+            Randomizable::Goal(goal) => goal as usize * KINDS + 1,
+            Randomizable::HintGhost(ghost) => ghost as usize * KINDS + 2,
+            Randomizable::Vane(vane) => vane as usize * KINDS + 3,
+            Randomizable::Crack(crack) => crack as usize * KINDS + 4,
+        }
+    }
+
+    fn insert(&mut self, item: Randomizable) {
+        let index = Self::index(item);
+        let (word, bit) = (index / u64::BITS as usize, index % u64::BITS as usize);
+        if word >= self.0.len() {
+            self.0.resize(word + 1, 0);
+        }
+        self.0[word] |= 1 << bit;
+    }
+
+    fn contains(&self, item: Randomizable) -> bool {
+        let index = Self::index(item);
+        let (word, bit) = (index / u64::BITS as usize, index % u64::BITS as usize);
+        self.0.get(word).is_some_and(|&bits| bits & (1 << bit) != 0)
+    }
+
+    /// The items in the set, found by testing every possible [`Randomizable`].
+    fn iter(&self) -> impl Iterator<Item = Randomizable> + '_ {
+        let items = Item::iter().map(Randomizable::from);
+        let goals = Goal::iter().map(Randomizable::from);
+        let ghosts = HintGhost::iter().map(Randomizable::from);
+        let vanes = Vane::iter().map(Randomizable::from);
+        let cracks = Crack::iter().map(Randomizable::from);
+        items.chain(goals).chain(ghosts).chain(vanes).chain(cracks).filter(|&item| self.contains(item))
+    }
+}
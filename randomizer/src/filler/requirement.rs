@@ -0,0 +1,575 @@
+use serde::de::{value, Deserialize, IntoDeserializer};
+use std::{
+    fmt::{self, Display, Formatter},
+    iter::Peekable,
+    str::CharIndices,
+    sync::Arc,
+};
+
+use crate::filler::{filler_item::Vane, progress::Progress};
+
+/// A requirement for accessing a [`Check`] or [`Path`], parsed from a logic expression such as
+/// `can_merge && (has_bow || has_eastern_keys(2))`.
+///
+/// Expressions are made of helpers, `true`, `false`, `!`, `&&`, `||` and parentheses. Built-in helpers are the
+/// [`Progress`] methods of the same name, named helpers are other expressions defined in the logic files.
+///
+/// `!` may only negate settings: logic never requires *not* having an item, which the fill relies on.
+///
+/// [`Check`]: crate::filler::check::Check
+/// [`Path`]: crate::filler::path::Path
+#[derive(Clone, Debug)]
+pub enum Requirement {
+    Always,
+    Never,
+    Helper(Helper),
+    Not(Arc<Requirement>),
+    All(Arc<[Requirement]>),
+    Any(Arc<[Requirement]>),
+}
+
+impl Requirement {
+    /// Parses a logic expression, looking up any name that isn't a built-in helper with `named`.
+    pub fn parse<F>(expression: &str, named: &mut F) -> Result<Self, String>
+    where
+        F: FnMut(&str) -> Result<Option<Requirement>, String>,
+    {
+        tokenize(expression)
+            .and_then(|tokens| Parser { tokens, position: 0, named }.parse())
+            .map_err(|err| format!("{} in \"{}\"", err, expression))
+    }
+
+    pub fn is_met(&self, progress: &Progress) -> bool {
+        match self {
+            Self::Always => true,
+            Self::Never => false,
+            Self::Helper(helper) => helper.is_met(progress),
+            Self::Not(requirement) => !requirement.is_met(progress),
+            Self::All(requirements) => requirements.iter().all(|requirement| requirement.is_met(progress)),
+            Self::Any(requirements) => requirements.iter().any(|requirement| requirement.is_met(progress)),
+        }
+    }
+
+    /// Whether meeting the requirement depends on the items collected, rather than only on the settings.
+    fn depends_on_items(&self) -> bool {
+        match self {
+            Self::Always | Self::Never => false,
+            Self::Helper(helper) => !helper.is_setting(),
+            Self::Not(requirement) => requirement.depends_on_items(),
+            Self::All(requirements) | Self::Any(requirements) => requirements.iter().any(Self::depends_on_items),
+        }
+    }
+
+    /// Writes the requirement, wrapped in parentheses if it binds looser than the surrounding `&&` or `!`.
+    fn fmt_nested(&self, f: &mut Formatter<'_>, parent: &Self) -> fmt::Result {
+        let needs_parentheses =
+            matches!((parent, self), (Self::All(_), Self::Any(_)) | (Self::Not(_), Self::All(_) | Self::Any(_)));
+
+        if needs_parentheses {
+            write!(f, "({})", self)
+        } else {
+            write!(f, "{}", self)
+        }
+    }
+}
+
+impl Display for Requirement {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Always => f.write_str("true"),
+            Self::Never => f.write_str("false"),
+            Self::Helper(helper) => write!(f, "{}", helper),
+            Self::Not(requirement) => {
+                f.write_str("!")?;
+                requirement.fmt_nested(f, self)
+            },
+            Self::All(requirements) | Self::Any(requirements) => {
+                let operator = if matches!(self, Self::All(_)) { " && " } else { " || " };
+                for (i, requirement) in requirements.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(operator)?;
+                    }
+                    requirement.fmt_nested(f, self)?;
+                }
+                Ok(())
+            },
+        }
+    }
+}
+
+impl Helper {
+    /// Whether the helper only reads the settings, and never the items collected.
+    fn is_setting(self) -> bool {
+        matches!(
+            self,
+            Self::cracksanity | Self::swordless_mode | Self::progression_enemies | Self::not_nice_mode | Self::lampless
+        )
+    }
+}
+
+/// An argument to a built-in helper, e.g. the `2` of `has_eastern_keys(2)`.
+trait HelperArg: Sized {
+    fn parse_arg(arg: &str) -> Result<Self, String>;
+
+    fn fmt_arg(&self, f: &mut Formatter<'_>) -> fmt::Result;
+}
+
+macro_rules! number_arg {
+    ($($number:ty),+) => {
+        $(impl HelperArg for $number {
+            fn parse_arg(arg: &str) -> Result<Self, String> {
+                arg.parse().map_err(|_| format!("Invalid number \"{}\"", arg))
+            }
+
+            fn fmt_arg(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                write!(f, "{}", self)
+            }
+        })+
+    };
+}
+
+number_arg!(u8, u16, f32);
+
+impl HelperArg for Vane {
+    fn parse_arg(arg: &str) -> Result<Self, String> {
+        Vane::deserialize(IntoDeserializer::<value::Error>::into_deserializer(arg))
+            .map_err(|_| format!("Unknown Weather Vane \"{}\"", arg))
+    }
+
+    fn fmt_arg(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+/// Declares the built-in helpers: first the [`Progress`] methods without arguments, then those with one.
+macro_rules! helpers {
+    ($($flag:ident,)+ ; $($counted:ident($arg:ty),)+) => {
+        /// A built-in helper, which calls the [`Progress`] method of the same name.
+        #[allow(non_camel_case_types)]
+        #[derive(Clone, Copy, Debug, PartialEq)]
+        pub enum Helper {
+            $($flag,)+
+            $($counted($arg),)+
+        }
+
+        impl Helper {
+            /// Parses a built-in helper, or returns `None` if there is no built-in helper with the `name`.
+            fn parse(name: &str, arg: Option<&str>) -> Option<Result<Self, String>> {
+                Some(match (name, arg) {
+                    $((stringify!($flag), None) => Ok(Self::$flag),)+
+                    $((stringify!($counted), Some(arg)) => <$arg>::parse_arg(arg).map(Self::$counted),)+
+                    $((stringify!($flag), Some(_)))|+ => Err(format!("{} doesn't take an argument", name)),
+                    $((stringify!($counted), None))|+ => Err(format!("{} needs an argument", name)),
+                    _ => return None,
+                })
+            }
+
+            /// Whether the `name` is taken by a built-in helper.
+            pub fn is_built_in(name: &str) -> bool {
+                matches!(name, $(stringify!($flag))|+ | $(stringify!($counted))|+)
+            }
+
+            fn is_met(self, progress: &Progress) -> bool {
+                match self {
+                    $(Self::$flag => progress.$flag(),)+
+                    $(Self::$counted(arg) => progress.$counted(arg),)+
+                }
+            }
+        }
+
+        impl Display for Helper {
+            fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                match self {
+                    $(Self::$flag => f.write_str(stringify!($flag)),)+
+                    $(Self::$counted(arg) => {
+                        write!(f, "{}(", stringify!($counted))?;
+                        arg.fmt_arg(f)?;
+                        f.write_str(")")
+                    },)+
+                }
+            }
+        }
+    };
+}
+
+helpers! {
+    has_lamp,
+    has_super_lamp,
+    has_net,
+    has_super_net,
+    has_bow,
+    has_nice_bow,
+    has_boomerang,
+    has_nice_boomerang,
+    has_hookshot,
+    has_nice_hookshot,
+    has_hammer,
+    has_nice_hammer,
+    has_bombs,
+    has_nice_bombs,
+    has_fire_rod,
+    has_nice_fire_rod,
+    has_ice_rod,
+    has_nice_ice_rod,
+    has_tornado_rod,
+    has_nice_tornado_rod,
+    has_sand_rod,
+    has_nice_sand_rod,
+    can_use_shield,
+    has_scoot_fruit,
+    has_foul_fruit,
+    has_fire_source,
+    can_destroy_curtain,
+    can_extinguish_torches,
+    has_bell,
+    are_hyrule_vanes_active,
+    are_lorule_vanes_active,
+    cracksanity,
+    can_escape,
+    can_escape_dungeon,
+    has_stamina_scroll,
+    has_bottle,
+    has_boots,
+    has_power_glove,
+    has_titans_mitt,
+    has_flippers,
+    are_cracks_open,
+    can_merge,
+    has_mail,
+    has_90_maiamai,
+    has_100_maiamai,
+    has_smooth_gem,
+    has_letter_in_a_bottle,
+    has_premium_milk,
+    has_gold_bee,
+    has_sword,
+    has_master_sword,
+    swordless_mode,
+    progression_enemies,
+    break_floor_tiles,
+    not_nice_mode,
+    lampless,
+    can_great_spin,
+    can_destroy_skull,
+    can_cut_grass,
+    can_attack,
+    can_attack_bowproof,
+    can_attack_fireproof,
+    can_attack_iceproof,
+    has_lamp_or_net_as_weapon,
+    has_lamp_as_weapon,
+    has_net_as_weapon,
+    can_hit_switch,
+    can_hit_switch_bootless,
+    can_hit_far_switch,
+    can_hit_shielded_switch,
+    can_hit_hog_1f_switch,
+    can_defeat_margomill,
+    can_defeat_moldorm,
+    can_defeat_yuga2,
+    can_defeat_gemesaur,
+    can_defeat_arrghus,
+    can_defeat_knucklemaster,
+    can_defeat_grinexx,
+    can_defeat_dharkstare,
+    has_sanctuary_key,
+    has_lorule_sanctuary_key,
+    has_eastern_compass,
+    has_eastern_big_key,
+    has_gales_big_key,
+    has_hera_big_key,
+    has_dark_big_key,
+    has_swamp_big_key,
+    has_skull_big_key,
+    has_thieves_key,
+    has_thieves_big_key,
+    has_ice_compass,
+    has_ice_big_key,
+    has_desert_big_key,
+    has_turtle_big_key,
+    has_completed_trials,
+    has_bow_of_light,
+    has_skull_eye_right,
+    has_skull_eyes,
+    thieves_b1_door_open,
+    thieves_b2_door_open,
+    thieves_b3_water_drained,
+    thieves_b1b2_doors_open,
+    thieves_escape_equipment,
+    adv_thieves_statue_clip,
+    hell_thieves_statue_clip,
+    can_rescue_turtles,
+    has_seen_ravio_signs,
+    is_ravio_shop_open,
+    has_bomb_flower,
+    has_shady_guy_trigger,
+    has_pendant_of_courage,
+    has_required_pendants,
+    has_sage_gulley,
+    has_sage_oren,
+    has_sage_seres,
+    has_sage_osfala,
+    has_sage_impa,
+    has_sage_irene,
+    has_sage_rosso,
+    has_lc_requirement,
+    has_yuganon_requirement,
+    has_saved_thief_girl,
+    has_opened_stylish_womans_house,
+    has_woman_roof_maiamai,
+    has_opened_sanctuary_doors,
+    can_access_milk_bar,
+    can_get_potion,
+    can_access_hyrule_blacksmith,
+    can_access_lorule_castle_field,
+    ;
+    hearts(f32),
+    has_rupees(u16),
+    has_master_ore(u8),
+    has_maiamai(u8),
+    has_eastern_keys(u8),
+    has_gales_keys(u8),
+    has_hera_keys(u8),
+    has_dark_keys(u8),
+    has_swamp_keys(u8),
+    has_skull_keys(u8),
+    has_ice_keys(u8),
+    has_desert_keys(u8),
+    has_turtle_keys(u8),
+    has_lorule_keys(u8),
+    has_weather_vane(Vane),
+}
+
+#[derive(Debug, PartialEq)]
+enum Token<'a> {
+    Word(&'a str),
+    And,
+    Or,
+    Not,
+    Open,
+    Close,
+}
+
+impl Display for Token<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Token::Word(word) => write!(f, "\"{}\"", word),
+            Token::And => f.write_str("\"&&\""),
+            Token::Or => f.write_str("\"||\""),
+            Token::Not => f.write_str("\"!\""),
+            Token::Open => f.write_str("\"(\""),
+            Token::Close => f.write_str("\")\""),
+        }
+    }
+}
+
+fn tokenize(expression: &str) -> Result<Vec<Token<'_>>, String> {
+    let mut tokens = Vec::new();
+    let mut chars: Peekable<CharIndices> = expression.char_indices().peekable();
+    let is_word_char = |c: char| c.is_ascii_alphanumeric() || c == '_' || c == '.';
+
+    while let Some((start, c)) = chars.next() {
+        let token = match c {
+            _ if c.is_whitespace() => continue,
+            '(' => Token::Open,
+            ')' => Token::Close,
+            '!' => Token::Not,
+            '&' | '|' if chars.next_if(|&(_, next)| next == c).is_some() => {
+                if c == '&' {
+                    Token::And
+                } else {
+                    Token::Or
+                }
+            },
+            _ if is_word_char(c) => {
+                let mut end = start + c.len_utf8();
+                while let Some((i, next)) = chars.next_if(|&(_, next)| is_word_char(next)) {
+                    end = i + next.len_utf8();
+                }
+                Token::Word(&expression[start..end])
+            },
+            _ => return Err(format!("Unexpected \"{}\"", c)),
+        };
+        tokens.push(token);
+    }
+
+    Ok(tokens)
+}
+
+/// Recursive descent parser, `&&` binds tighter than `||` and `!` tighter than both.
+struct Parser<'a, 'f, F> {
+    tokens: Vec<Token<'a>>,
+    position: usize,
+    named: &'f mut F,
+}
+
+impl<'a, F> Parser<'a, '_, F>
+where
+    F: FnMut(&str) -> Result<Option<Requirement>, String>,
+{
+    fn parse(&mut self) -> Result<Requirement, String> {
+        let requirement = self.any()?;
+        match self.next() {
+            None => Ok(requirement),
+            Some(token) => Err(format!("Unexpected {}", token)),
+        }
+    }
+
+    fn next(&mut self) -> Option<&Token<'a>> {
+        self.position += 1;
+        self.tokens.get(self.position - 1)
+    }
+
+    fn next_if(&mut self, token: Token) -> bool {
+        let matches = self.tokens.get(self.position) == Some(&token);
+        if matches {
+            self.position += 1;
+        }
+        matches
+    }
+
+    fn any(&mut self) -> Result<Requirement, String> {
+        let mut requirements = vec![self.all()?];
+        while self.next_if(Token::Or) {
+            requirements.push(self.all()?);
+        }
+        Ok(if requirements.len() == 1 { requirements.remove(0) } else { Requirement::Any(requirements.into()) })
+    }
+
+    fn all(&mut self) -> Result<Requirement, String> {
+        let mut requirements = vec![self.unary()?];
+        while self.next_if(Token::And) {
+            requirements.push(self.unary()?);
+        }
+        Ok(if requirements.len() == 1 { requirements.remove(0) } else { Requirement::All(requirements.into()) })
+    }
+
+    fn unary(&mut self) -> Result<Requirement, String> {
+        match self.next() {
+            Some(Token::Not) => {
+                let requirement = self.unary()?;
+                if requirement.depends_on_items() {
+                    return Err(format!("\"!\" can only negate settings, not {}", requirement));
+                }
+                Ok(Requirement::Not(Arc::new(requirement)))
+            },
+            Some(Token::Open) => {
+                let requirement = self.any()?;
+                if self.next_if(Token::Close) {
+                    Ok(requirement)
+                } else {
+                    Err("Missing \")\"".to_owned())
+                }
+            },
+            Some(&Token::Word(word)) => self.word(word),
+            Some(token) => Err(format!("Unexpected {}", token)),
+            None => Err("Unexpected end of expression".to_owned()),
+        }
+    }
+
+    fn word(&mut self, word: &str) -> Result<Requirement, String> {
+        let arg = if self.next_if(Token::Open) {
+            let arg = match self.next() {
+                Some(&Token::Word(arg)) => arg,
+                _ => return Err(format!("Missing argument to {}", word)),
+            };
+            if !self.next_if(Token::Close) {
+                return Err(format!("Missing \")\" after the argument to {}", word));
+            }
+            Some(arg)
+        } else {
+            None
+        };
+
+        match (word, arg) {
+            ("true", None) => Ok(Requirement::Always),
+            ("false", None) => Ok(Requirement::Never),
+            _ => match Helper::parse(word, arg) {
+                Some(helper) => helper.map(Requirement::Helper),
+                None if arg.is_some() => Err(format!("Unknown helper \"{}\"", word)),
+                None => (self.named)(word)?.ok_or_else(|| format!("Unknown helper \"{}\"", word)),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(expression: &str) -> Result<Requirement, String> {
+        Requirement::parse(expression, &mut |_| Ok(None))
+    }
+
+    #[test]
+    fn and_binds_tighter_than_or() {
+        let requirement = parse("has_bow || has_lamp && has_net").unwrap();
+        assert!(matches!(&requirement, Requirement::Any(any) if matches!(any[1], Requirement::All(_))));
+        assert_eq!(requirement.to_string(), "has_bow || has_lamp && has_net");
+
+        let requirement = parse("(has_bow || has_lamp) && has_net").unwrap();
+        assert!(matches!(&requirement, Requirement::All(all) if matches!(all[0], Requirement::Any(_))));
+        assert_eq!(requirement.to_string(), "(has_bow || has_lamp) && has_net");
+    }
+
+    #[test]
+    fn not_binds_tighter_than_and() {
+        let requirement = parse("!cracksanity && lampless").unwrap();
+        assert!(matches!(&requirement, Requirement::All(all) if matches!(all[0], Requirement::Not(_))));
+
+        let requirement = parse("!(cracksanity && lampless)").unwrap();
+        assert!(matches!(&requirement, Requirement::Not(not) if matches!(**not, Requirement::All(_))));
+        assert_eq!(requirement.to_string(), "!(cracksanity && lampless)");
+    }
+
+    #[test]
+    fn parses_built_in_helpers() {
+        assert!(matches!(parse("true"), Ok(Requirement::Always)));
+        assert!(matches!(parse("false"), Ok(Requirement::Never)));
+        assert!(matches!(parse("has_bow"), Ok(Requirement::Helper(Helper::has_bow))));
+        assert!(matches!(parse("has_eastern_keys(2)"), Ok(Requirement::Helper(Helper::has_eastern_keys(2)))));
+        assert!(matches!(
+            parse("has_weather_vane(KakarikoVillageWV)"),
+            Ok(Requirement::Helper(Helper::has_weather_vane(Vane::KakarikoVillageWV)))
+        ));
+
+        let err = |expression| parse(expression).unwrap_err();
+        assert_eq!(err("has_bow(1)"), "has_bow doesn't take an argument in \"has_bow(1)\"");
+        assert_eq!(err("has_eastern_keys"), "has_eastern_keys needs an argument in \"has_eastern_keys\"");
+        assert_eq!(err("has_eastern_keys(two)"), "Invalid number \"two\" in \"has_eastern_keys(two)\"");
+        assert_eq!(err("has_everything"), "Unknown helper \"has_everything\" in \"has_everything\"");
+    }
+
+    #[test]
+    fn looks_up_named_helpers() {
+        let mut named = |name: &str| match name {
+            "can_open_door" => Ok(Some(Requirement::Helper(Helper::has_power_glove))),
+            "can_loop" => Err("Helper \"can_loop\" depends on itself".to_owned()),
+            _ => Ok(None),
+        };
+
+        let requirement = Requirement::parse("can_open_door && has_bow", &mut named).unwrap();
+        assert_eq!(requirement.to_string(), "has_power_glove && has_bow");
+
+        let err = Requirement::parse("has_bow || can_loop", &mut named).unwrap_err();
+        assert_eq!(err, "Helper \"can_loop\" depends on itself in \"has_bow || can_loop\"");
+        assert!(Requirement::parse("can_fly", &mut named).unwrap_err().starts_with("Unknown helper \"can_fly\""));
+    }
+
+    #[test]
+    fn rejects_malformed_expressions() {
+        for expression in ["", "has_bow &&", "(has_bow", "has_bow)", "has_bow & has_lamp", "has_bow has_lamp"] {
+            assert!(parse(expression).is_err(), "{}", expression);
+        }
+    }
+
+    #[test]
+    fn negates_only_settings() {
+        assert!(parse("!cracksanity && !(swordless_mode || lampless)").is_ok());
+
+        for expression in ["!has_bow", "!(cracksanity || has_bow)", "!!has_eastern_keys(1)"] {
+            let err = parse(expression).unwrap_err();
+            assert!(err.starts_with("\"!\" can only negate settings"), "{}", err);
+        }
+    }
+}
@@ -1,7 +1,6 @@
 use crate::filler::tower_stage::TowerStage;
 use crate::Result;
 use game::Course::{EnemyAttackL, EnemyAttackM, EnemyAttackS};
-use macros::fail;
 use modinfo::Settings;
 use rand::rngs::StdRng;
 use rand::seq::SliceRandom;
@@ -10,12 +9,6 @@ use rand::seq::SliceRandom;
 pub fn choose_floors(settings: &Settings, rng: &mut StdRng) -> Result<Vec<TowerStage>> {
     let mut chosen_floors = Vec::with_capacity(settings.treacherous_tower_floors);
 
-    if settings.treacherous_tower_floors < 2 {
-        fail!("Treacherous Tower must have at least 2 floors.");
-    } else if settings.treacherous_tower_floors > 66 {
-        fail!("Treacherous Tower may have at most 66 floors.");
-    }
-
     // Choose (n - 2) random filler floors.
     // Since Advanced floors drastically outnumber Intermediate and Beginner floors, use a weighted factor to keep the
     // result set in roughly an even Advanced/Intermediate/Beginner split, if possible. This won't work as effectively
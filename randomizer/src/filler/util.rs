@@ -18,6 +18,13 @@ pub(crate) fn shuffle<K>(rng: &mut StdRng, mut vec: Vec<K>) -> Vec<K> {
     shuffled
 }
 
+/// Removes the first occurrence of an element from a Vec, leaving any other copies in place.
+pub(crate) fn remove_first<T: PartialEq>(vec: &mut Vec<T>, element: &T) {
+    if let Some(index) = vec.iter().position(|x| x == element) {
+        vec.remove(index);
+    }
+}
+
 /// Randomly pair together entries from a Vec and return the resultant Map.
 pub(crate) fn pair_randomly<T>(rng: &mut StdRng, vec: Vec<T>) -> crate::Result<BTreeMap<T, T>>
 where
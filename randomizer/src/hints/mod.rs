@@ -1,20 +1,22 @@
 use crate::filler::check::Check;
 use crate::filler::cracks::Crack;
+use crate::filler::dungeon_entrances::DungeonEntrance;
 use crate::filler::filler_item::{Goal, Item, Randomizable};
 use crate::filler::progress::Progress;
 use crate::filler::util::shuffle;
-use crate::filler::{find_reachable_checks, get_items_from_reachable_checks};
+use crate::filler::{
+    find_reachable_checks, get_item_set_from_reachable_checks, get_items_from_reachable_checks,
+    is_shuffled_dungeon_item,
+};
 use crate::hints::formatting::name;
 use crate::patch::util::is_sage;
 use crate::{CheckMap, DashSet, SeedInfo};
 use game::ghosts::HintGhost;
 use log::{debug, info};
-use macros::fail;
 use modinfo::settings::cracksanity::Cracksanity;
 use rand::seq::IteratorRandom;
 use rand::seq::SliceRandom;
 use rand::{rngs::StdRng, Rng};
-use rom::Error;
 use serde::{
     ser::{SerializeSeq, SerializeStruct},
     Serialize, Serializer,
@@ -50,6 +52,9 @@ pub struct LocationHint {
     /// The specific [`Check`] containing the hinted item.
     pub check: Check,
 
+    /// The door leading to the dungeon of the hinted check, when Dungeon Entrances moved it.
+    pub door: Option<DungeonEntrance>,
+
     /// List of Hint Ghosts that are guaranteed to be logically reachable before the hinted item.
     pub logical_ghosts: Vec<HintGhost>,
 
@@ -77,8 +82,9 @@ impl Hint for LocationHint {
     fn get_hint(&self) -> String {
         let article = self.item.get_article();
         format!(
-            "{}\nhas {}{}{}.",
+            "{}{}\nhas {}{}{}.",
             &self.check.get_location_info().unwrap().name(),
+            behind_door_aside(self.door, "\n"),
             article,
             if article.is_empty() { "" } else { " " },
             &self.item.as_str_colorized()
@@ -88,8 +94,9 @@ impl Hint for LocationHint {
     fn get_hint_spoiler(&self) -> String {
         let article = self.item.get_article();
         format!(
-            "{} has {}{}{}.",
+            "{}{} has {}{}{}.",
             &self.check.get_location_info().unwrap().name(),
+            behind_door_aside(self.door, " "),
             article,
             if article.is_empty() { "" } else { " " },
             &self.item.as_str()
@@ -115,6 +122,9 @@ pub struct PathHint {
     /// The specific [`Check`] containing the hinted item.
     pub check: Check,
 
+    /// The door leading to the dungeon of the hinted check, when Dungeon Entrances moved it.
+    pub door: Option<DungeonEntrance>,
+
     /// The goal that this hint leads to.
     pub goal: Goal,
 
@@ -132,14 +142,20 @@ pub struct PathHint {
 impl Hint for PathHint {
     fn get_hint(&self) -> String {
         format!(
-            "{}\nis on the path to\n{}",
+            "{}{}\nis on the path to\n{}",
             &self.check.get_location_info().unwrap().region_colorized(),
+            behind_door_aside(self.door, "\n"),
             &self.goal.as_str_colorized()
         )
     }
 
     fn get_hint_spoiler(&self) -> String {
-        format!("{} is on the path to {}", self.check.get_location_info().unwrap().region(), self.goal.as_str())
+        format!(
+            "{}{} is on the path to {}",
+            self.check.get_location_info().unwrap().region(),
+            behind_door_aside(self.door, " "),
+            self.goal.as_str()
+        )
     }
 }
 
@@ -191,19 +207,27 @@ impl Hint for CrackHint {
 pub struct BowOfLightHint {
     /// The specific [`Check`] containing the Bow of Light.
     pub check: Check,
+
+    /// The door leading to the dungeon of the hinted check, when Dungeon Entrances moved it.
+    pub door: Option<DungeonEntrance>,
 }
 
 impl Hint for BowOfLightHint {
     fn get_hint(&self) -> String {
         format!(
-            "Did you find the {}\nin {}?",
+            "Did you find the {}\nin {}{}?",
             name("Bow of Light"),
             &self.check.get_location_info().unwrap().region_colorized(),
+            behind_door(self.door, "\n")
         )
     }
 
     fn get_hint_spoiler(&self) -> String {
-        format!("Did you find the Bow of Light in {}?", &self.check.get_location_info().unwrap().region())
+        format!(
+            "Did you find the Bow of Light in {}{}?",
+            &self.check.get_location_info().unwrap().region(),
+            behind_door(self.door, " ")
+        )
     }
 }
 
@@ -216,8 +240,23 @@ impl Serialize for BowOfLightHint {
     }
 }
 
+/// Names the door leading to a hinted dungeon when Dungeon Entrances moved it, to follow the dungeon's name.
+pub(crate) fn behind_door(door: Option<DungeonEntrance>, separator: &str) -> String {
+    door.map(|door| format!(",{}behind the {} entrance", separator, door)).unwrap_or_default()
+}
+
+/// [`behind_door`] set off by commas, to name the door in the middle of a sentence.
+fn behind_door_aside(door: Option<DungeonEntrance>, separator: &str) -> String {
+    door.map(|_| behind_door(door, separator) + ",").unwrap_or_default()
+}
+
+/// The door leading to the dungeon that `check` is in, when Dungeon Entrances moved it.
+fn door_to_dungeon_of(seed_info: &SeedInfo, check: &Check) -> Option<DungeonEntrance> {
+    seed_info.transitions().shuffled_door_to(check.get_location_info()?.region())
+}
+
 /// Generates Always, Path, and Sometimes Hints based on settings
-pub fn generate_hints(rng: &mut StdRng, seed_info: &mut SeedInfo, check_map: &mut CheckMap) -> Result<(), Error> {
+pub fn generate_hints(rng: &mut StdRng, seed_info: &mut SeedInfo, check_map: &mut CheckMap) -> crate::Result<()> {
     info!("Generating Hints...");
     const NUM_TOTAL_HINTS: usize = 29;
 
@@ -228,13 +267,13 @@ pub fn generate_hints(rng: &mut StdRng, seed_info: &mut SeedInfo, check_map: &mu
     // let mut crack_hints =
     //     generate_crack_hints(settings, crack_map, world_graph, check_map, &mut taken_checks, &mut taken_ghosts, rng);
 
-    let mut always_hints = generate_always_hints(rng, seed_info, check_map, &mut taken_checks, &mut taken_ghosts);
-    let mut maiamai_hints = generate_maiamai_hints(rng, seed_info, check_map, &mut taken_checks, &mut taken_ghosts);
-    let mut path_hints = generate_path_hints(rng, seed_info, check_map, &mut taken_checks, &mut taken_ghosts);
+    let mut always_hints = generate_always_hints(rng, seed_info, check_map, &mut taken_checks, &mut taken_ghosts)?;
+    let mut maiamai_hints = generate_maiamai_hints(rng, seed_info, check_map, &mut taken_checks, &mut taken_ghosts)?;
+    let mut path_hints = generate_path_hints(rng, seed_info, check_map, &mut taken_checks, &mut taken_ghosts)?;
 
     let num_sometimes_hints = NUM_TOTAL_HINTS - always_hints.len() - maiamai_hints.len() - path_hints.len();
     let mut sometimes_hints =
-        generate_sometimes_hints(rng, seed_info, check_map, num_sometimes_hints, &taken_checks, &mut taken_ghosts);
+        generate_sometimes_hints(rng, seed_info, check_map, num_sometimes_hints, &taken_checks, &mut taken_ghosts)?;
 
     duplicate_hints(
         &mut taken_ghosts, &mut always_hints, &mut maiamai_hints, &mut path_hints, &mut sometimes_hints,
@@ -317,18 +356,16 @@ fn duplicate_hints(
 
 /// Generates the Bow of Light Hint
 /// todo need a generic "find where item be at" function
-fn generate_bow_of_light_hint(
-    SeedInfo { settings, world_graph, .. }: &SeedInfo, check_map: &mut CheckMap,
-) -> Option<BowOfLightHint> {
-    if settings.progressive_bow_of_light {
+fn generate_bow_of_light_hint(seed_info: &SeedInfo, check_map: &mut CheckMap) -> Option<BowOfLightHint> {
+    if seed_info.settings.progressive_bow_of_light {
         return None;
     }
 
-    for location_node in world_graph.values() {
-        for &check in location_node.clone().get_checks().iter().flatten().collect::<Vec<&Check>>() {
+    for location_node in seed_info.world_graph.values() {
+        for check in location_node.get_checks().iter().flatten() {
             if let Randomizable::Item(item) = check_map.get(check.get_name()).unwrap().unwrap() {
                 if BowOfLight == item {
-                    return Some(BowOfLightHint { check });
+                    return Some(BowOfLightHint { check: check.clone(), door: door_to_dungeon_of(seed_info, check) });
                 }
             }
         }
@@ -344,7 +381,7 @@ fn generate_bow_of_light_hint(
 fn generate_always_hints(
     rng: &mut StdRng, seed_info: &SeedInfo, check_map: &mut CheckMap, taken_checks: &mut Vec<String>,
     taken_ghosts: &mut Vec<HintGhost>,
-) -> Vec<LocationHint> {
+) -> crate::Result<Vec<LocationHint>> {
     let mut always_checks = vec![
         "Blacksmith (Lorule)", "Bouldering Guy", "Great Rupee Fairy", "Haunted Grove Stump", "Irene",
         "Master Sword Pedestal", "Octoball Derby", "Queen Oren", "Rosso (1)", "Rosso (2)", "Shady Guy",
@@ -355,7 +392,7 @@ fn generate_always_hints(
 
     let mut always_hints = Vec::new();
     for check_name in always_checks {
-        let mut location_hint = generate_location_hint(check_name, seed_info, check_map);
+        let mut location_hint = generate_location_hint(check_name, seed_info, check_map)?;
         if location_hint.choose_ghost(rng, taken_ghosts).is_err() {
             continue;
         }
@@ -363,7 +400,7 @@ fn generate_always_hints(
         taken_checks.push(check_name.to_string());
     }
 
-    always_hints
+    Ok(always_hints)
 }
 
 /**
@@ -372,7 +409,7 @@ fn generate_always_hints(
 fn generate_maiamai_hints(
     rng: &mut StdRng, seed_info: &SeedInfo, check_map: &mut CheckMap, taken_checks: &mut Vec<String>,
     taken_ghosts: &mut Vec<HintGhost>,
-) -> Vec<LocationHint> {
+) -> crate::Result<Vec<LocationHint>> {
     const NUM_MAI_HINTS: usize = 5;
     let mut available_maiamai_checks = vec![
         "Maiamai Bow Upgrade", "Maiamai Boomerang Upgrade", "Maiamai Hookshot Upgrade", "Maiamai Hammer Upgrade",
@@ -405,7 +442,7 @@ fn generate_maiamai_hints(
     // Generate the actual Location Hints
     let mut maiamai_hints = Vec::with_capacity(NUM_MAI_HINTS);
     for check_name in chosen_maiamai_checks {
-        let mut location_hint = generate_location_hint(check_name, seed_info, check_map);
+        let mut location_hint = generate_location_hint(check_name, seed_info, check_map)?;
         if location_hint.choose_ghost(rng, taken_ghosts).is_err() {
             continue;
         }
@@ -413,13 +450,15 @@ fn generate_maiamai_hints(
         taken_checks.push(check_name.to_string());
     }
 
-    maiamai_hints
+    Ok(maiamai_hints)
 }
 
-fn generate_location_hint(check_name: &'static str, seed_info: &SeedInfo, check_map: &mut CheckMap) -> LocationHint {
+fn generate_location_hint(
+    check_name: &'static str, seed_info: &SeedInfo, check_map: &mut CheckMap,
+) -> crate::Result<LocationHint> {
     // fixme this sucks
     let mut check = None;
-    'outer: for (_, loc_node) in seed_info.world_graph.clone() {
+    'outer: for (_, loc_node) in seed_info.world_graph.iter() {
         let checks = loc_node.get_checks().clone();
         for c in checks.into_iter().flatten().collect::<Vec<Check>>() {
             if check_name.eq(c.get_name()) {
@@ -432,10 +471,10 @@ fn generate_location_hint(check_name: &'static str, seed_info: &SeedInfo, check_
     let (item, check) = if let Some(check) = check {
         (check_map.get(check.get_name()).unwrap().unwrap(), check)
     } else {
-        fail!("Failed to lookup Check from check_name: {}", check_name);
+        return Err(crate::Error::unknown_check(format!("Failed to lookup Check from check_name: {}", check_name)));
     };
 
-    let logical_ghosts = find_checks_before_goal(seed_info, check_map, item)
+    let logical_ghosts = find_checks_before_goal(seed_info, check_map, item)?
         .iter()
         .filter_map(|check| {
             if let Some(Randomizable::HintGhost(ghost)) = check.get_quest() {
@@ -445,7 +484,8 @@ fn generate_location_hint(check_name: &'static str, seed_info: &SeedInfo, check_
         })
         .collect::<Vec<_>>();
 
-    LocationHint { item: item.as_item().unwrap(), check, logical_ghosts, ghosts: vec![] }
+    let door = door_to_dungeon_of(seed_info, &check);
+    Ok(LocationHint { item: item.as_item().unwrap(), check, door, logical_ghosts, ghosts: vec![] })
 }
 
 /**
@@ -456,7 +496,7 @@ fn generate_location_hint(check_name: &'static str, seed_info: &SeedInfo, check_
 fn generate_sometimes_hints(
     rng: &mut StdRng, seed_info: &SeedInfo, check_map: &mut CheckMap, num_sometimes_hints: usize,
     taken_checks: &[String], taken_ghosts: &mut Vec<HintGhost>,
-) -> Vec<LocationHint> {
+) -> crate::Result<Vec<LocationHint>> {
     let mut sometimes_checks = vec![
         "Bee Guy (2)", "Behind Ice Gimos", "Bird Lover", "Blacksmith", "Blacksmith Cave", "Cucco Mini-Dungeon",
         "Hookshot Mini-Dungeon", "Donkey Cave", "Eastern Ruins Peg Circle", "Merge Mini-Dungeon", "Fire Cave Pillar",
@@ -506,7 +546,7 @@ fn generate_sometimes_hints(
         }
 
         let selected_hint = sometimes_checks.remove(rng.gen_range(0..sometimes_checks.len()));
-        let mut location_hint = generate_location_hint(selected_hint, seed_info, check_map);
+        let mut location_hint = generate_location_hint(selected_hint, seed_info, check_map)?;
         if location_hint.choose_ghost(rng, taken_ghosts).is_err() {
             continue;
         }
@@ -515,7 +555,7 @@ fn generate_sometimes_hints(
         sometimes_hint_count += 1;
     }
 
-    sometimes_hints
+    Ok(sometimes_hints)
 }
 
 /**
@@ -529,7 +569,7 @@ fn generate_sometimes_hints(
 fn generate_path_hints(
     rng: &mut StdRng, seed_info: &SeedInfo, check_map: &mut CheckMap, taken_checks: &mut Vec<String>,
     taken_ghosts: &mut Vec<HintGhost>,
-) -> Vec<PathHint> {
+) -> crate::Result<Vec<PathHint>> {
     let mut bosses_and_prize_locations = vec![
         (Goal::Yuga, "[EP] Prize"),
         (Goal::Margomill, "[HG] Prize"),
@@ -551,7 +591,7 @@ fn generate_path_hints(
 
     for (goal, prize_loc) in bosses_and_prize_locations {
         if is_sage(check_map.get(prize_loc).unwrap().unwrap()) {
-            let mut potential_paths = get_potential_path_hints(rng, seed_info, check_map, taken_checks, goal);
+            let mut potential_paths = get_potential_path_hints(rng, seed_info, check_map, taken_checks, goal)?;
 
             if let Some(chosen_path) = choose_path_hint(&mut potential_paths, taken_checks, taken_ghosts, rng) {
                 chosen_paths.push(chosen_path);
@@ -579,7 +619,7 @@ fn generate_path_hints(
         }
     }
 
-    chosen_paths
+    Ok(chosen_paths)
 }
 
 fn choose_path_hint(
@@ -609,7 +649,7 @@ fn choose_path_hint(
 /// Finds all checks available before a given Quest Goal using a modified Sphere Search.
 fn find_checks_before_goal(
     seed_info: &SeedInfo, check_map: &mut CheckMap, goal: impl Into<Randomizable>,
-) -> DashSet<Check> {
+) -> crate::Result<DashSet<Check>> {
     let goal = goal.into();
     let mut progress = Progress::new(seed_info);
     let mut reachable_checks: Vec<Check>;
@@ -617,8 +657,8 @@ fn find_checks_before_goal(
 
     // Find candidate Path Checks with a modified sphere search
     loop {
-        reachable_checks = find_reachable_checks(seed_info, &progress);
-        potential_path_checks.extend(&reachable_checks);
+        reachable_checks = find_reachable_checks(seed_info, &progress)?;
+        potential_path_checks.extend(reachable_checks.iter().cloned());
         let reachable_items = get_items_from_reachable_checks(seed_info, &reachable_checks, check_map);
 
         let new_items = reachable_items.difference(&progress);
@@ -632,22 +672,23 @@ fn find_checks_before_goal(
         }
     }
 
-    potential_path_checks
+    Ok(potential_path_checks)
 }
 
 /// Determines the possible Path Hints for a given goal, if any exist. Paths are returned in a random order.
 fn get_potential_path_hints(
     rng: &mut StdRng, seed_info: &SeedInfo, check_map: &mut CheckMap, taken_checks: &mut [String], goal: Goal,
-) -> Vec<PathHint> {
+) -> crate::Result<Vec<PathHint>> {
     let mut reachable_checks: Vec<Check>;
     let mut potential_paths: Vec<PathHint> = Vec::new();
 
-    let mut potential_path_checks = find_checks_before_goal(seed_info, check_map, goal);
+    let mut potential_path_checks = find_checks_before_goal(seed_info, check_map, goal)?;
 
     // Limit potential paths to locations with valid Path Items that haven't yet been taken
     potential_path_checks.retain(|check| {
         if let Some(Some(Randomizable::Item(item))) = check_map.get(check.get_name()) {
-            !taken_checks.contains(&check.get_name().to_string()) && POSSIBLE_PATH_ITEMS.contains(item)
+            !taken_checks.contains(&check.get_name().to_string())
+                && (POSSIBLE_PATH_ITEMS.contains(item) || is_shuffled_dungeon_item(&seed_info.settings, *item))
         } else {
             false
         }
@@ -664,7 +705,7 @@ fn get_potential_path_hints(
         let mut progress = nothing_but_hearts_and_rupees.clone();
 
         loop {
-            reachable_checks = find_reachable_checks(seed_info, &progress);
+            reachable_checks = find_reachable_checks(seed_info, &progress)?;
 
             // Remove Potential Path Location
             reachable_checks.retain(|c| check.ne(c));
@@ -676,10 +717,9 @@ fn get_potential_path_hints(
             if new_items.is_empty() {
                 // Item could be Path if goal couldn't be reached without it
                 if !progress.has(goal) {
-                    let hint_locations = reachable_items
-                        .get_items()
-                        .iter()
-                        .filter_map(|&item| if let Randomizable::HintGhost(ghost) = item { Some(ghost) } else { None })
+                    let hint_locations = get_item_set_from_reachable_checks(seed_info, &reachable_checks, check_map)
+                        .into_iter()
+                        .filter_map(|item| if let Randomizable::HintGhost(ghost) = item { Some(ghost) } else { None })
                         .collect::<_>();
 
                     let path_item = check_map
@@ -689,6 +729,7 @@ fn get_potential_path_hints(
 
                     potential_paths.push(PathHint {
                         goal,
+                        door: door_to_dungeon_of(seed_info, &check),
                         check,
                         ghosts: vec![],
                         logical_ghosts: hint_locations,
@@ -704,7 +745,7 @@ fn get_potential_path_hints(
         }
     }
 
-    shuffle(rng, potential_paths)
+    Ok(shuffle(rng, potential_paths))
 }
 
 struct SerializeGhosts<'a>(&'a [HintGhost]);
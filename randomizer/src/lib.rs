@@ -1,8 +1,8 @@
 use crate::filler::filler_item::Vane;
 use crate::filler::tower_stage::TowerStage;
 use crate::filler::trials::TrialsConfig;
-use crate::filler::{cracks, text, treacherous_tower, trials, vanes};
-use crate::world::WorldGraph;
+use crate::filler::{cracks, dungeon_entrances, text, treacherous_tower, trials, vanes};
+use crate::world::{Transitions, WorldGraph};
 use crate::{
     constants::VERSION,
     hints::{formatting::*, Hints},
@@ -11,10 +11,13 @@ use crate::{
     system::UserConfig,
 };
 use filler::cracks::Crack;
+use filler::dungeon_entrances::DungeonEntrance;
+use filler::failure::FailureReport;
 use filler::filler_item::Randomizable;
 use game::Item::{self};
 use log::{debug, error, info};
-use macros::fail;
+use modinfo::settings::settings_string;
+use modinfo::settings::validation::{self, Problem};
 use modinfo::Settings;
 use patch::Patcher;
 use path_absolutize::*;
@@ -26,10 +29,13 @@ use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 use std::hash::BuildHasherDefault;
 use std::{
     error::Error as StdError,
+    fmt::{self, Display, Formatter},
     fs::File,
     hash::{Hash, Hasher},
     io::{self, Write},
     ops::Deref,
+    path::PathBuf,
+    sync::Arc,
 };
 use twox_hash::XxHash64;
 
@@ -42,6 +48,8 @@ pub mod regions;
 pub mod system;
 mod world;
 
+pub use world::tricks::{tricks, Trick};
+
 pub type Result<T, E = Error> = core::result::Result<T, E>;
 
 #[derive(Debug)]
@@ -72,17 +80,64 @@ impl Error {
         Self { kind: ErrorKind::Io, inner: err.into() }
     }
 
+    fn invalid_settings<S>(err: S) -> Self
+    where
+        S: Into<Box<dyn StdError + Send + Sync + 'static>>,
+    {
+        Self { kind: ErrorKind::InvalidSettings, inner: err.into() }
+    }
+
+    fn unknown_check<S>(err: S) -> Self
+    where
+        S: Into<Box<dyn StdError + Send + Sync + 'static>>,
+    {
+        Self { kind: ErrorKind::UnknownCheck, inner: err.into() }
+    }
+
+    fn unbeatable<S>(err: S) -> Self
+    where
+        S: Into<Box<dyn StdError + Send + Sync + 'static>>,
+    {
+        Self { kind: ErrorKind::Unbeatable, inner: err.into() }
+    }
+
+    fn output_not_writable<S>(err: S) -> Self
+    where
+        S: Into<Box<dyn StdError + Send + Sync + 'static>>,
+    {
+        Self { kind: ErrorKind::OutputNotWritable, inner: err.into() }
+    }
+
     /// Gets the type of this error.
     pub fn kind(&self) -> &ErrorKind {
         &self.kind
     }
 
+    /// The [`FailureReport`] of an [`Unbeatable`] error raised by the filler, if there is one.
+    ///
+    /// [`Unbeatable`]: ErrorKind::Unbeatable
+    pub fn failure_report(&self) -> Option<&FailureReport> {
+        self.inner.downcast_ref()
+    }
+
     /// Converts this error into its inner value.
     pub fn into_inner(self) -> Box<dyn StdError + Send + Sync + 'static> {
         self.inner
     }
 }
 
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.inner.fmt(f)
+    }
+}
+
+impl StdError for Error {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        Some(self.inner.as_ref())
+    }
+}
+
 impl From<rom::Error> for Error {
     fn from(err: rom::Error) -> Self {
         let kind = match err.kind() {
@@ -99,17 +154,39 @@ impl From<io::Error> for Error {
     }
 }
 
+impl From<world::WorldGraphError> for Error {
+    fn from(err: world::WorldGraphError) -> Self {
+        match err {
+            world::WorldGraphError::File(err) => Self { kind: ErrorKind::InvalidLogic, inner: err.into() },
+            world::WorldGraphError::Logic(errors) => Self::invalid_settings(errors.join("\n")),
+        }
+    }
+}
+
 impl From<system::Error> for Error {
     fn from(err: system::Error) -> Self {
         Self { kind: ErrorKind::Internal, inner: err.into() }
     }
 }
 
-#[derive(Debug)]
+/// The kind of error contained within the [`Error`] type.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum ErrorKind {
+    /// An unexpected error within the randomizer itself.
     Internal,
+    /// An error within the provided ROM, or while patching it.
     Game,
     Io,
+    /// The provided Settings are invalid, or incompatible with each other.
+    InvalidSettings,
+    /// A check name provided by the user does not match any check in the world graph.
+    UnknownCheck,
+    /// A logic file could not be read, or is malformed.
+    InvalidLogic,
+    /// The Settings did not produce a completable seed.
+    Unbeatable,
+    /// The patch files or spoiler log could not be written to the output directory.
+    OutputNotWritable,
 }
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Serialize)]
@@ -265,7 +342,7 @@ where
 
 /// Align JSON Key-Values for readability
 /// Can't find a decent library for this, so we're doing it manually
-fn align_json_values(json: &mut String) {
+fn align_json_values(json: &mut String) -> Result<()> {
     const KEY_ALIGNMENT: usize = 56;
     let mut index_colon = 0;
     while index_colon < json.len() {
@@ -279,18 +356,19 @@ fn align_json_values(json: &mut String) {
             continue;
         }
 
-        let index_prev_new_line = json[..index_colon].rfind('\n').unwrap_or_else(|| {
-            fail!("Couldn't fine new line character before index: {}", index_colon);
-        });
+        let index_prev_new_line = json[..index_colon].rfind('\n').ok_or_else(|| {
+            Error::internal(format!("Couldn't find new line character before index: {}", index_colon))
+        })?;
         let line_length_up_to_value = index_colon - index_prev_new_line;
 
         if KEY_ALIGNMENT < line_length_up_to_value {
             error!("Failed to write Spoiler Log");
-            error!(
-                "JSON Key Alignment value smaller than line length up to that point: {} < {}",
-                KEY_ALIGNMENT, line_length_up_to_value
-            );
-            fail!("Problem line: {}", &json[index_prev_new_line..index_colon]);
+            return Err(Error::internal(format!(
+                "JSON Key Alignment value smaller than line length up to that point: {} < {}\nProblem line: {}",
+                KEY_ALIGNMENT,
+                line_length_up_to_value,
+                &json[index_prev_new_line..index_colon]
+            )));
         }
 
         let spaces_to_add = KEY_ALIGNMENT - line_length_up_to_value;
@@ -298,6 +376,8 @@ fn align_json_values(json: &mut String) {
         json.insert_str(&index_colon + 1, (0..spaces_to_add).map(|_| " ").collect::<String>().as_str());
         index_colon += 1;
     }
+
+    Ok(())
 }
 
 #[derive(Serialize, Default, Debug)]
@@ -317,10 +397,22 @@ pub struct SeedInfo {
 
     pub settings: Settings,
 
+    /// The compact, shareable form of [`settings`], see [`settings_string`].
+    #[serde(skip_deserializing)]
+    pub settings_string: String,
+
     /// The list of exclusions provided by the user in [`settings`], enhanced by the randomizer based on settings.
     #[serde(skip_deserializing)]
     pub full_exclusions: BTreeSet<String>,
 
+    /// The checks guaranteed to hold progression, from the priorities in [`settings`] with their subregions expanded.
+    #[serde(skip_deserializing)]
+    pub full_priorities: BTreeSet<String>,
+
+    /// How many of each item the junk pool was drawn with, before any were placed.
+    #[serde(skip_deserializing)]
+    pub junk_counts: BTreeMap<String, usize>,
+
     #[serde(skip_deserializing)]
     pub treacherous_tower_floors: Vec<TowerStage>,
 
@@ -333,6 +425,9 @@ pub struct SeedInfo {
     #[serde(skip_deserializing)]
     pub crack_map: CrackMap,
 
+    #[serde(skip_deserializing, skip_serializing_if = "dungeon_entrances::is_vanilla")]
+    pub dungeon_entrance_map: DungeonEntranceMap,
+
     #[serde(skip_deserializing, rename = "weather_vane_map")]
     pub vane_map: VaneMap,
 
@@ -346,13 +441,18 @@ pub struct SeedInfo {
     pub hints: Hints,
 
     #[serde(skip_deserializing, skip_serializing)]
-    pub world_graph: WorldGraph,
+    pub world_graph: Arc<WorldGraph>,
 }
 
 impl SeedInfo {
     pub fn is_excluded(&self, check_name: &str) -> bool {
         self.full_exclusions.contains(check_name)
     }
+
+    /// The seed's shuffled transitions, resolving where the dynamic edges of the [`WorldGraph`] lead.
+    pub fn transitions(&self) -> Transitions<'_> {
+        Transitions::new(&self.crack_map, &self.dungeon_entrance_map)
+    }
 }
 
 impl Default for SeedInfo {
@@ -362,8 +462,12 @@ impl Default for SeedInfo {
             version: "".to_owned(),
             hash: Default::default(),
             settings: Default::default(),
+            settings_string: Default::default(),
             full_exclusions: Default::default(),
+            full_priorities: Default::default(),
+            junk_counts: Default::default(),
             crack_map: Default::default(),
+            dungeon_entrance_map: Default::default(),
             vane_map: Default::default(),
             layout: Default::default(),
             metrics: Default::default(),
@@ -380,6 +484,20 @@ impl Default for SeedInfo {
 pub fn generate_seed(
     seed: u32, settings: Settings, user_config: &UserConfig, no_patch: bool, no_spoiler: bool,
 ) -> Result<()> {
+    let seed_info = &match generate_seed_info(seed, settings) {
+        Ok(seed_info) => seed_info,
+        Err(err) => {
+            write_failure_report(&err, user_config, no_spoiler)?;
+            return Err(err);
+        },
+    };
+    patch_seed(seed_info, user_config, no_patch, no_spoiler)?;
+
+    Ok(())
+}
+
+/// Generates the [`SeedInfo`] for one ALBWR Seed without patching anything or writing a spoiler log.
+pub fn generate_seed_info(seed: u32, settings: Settings) -> Result<SeedInfo> {
     validate_settings(&settings)?;
 
     let rng = &mut StdRng::seed_from_u64(seed as u64);
@@ -390,10 +508,46 @@ pub fn generate_seed(
 
     // settings.log_settings();
 
-    let seed_info = &calculate_seed_info(seed, settings, hash, rng)?;
-    patch_seed(seed_info, user_config, no_patch, no_spoiler)?;
+    calculate_seed_info(seed, settings, hash, rng)
+}
 
-    Ok(())
+/// Builds the World Graph and shuffled transitions that `seed` would be generated with, without filling it. The Crack
+/// and Dungeon Entrance Maps are drawn first from the same RNG as [`generate_seed_info`], so they match the seed's.
+pub fn generate_world_graph(seed: u32, settings: Settings) -> Result<SeedInfo> {
+    validate_settings(&settings)?;
+
+    let rng = &mut StdRng::seed_from_u64(seed as u64);
+    let crack_map = cracks::build_crack_map(&settings, rng)?;
+    let dungeon_entrance_map = dungeon_entrances::build_dungeon_entrance_map(&settings, rng);
+    let world_graph = world::build_world_graph(&settings)?;
+
+    Ok(SeedInfo {
+        seed,
+        version: VERSION.to_owned(),
+        settings,
+        crack_map,
+        dungeon_entrance_map,
+        world_graph,
+        ..Default::default()
+    })
+}
+
+/// Writes the [`FailureReport`] of a seed that could not be generated to the output directory, where its spoiler log
+/// would have gone, and returns the path it was written to. Nothing is written if `err` carries no report, or if
+/// `no_spoiler` is set.
+pub fn write_failure_report(err: &Error, user_config: &UserConfig, no_spoiler: bool) -> Result<Option<PathBuf>> {
+    let Some(report) = err.failure_report().filter(|_| !no_spoiler) else {
+        return Ok(None);
+    };
+
+    let path = user_config.output().join(format!("{:0>10}_failure.json", report.seed()));
+    info!("Writing Failure Report to:      {}", &path.absolutize()?.display());
+
+    let serialized = serde_json::to_string_pretty(report).unwrap();
+    write!(File::create(&path).map_err(Error::output_not_writable)?, "{}", serialized)
+        .map_err(Error::output_not_writable)?;
+
+    Ok(Some(path))
 }
 
 /// A hash used in-game to quickly verify that two players are playing the same seed.
@@ -451,44 +605,46 @@ impl Serialize for SeedHash {
 }
 
 /// Validates the Settings to make sure the user hasn't made incompatible selections
-fn validate_settings(settings: &Settings) -> Result<()> {
-    // LC Requirement
-    if !(0..=7).contains(&settings.lc_requirement) {
-        fail!("Invalid Lorule Castle Requirement: \"{}\" was not between 0-7, inclusive.", settings.lc_requirement);
-    }
-
-    // Yuganon Requirement
-    // if !(0..=7).contains(&settings.logic.yuganon_requirement) {
-    //     fail!("Invalid Yuga Ganon Requirement: \"{}\" was not between 0-7, inclusive.", settings.logic.yuganon_requirement);
-    // }
-
-    if settings.yuganon_requirement != settings.lc_requirement {
-        fail!(
-            "Yuga Ganon Requirement: \"{}\" is different than Lorule Castle Requirement: \"{}\"\n\
-        Different values for these settings are not yet supported!",
-            settings.yuganon_requirement,
-            settings.lc_requirement
-        );
+pub fn validate_settings(settings: &Settings) -> Result<()> {
+    let errors = settings_problems(settings)?.into_iter().filter(Problem::is_error).collect::<Vec<_>>();
+    if errors.is_empty() {
+        return Ok(());
     }
 
-    // Progressive Bow of Light
-    if settings.progressive_bow_of_light && settings.bow_of_light_in_castle {
-        fail!("The progressive_bow_of_light and bow_of_light_in_castle settings cannot both be enabled.");
-    }
-
-    // Swords
-    if settings.sword_in_shop && settings.swordless_mode {
-        fail!("The sword_in_shop and swordless_mode settings cannot both be enabled.");
+    let message = errors.iter().map(Problem::to_string).collect::<Vec<_>>().join("\n");
+    if errors.iter().all(|problem| problem.field == "user_exclusions") {
+        Err(Error::unknown_check(message))
+    } else {
+        Err(Error::invalid_settings(message))
     }
+}
 
-    // Assured Weapons
-    if settings.assured_weapon && (settings.sword_in_shop || settings.boots_in_shop) {
-        fail!(
-            "The assured_weapon setting cannot be enabled when either sword_in_shop or boots_in_shop is also enabled."
-        );
-    }
+/// Finds every problem with the Settings, including any `user_exclusions` that don't match a check name and any
+/// `logic_overrides` or `enabled_tricks` that don't apply to the logic files. Fails if the logic files themselves
+/// can't be loaded.
+pub fn settings_problems(settings: &Settings) -> Result<Vec<Problem>> {
+    let mut problems = validation::validate(settings, Some(&check_names()?));
+    let known_tricks = world::tricks::tricks().map_err(world::WorldGraphError::from)?;
+    for trick in settings.enabled_tricks.iter().filter(|trick| !known_tricks.contains_key(trick.as_str())) {
+        problems.push(Problem::error("enabled_tricks", format!("Unknown trick \"{}\"", trick)));
+    }
+    match world::build_world_graph(settings) {
+        Ok(_) => {},
+        Err(world::WorldGraphError::Logic(errors)) => {
+            problems.extend(errors.into_iter().map(|err| Problem::error("logic_overrides", err)))
+        },
+        Err(err) => return Err(err.into()),
+    }
+    Ok(problems)
+}
 
-    Ok(())
+/// The names of every check in the world graph, as used by `user_exclusions` and the spoiler log.
+pub fn check_names() -> Result<BTreeSet<String>> {
+    Ok(world::build_world_graph(&Settings::default())?
+        .values()
+        .flat_map(|location_node| location_node.clone().get_checks().iter().flatten().cloned().collect::<Vec<_>>())
+        .map(|check| check.get_name().to_owned())
+        .collect())
 }
 
 /// "Deterministic `HashMap`" that uses a hashing algorithm not based on any random number generation, unlike the Rust
@@ -506,6 +662,9 @@ pub type CheckMap = DashMap<String, Option<Randomizable>>;
 /// so each Crack and its destination must have a corresponding reversed entry.
 pub type CrackMap = BTreeMap<Crack, Crack>;
 
+/// Map of every dungeon entrance door to the dungeon it leads to.
+pub type DungeonEntranceMap = BTreeMap<DungeonEntrance, DungeonEntrance>;
+
 /// Map of all Weather Vanes to the destination Vanes they unlock.
 pub type VaneMap = BTreeMap<Vane, Vane>;
 
@@ -514,20 +673,25 @@ fn calculate_seed_info(seed: u32, settings: Settings, hash: SeedHash, rng: &mut
     info!("Calculating Seed Info...");
 
     let crack_map = cracks::build_crack_map(&settings, rng)?;
+    let dungeon_entrance_map = dungeon_entrances::build_dungeon_entrance_map(&settings, rng);
     let vane_map = vanes::build_vanes_map(&settings, rng)?;
     let text = text::generate(rng)?;
     let trials_config = trials::configure(rng, &settings)?;
     let treacherous_tower_floors = treacherous_tower::choose_floors(&settings, rng)?;
-    let world_graph = world::build_world_graph(&crack_map);
+    let world_graph = world::build_world_graph(&settings)?;
 
     let mut seed_info = SeedInfo {
         seed,
         version: VERSION.to_owned(),
         hash,
+        settings_string: settings_string::encode(&settings),
         settings,
         full_exclusions: Default::default(),
+        full_priorities: Default::default(),
+        junk_counts: Default::default(),
         vane_map,
         crack_map,
+        dungeon_entrance_map,
         layout: Default::default(),
         metrics: Default::default(),
         hints: Default::default(),
@@ -538,7 +702,7 @@ fn calculate_seed_info(seed: u32, settings: Settings, hash: SeedHash, rng: &mut
     };
 
     // Check Map and Item Pools
-    let check_map = &mut filler::prefill_check_map(&mut seed_info.world_graph);
+    let check_map = &mut filler::prefill_check_map(&seed_info.world_graph)?;
 
     // Filler Algorithm
     filler::fill_all_locations_reachable(rng, &mut seed_info, check_map)?;
@@ -572,7 +736,7 @@ pub fn patch_seed(seed_info: &SeedInfo, user_config: &UserConfig, no_patch: bool
         // patch::research_msbf_msbt(&mut patcher,
         //     game::Course::IndoorLight, "FieldLight_18_SahasPupil", // MSBF
         //     game::Course::IndoorLight, "FieldLight_18", // MSBT
-        //     true);
+        //     true)?;
 
         regions::patch(&mut patcher, seed_info)?;
         let patches = patcher.prepare(seed_info)?;
@@ -585,14 +749,11 @@ pub fn patch_seed(seed_info: &SeedInfo, user_config: &UserConfig, no_patch: bool
         //let spoiler = Spoiler::from(seed_info);
 
         let mut serialized = serde_json::to_string_pretty(&seed_info).unwrap();
-        align_json_values(&mut serialized);
+        align_json_values(&mut serialized)?;
 
-        write!(File::create(path)?, "{}", serialized).expect("Could not write the spoiler log.");
+        write!(File::create(path).map_err(Error::output_not_writable)?, "{}", serialized)
+            .map_err(Error::output_not_writable)?;
     }
 
-    // let path = user_config.output().join(format!("{:0>10}_world_graph.json", seed_info.seed));
-    // let world_graph = serde_json::to_string_pretty(&seed_info.world_graph).unwrap();
-    // write!(File::create(path)?, "{}", world_graph).expect("Could not write World Graph");
-
     Ok(())
 }
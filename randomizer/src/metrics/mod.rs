@@ -2,18 +2,17 @@ use crate::filler::check::Check;
 use crate::filler::cracks::Crack;
 use crate::filler::filler_item::{Goal, Item, Randomizable, Vane};
 use crate::filler::progress::Progress;
-use crate::{filler, CheckMap, SeedInfo};
+use crate::{filler, CheckMap, Result, SeedInfo};
 use game::ghosts::HintGhost;
 use log::info;
-use rom::Error;
 use serde::Serialize;
 use std::collections::BTreeMap;
 
 /// Perform any post-generation analysis for a seed here
-pub fn calculate_metrics(seed_info: &mut SeedInfo, check_map: &mut CheckMap) -> Result<(), Error> {
+pub fn calculate_metrics(seed_info: &mut SeedInfo, check_map: &mut CheckMap) -> Result<()> {
     info!("Calculating Metrics...");
 
-    let playthrough = sphere_search(seed_info, check_map);
+    let playthrough = sphere_search(seed_info, check_map)?;
 
     seed_info.metrics = Metrics { spheres: playthrough.len(), playthrough };
 
@@ -78,7 +77,7 @@ impl Sphere {
 }
 
 /// Sphere Search
-fn sphere_search(seed_info: &mut SeedInfo, check_map: &mut CheckMap) -> BTreeMap<String, Sphere> {
+fn sphere_search(seed_info: &mut SeedInfo, check_map: &mut CheckMap) -> Result<BTreeMap<String, Sphere>> {
     info!("Generating Playthrough...");
 
     let mut progress = Progress::new(seed_info);
@@ -87,7 +86,7 @@ fn sphere_search(seed_info: &mut SeedInfo, check_map: &mut CheckMap) -> BTreeMap
     let mut sphere_num = 0;
 
     loop {
-        reachable_checks = filler::find_reachable_checks(seed_info, &progress);
+        reachable_checks = filler::find_reachable_checks(seed_info, &progress)?;
         let reachable_items = filler::get_items_from_reachable_checks(seed_info, &reachable_checks, check_map);
 
         let new_items = reachable_items.difference(&progress);
@@ -115,7 +114,7 @@ fn sphere_search(seed_info: &mut SeedInfo, check_map: &mut CheckMap) -> BTreeMap
         sphere_num += 1;
     }
 
-    spheres
+    Ok(spheres)
 }
 
 #[derive(Default, Debug, Clone, Serialize)]
@@ -124,4 +123,18 @@ pub struct Metrics {
     playthrough: Playthrough,
 }
 
+impl Metrics {
+    /// The number of Spheres in the [`Playthrough`].
+    pub fn spheres(&self) -> usize {
+        self.spheres
+    }
+
+    /// Iterates over every major [`Item`] in the [`Playthrough`], paired with the index of the Sphere containing it.
+    pub fn major_items_by_sphere(&self) -> impl Iterator<Item = (usize, Item)> + '_ {
+        self.playthrough.values().enumerate().flat_map(|(index, sphere)| {
+            sphere.items.values().filter(|&&item| Randomizable::from(item).is_major_item()).map(move |&item| (index, item))
+        })
+    }
+}
+
 pub type Playthrough = BTreeMap<String, Sphere>;
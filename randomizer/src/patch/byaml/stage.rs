@@ -1,8 +1,8 @@
+use crate::patch::dungeon_items::{self, BIG_KEY_DOORS, SMALL_KEY_DOORS};
 use crate::patch::Patcher;
 use crate::{patch::util::*, regions, Result, SeedInfo};
 use game::Course::{self, *};
 use log::info;
-use macros::fail;
 use modinfo::settings::cracksanity::Cracksanity;
 use modinfo::settings::keysy::Keysy;
 use modinfo::settings::Settings;
@@ -122,6 +122,7 @@ pub fn patch(patcher: &mut Patcher, seed_info: &SeedInfo) -> Result<()> {
     patch_cracksanity(patcher);
     patch_keysy_small(patcher, &seed_info.settings);
     patch_keysy_big(patcher, &seed_info.settings);
+    dungeon_items::patch(patcher, seed_info);
     // patch_reverse_desert_palace(patcher, settings);
 
     patch_big_bomb_flower_skip(patcher, &seed_info.settings);
@@ -992,9 +993,7 @@ fn patch_blacksmith_hyrule(patcher: &mut Patcher) {
                 obj.srt.scale = match obj.id {
                     35 => Vec3 { x: 1.00000, y: 2.00000, z: 2.22222 },
                     34 => Vec3 { x: 0.52632, y: 2.00000, z: 1.66667 },
-                    _ => {
-                        fail!("PackageSword wasn't a chest")
-                    },
+                    _ => unreachable!("PackageSword wasn't a chest"),
                 }
             }),
             disable(19), // Map attention
@@ -1271,59 +1270,9 @@ fn patch_keysy_small(patcher: &mut Patcher, settings: &Settings) {
         _ => return,
     }
 
-    patcher.modify_objs(CaveLight, 18, [disable(60)]); // Hyrule Sewers
-
-    patcher.modify_objs(DungeonEast, 1, [disable(114)]); // Eastern Palace 1F
-    patcher.modify_objs(DungeonEast, 2, [disable(34)]); // Eastern Palace 2F
-
-    patcher.modify_objs(DungeonWind, 1, [disable(74)]); // House of Gales 1F
-    patcher.modify_objs(DungeonWind, 2, [disable(150)]); // House of Gales 2F
-    patcher.modify_objs(DungeonWind, 2, [disable(153)]); // House of Gales 2F
-    patcher.modify_objs(DungeonWind, 3, [disable(54)]); // House of Gales 3F
-
-    patcher.modify_objs(DungeonHera, 1, [disable(245)]); // Tower of Hera 3F
-    patcher.modify_objs(DungeonHera, 1, [disable(335)]); // Tower of Hera 7F
-
-    patcher.modify_objs(AttractionDark, 2, [disable(60)]); // Lorule Sewers
-
-    patcher.modify_objs(DungeonDark, 2, [disable(26)]); // Dark Palace 1F
-    patcher.modify_objs(DungeonDark, 2, [disable(231)]); // Dark Palace 1F
-    patcher.modify_objs(DungeonDark, 1, [disable(108)]); // Dark Palace B1
-    patcher.modify_objs(DungeonDark, 1, [disable(142)]); // Dark Palace B1
-
-    // Swamp B1 Key doors are two sided, so there are twice as many
-    patcher.modify_objs(DungeonWater, 2, [disable(65)]); // Swamp Palace B1
-    patcher.modify_objs(DungeonWater, 2, [disable(205)]); // Swamp Palace B1 - Center Room
-    patcher.modify_objs(DungeonWater, 2, [disable(207)]); // Swamp Palace B1 - Center Room
-    patcher.modify_objs(DungeonWater, 2, [disable(208)]); // Swamp Palace B1
-    patcher.modify_objs(DungeonWater, 2, [disable(209)]); // Swamp Palace B1 - Center Room
-    patcher.modify_objs(DungeonWater, 2, [disable(210)]); // Swamp Palace B1
-
-    patcher.modify_objs(DungeonDokuro, 1, [disable(240)]); // Skull Woods B1
-    patcher.modify_objs(DungeonDokuro, 1, [disable(332)]); // Skull Woods B1
-    patcher.modify_objs(DungeonDokuro, 2, [disable(223)]); // Skull Woods B2
-
-    patcher.modify_objs(DungeonHagure, 1, [disable(542)]); // Thieves' Hideout B2
-
-    patcher.modify_objs(DungeonKame, 2, [disable(116)]); // Turtle Rock B1
-    patcher.modify_objs(DungeonKame, 2, [disable(118)]); // Turtle Rock B1
-    patcher.modify_objs(DungeonKame, 2, [disable(229)]); // Turtle Rock B1
-
-    patcher.modify_objs(DungeonSand, 1, [disable(77)]); // Desert Palace 1F
-    patcher.modify_objs(DungeonSand, 1, [disable(419)]); // Desert Palace 1F
-    patcher.modify_objs(DungeonSand, 2, [disable(259)]); // Desert Palace 2F
-    patcher.modify_objs(DungeonSand, 2, [disable(463)]); // Desert Palace 2F
-    patcher.modify_objs(DungeonSand, 3, [disable(156)]); // Desert Palace 3F
-
-    patcher.modify_objs(DungeonIce, 1, [disable(116)]); // Ice Ruins B1
-    patcher.modify_objs(DungeonIce, 1, [disable(169)]); // Ice Ruins B1
-    patcher.modify_objs(DungeonIce, 1, [disable(230)]); // Ice Ruins B2
-
-    patcher.modify_objs(DungeonGanon, 1, [disable(416)]); // Lorule Castle
-    patcher.modify_objs(DungeonGanon, 1, [disable(990)]); // Lorule Castle
-    patcher.modify_objs(DungeonGanon, 1, [disable(1090)]); // Lorule Castle
-    patcher.modify_objs(DungeonGanon, 1, [disable(1104)]); // Lorule Castle
-    patcher.modify_objs(DungeonGanon, 1, [disable(1307)]); // Lorule Castle
+    for (_, course, stage, unq) in SMALL_KEY_DOORS {
+        patcher.modify_objs(course, stage, [disable(unq)]);
+    }
 }
 
 /// Big Keysy - Remove all huge doors
@@ -1333,16 +1282,9 @@ fn patch_keysy_big(patcher: &mut Patcher, settings: &Settings) {
         _ => return,
     }
 
-    patcher.modify_objs(DungeonEast, 2, [disable(26)]); // Eastern Palace 2F
-    patcher.modify_objs(DungeonWind, 3, [disable(401)]); // House of Gales 3F
-    patcher.modify_objs(DungeonHera, 1, [disable(740)]); // Tower of Hera 11F
-    patcher.modify_objs(DungeonDark, 1, [disable(38)]); // Dark Palace B1
-    patcher.modify_objs(DungeonWater, 1, [disable(29)]); // Swamp Palace 1F
-    patcher.modify_objs(DungeonDokuro, 2, [disable(106)]); // Skull Woods B2
-    patcher.modify_objs(DungeonHagure, 1, [disable(531)]); // Thieves' Hideout
-    patcher.modify_objs(DungeonKame, 2, [disable(28)]); // Turtle Rock B1
-    patcher.modify_objs(DungeonSand, 3, [disable(9)]); // Desert Palace 3F
-    patcher.modify_objs(DungeonIce, 1, [disable(291)]); // Ice Ruins B4
+    for (_, course, stage, unq) in BIG_KEY_DOORS {
+        patcher.modify_objs(course, stage, [disable(unq)]);
+    }
 }
 
 fn patch_cracksanity(patcher: &mut Patcher) {
@@ -1749,8 +1691,8 @@ fn patch_castles(patcher: &mut Patcher) {
         [
             set_disable_flag(4, Flag::TRIFORCE_OF_COURAGE),  // Trial's Door
             clear_enable_flag(12),                           // Yuga revives Ganon cutscene
-            set_disable_flag(12, Flag::TRIFORCE_OF_COURAGE), // Yuga revives Ganon cutscene
-            set_enable_flag(34, Flag::TRIFORCE_OF_COURAGE),  // Throne Room Loading Zone
+            set_disable_flag(12, Flag::YUGANON_REQUIREMENT), // Yuga revives Ganon cutscene
+            set_enable_flag(34, Flag::YUGANON_REQUIREMENT),  // Throne Room Loading Zone
             set_enable_flag(23, Flag::TRIFORCE_OF_COURAGE),  // Skull (top right, controller obj)
         ],
     );
@@ -1786,8 +1728,8 @@ fn patch_castles(patcher: &mut Patcher) {
         [
             // fight start trigger
             call(10, move |obj| {
-                obj.set_enable_flag(Flag::TRIFORCE_OF_COURAGE);
-                obj.set_active_flag(Flag::TRIFORCE_OF_COURAGE);
+                obj.set_enable_flag(Flag::YUGANON_REQUIREMENT);
+                obj.set_active_flag(Flag::YUGANON_REQUIREMENT);
             }),
             clear_enable_flag(27), // Hilda
             clear_enable_flag(41), // camera offset
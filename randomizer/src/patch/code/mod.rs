@@ -11,7 +11,10 @@ use rom::{flag::Flag, scene::SpawnPoint, ExHeader, RomRegion};
 
 use super::Patcher;
 use crate::{
-    filler::filler_item::{Item::*, Randomizable},
+    filler::{
+        filler_item::{Item as FillerItem, Item::*, Randomizable},
+        item_pools,
+    },
     patch::{
         code::arm::{
             b, bl,
@@ -183,9 +186,10 @@ pub fn create(patcher: &Patcher, seed_info: &SeedInfo) -> Code {
     }
 
     // Enable Y Button
+    let enable_y_button = starting_items(&mut code, &seed_info.settings, patcher.game.region());
     match patcher.game.region() {
-        RomRegion::US => code.text().patch(0x47B2C8, [mov(R0, 1)]),
-        RomRegion::EU => code.text().patch(0x47B2A0, [mov(R0, 1)]),
+        RomRegion::US => code.text().patch(0x47B2C8, [enable_y_button]),
+        RomRegion::EU => code.text().patch(0x47B2A0, [enable_y_button]),
     };
 
     // instant text
@@ -535,6 +539,83 @@ fn quake(code: &mut Code, region: RomRegion) {
     };
 }
 
+/// Grants the [`Settings::starting_items`] not already set by an Event Flag at game start, like Quake and the dungeon
+/// keys. Returns the instruction that enables the Y Button, which is where the items are granted.
+///
+/// Each item is given by calling its handler from the jump table of the item `Add()` function, like [`quake`] hooks
+/// into. The handlers are assumed to expect the player object in R4 and the item in R5, and all of them return through
+/// the function's epilogue that pops {R4, R5, R6, PC}, so each call pushes a matching frame. The items are granted the
+/// first time the game checks whether the Y Button is usable on a new file, then Flag 989 keeps them from being
+/// granted again.
+fn starting_items(code: &mut Code, settings: &Settings, region: RomRegion) -> Instruction {
+    let items = item_pools::get_starting_items(settings).into_iter().filter_map(added_item).collect::<Vec<_>>();
+    if items.is_empty() {
+        return mov(R0, 1);
+    }
+
+    let (add_item_table, fn_get_event_flag, fn_set_event_flag) = match region {
+        RomRegion::US => (0x344848, FN_GET_EVENT_FLAG_US, FN_SET_EVENT_FLAG_US),
+        RomRegion::EU => (0x344824, FN_GET_EVENT_FLAG_EU, FN_SET_EVENT_FLAG_EU),
+    };
+
+    // R0: item, R1: its entry in the jump table
+    let add_item = code.text().define([
+        push([R4, R5, R6, LR]),
+        mov(R5, R0),
+        ldr(R4, PLAYER_OBJECT_SINGLETON),
+        ldr(R4, (R4, 0x0)),
+        ldr(PC, (R1, 0x0)),
+    ]);
+
+    // Defined back to front, so each item can branch to the next one
+    let mut next = code.text().define([mov(R0, 1), pop([R4, PC])]);
+    for &item in items.iter().rev() {
+        next = code.text().define([
+            mov(R0, item as u32),
+            ldr(R1, add_item_table + 4 * item as u32),
+            bl(add_item),
+            b(next),
+        ]);
+    }
+
+    let grant_starting_items = code.text().define([
+        push([R4, LR]),
+        ldr(R0, EVENT_FLAG_PTR),
+        ldr(R0, (R0, 0x0)),
+        ldr(R1, Flag::STARTING_ITEMS_GRANTED.get_value()),
+        bl(fn_get_event_flag),
+        cmp(R0, 0x0),
+        mov(R0, 1).ne(),
+        pop([R4, PC]).ne(),
+        ldr(R0, EVENT_FLAG_PTR),
+        mov(R2, 0x1),
+        ldr(R1, Flag::STARTING_ITEMS_GRANTED.get_value()),
+        ldr(R0, (R0, 0x0)),
+        bl(fn_set_event_flag),
+        b(next),
+    ]);
+    bl(grant_starting_items)
+}
+
+/// The game item whose `Add()` handler grants the given starting item, if it isn't set by an Event Flag instead.
+///
+/// The game upgrades progressive items before calling `Add()`, so second copies name their upgrade directly.
+fn added_item(item: FillerItem) -> Option<Item> {
+    use FillerItem as F;
+    match item {
+        F::Lamp02 => Some(ItemKandelaarLv2),
+        F::Net02 => Some(ItemInsectNetLv2),
+        F::Glove02 => Some(PowerfulGlove),
+        F::PegasusBoots | F::Flippers | F::Bell | F::HintGlasses | F::Lamp01 | F::Net01 | F::Glove01 => {
+            Some(item.to_game_item())
+        },
+        F::HeartContainer01 | F::HeartContainer02 | F::HeartContainer03 | F::HeartContainer04 | F::HeartContainer05
+        | F::HeartContainer06 | F::HeartContainer07 | F::HeartContainer08 | F::HeartContainer09
+        | F::HeartContainer10 => Some(item.to_game_item()),
+        _ => None,
+    }
+}
+
 /// Mother Maiamai Stuff
 fn mother_maiamai(code: &mut Code, layout: &Layout, item_names: &HashMap<Item, u32>, region: RomRegion) {
     /// Use flags 302-311 (not 305) to record whether we've picked up that item's upgrade.
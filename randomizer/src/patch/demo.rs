@@ -1,8 +1,10 @@
+use crate::patch::dungeon_items;
 use crate::SeedInfo;
 use game::Course::*;
 use log::info;
 use modinfo::settings::cracks::Cracks;
 use modinfo::settings::cracksanity::Cracksanity;
+use modinfo::settings::starting_items::StartingItem;
 use modinfo::settings::trials_door::TrialsDoor;
 use modinfo::settings::weather_vanes::WeatherVanes::*;
 use rom::flag::Flag;
@@ -135,10 +137,13 @@ fn get_initial_flags_to_set(SeedInfo { trials_config, settings, .. }: &SeedInfo)
     ];
 
     // Cracks Open/Closed + Quake
-    if settings.cracks == Cracks::Open {
+    if settings.cracks == Cracks::Open || settings.starting_count(StartingItem::Quake) > 0 {
         flags.push(510);
     }
 
+    // Starting Small Keys and Big Keys
+    flags.extend(dungeon_items::starting_key_flags(settings).into_iter().map(Flag::get_value));
+
     // Ravio's Shop Open/Closed
     if settings.ravios_shop == modinfo::settings::ravios_shop::RaviosShop::Open {
         flags.extend(vec![
@@ -153,6 +158,11 @@ fn get_initial_flags_to_set(SeedInfo { trials_config, settings, .. }: &SeedInfo)
         flags.push(670);
     }
 
+    // Enable fighting Yuga Ganon from start
+    if settings.yuganon_requirement == 0 {
+        flags.push(Flag::YUGANON_REQUIREMENT.get_value());
+    }
+
     // Night Mode
     if settings.night_mode {
         flags.push(964);
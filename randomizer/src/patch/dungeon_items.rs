@@ -0,0 +1,173 @@
+use super::Patcher;
+use crate::filler::dungeons::{self, Dungeon};
+use crate::filler::filler_item::Randomizable;
+use crate::filler::item_pools;
+use crate::patch::util::set_disable_flag;
+use crate::SeedInfo;
+use game::Course::{self as CourseId, *};
+use log::info;
+use modinfo::Settings;
+use rom::flag::Flag;
+
+/// The Small Key-locked doors of each dungeon, with their stage (1-based) and unq.
+pub(crate) const SMALL_KEY_DOORS: [(Dungeon, CourseId, u16, u16); 40] = [
+    (Dungeon::HyruleSanctuary, CaveLight, 18, 60), // Hyrule Sewers
+    (Dungeon::EasternPalace, DungeonEast, 1, 114), // Eastern Palace 1F
+    (Dungeon::EasternPalace, DungeonEast, 2, 34), // Eastern Palace 2F
+    (Dungeon::HouseOfGales, DungeonWind, 1, 74), // House of Gales 1F
+    (Dungeon::HouseOfGales, DungeonWind, 2, 150), // House of Gales 2F
+    (Dungeon::HouseOfGales, DungeonWind, 2, 153), // House of Gales 2F
+    (Dungeon::HouseOfGales, DungeonWind, 3, 54), // House of Gales 3F
+    (Dungeon::TowerOfHera, DungeonHera, 1, 245), // Tower of Hera 3F
+    (Dungeon::TowerOfHera, DungeonHera, 1, 335), // Tower of Hera 7F
+    (Dungeon::LoruleSanctuary, AttractionDark, 2, 60), // Lorule Sewers
+    (Dungeon::DarkPalace, DungeonDark, 2, 26), // Dark Palace 1F
+    (Dungeon::DarkPalace, DungeonDark, 2, 231), // Dark Palace 1F
+    (Dungeon::DarkPalace, DungeonDark, 1, 108), // Dark Palace B1
+    (Dungeon::DarkPalace, DungeonDark, 1, 142), // Dark Palace B1
+    // Swamp B1 Key doors are two sided, so there are twice as many
+    (Dungeon::SwampPalace, DungeonWater, 2, 65), // Swamp Palace B1
+    (Dungeon::SwampPalace, DungeonWater, 2, 205), // Swamp Palace B1 - Center Room
+    (Dungeon::SwampPalace, DungeonWater, 2, 207), // Swamp Palace B1 - Center Room
+    (Dungeon::SwampPalace, DungeonWater, 2, 208), // Swamp Palace B1
+    (Dungeon::SwampPalace, DungeonWater, 2, 209), // Swamp Palace B1 - Center Room
+    (Dungeon::SwampPalace, DungeonWater, 2, 210), // Swamp Palace B1
+    (Dungeon::SkullWoods, DungeonDokuro, 1, 240), // Skull Woods B1
+    (Dungeon::SkullWoods, DungeonDokuro, 1, 332), // Skull Woods B1
+    (Dungeon::SkullWoods, DungeonDokuro, 2, 223), // Skull Woods B2
+    (Dungeon::ThievesHideout, DungeonHagure, 1, 542), // Thieves' Hideout B2
+    (Dungeon::TurtleRock, DungeonKame, 2, 116), // Turtle Rock B1
+    (Dungeon::TurtleRock, DungeonKame, 2, 118), // Turtle Rock B1
+    (Dungeon::TurtleRock, DungeonKame, 2, 229), // Turtle Rock B1
+    (Dungeon::DesertPalace, DungeonSand, 1, 77), // Desert Palace 1F
+    (Dungeon::DesertPalace, DungeonSand, 1, 419), // Desert Palace 1F
+    (Dungeon::DesertPalace, DungeonSand, 2, 259), // Desert Palace 2F
+    (Dungeon::DesertPalace, DungeonSand, 2, 463), // Desert Palace 2F
+    (Dungeon::DesertPalace, DungeonSand, 3, 156), // Desert Palace 3F
+    (Dungeon::IceRuins, DungeonIce, 1, 116), // Ice Ruins B1
+    (Dungeon::IceRuins, DungeonIce, 1, 169), // Ice Ruins B1
+    (Dungeon::IceRuins, DungeonIce, 1, 230), // Ice Ruins B2
+    (Dungeon::LoruleCastle, DungeonGanon, 1, 416), // Lorule Castle
+    (Dungeon::LoruleCastle, DungeonGanon, 1, 990), // Lorule Castle
+    (Dungeon::LoruleCastle, DungeonGanon, 1, 1090), // Lorule Castle
+    (Dungeon::LoruleCastle, DungeonGanon, 1, 1104), // Lorule Castle
+    (Dungeon::LoruleCastle, DungeonGanon, 1, 1307), // Lorule Castle
+];
+
+/// The huge doors opened by each dungeon's Big Key, with their stage (1-based) and unq.
+pub(crate) const BIG_KEY_DOORS: [(Dungeon, CourseId, u16, u16); 10] = [
+    (Dungeon::EasternPalace, DungeonEast, 2, 26), // Eastern Palace 2F
+    (Dungeon::HouseOfGales, DungeonWind, 3, 401), // House of Gales 3F
+    (Dungeon::TowerOfHera, DungeonHera, 1, 740), // Tower of Hera 11F
+    (Dungeon::DarkPalace, DungeonDark, 1, 38), // Dark Palace B1
+    (Dungeon::SwampPalace, DungeonWater, 1, 29), // Swamp Palace 1F
+    (Dungeon::SkullWoods, DungeonDokuro, 2, 106), // Skull Woods B2
+    (Dungeon::ThievesHideout, DungeonHagure, 1, 531), // Thieves' Hideout
+    (Dungeon::TurtleRock, DungeonKame, 2, 28), // Turtle Rock B1
+    (Dungeon::DesertPalace, DungeonSand, 3, 9), // Desert Palace 3F
+    (Dungeon::IceRuins, DungeonIce, 1, 291), // Ice Ruins B4
+];
+
+/// Dungeon items outside their own dungeon
+///
+/// The game credits a Small Key or Big Key to the dungeon it is found in, so a shuffled one instead sets a flag of its
+/// dungeon when picked up, see [`pickup_flag`]. That flag opens all of the dungeon's doors of that kind, whichever of
+/// its keys is found first. This never opens a door later than logic expects, as logic needs at least one key for any
+/// door. Key Rings set the same flag, standing in for every Small Key of their dungeon.
+///
+/// Keys the player starts with set the flag at game start, see [`starting_key_flags`].
+pub(crate) fn patch(patcher: &mut Patcher, SeedInfo { settings, .. }: &SeedInfo) {
+    info!("Patching Dungeon Item Doors...");
+
+    let starting_key_flags = starting_key_flags(settings);
+
+    for (dungeon, course, stage, unq) in SMALL_KEY_DOORS {
+        let key = dungeon.key_ring().filter(|_| settings.has_key_rings()).or(dungeon.small_keys().first().copied());
+        let flag = small_keys_flag(dungeon);
+        if key.is_some_and(|key| dungeons::needs_pickup_flag(settings, key)) || starting_key_flags.contains(&flag) {
+            patcher.modify_objs(course, stage, [set_disable_flag(unq, flag)]);
+        }
+    }
+
+    for (dungeon, course, stage, unq) in BIG_KEY_DOORS {
+        let Some(flag) = big_key_flag(dungeon) else {
+            continue;
+        };
+        let key = dungeon.big_key();
+        if key.is_some_and(|key| dungeons::needs_pickup_flag(settings, key)) || starting_key_flags.contains(&flag) {
+            patcher.modify_objs(course, stage, [set_disable_flag(unq, flag)]);
+        }
+    }
+}
+
+/// The flags of the dungeons whose keys the player starts with, set at game start to open their doors.
+///
+/// Like a shuffled key, any number of a dungeon's Small Keys opens all of its Small Key doors.
+pub(crate) fn starting_key_flags(settings: &Settings) -> Vec<Flag> {
+    let mut flags = item_pools::get_starting_items(settings)
+        .into_iter()
+        .filter_map(|item| {
+            let dungeon = Dungeon::of_item(item)?;
+            if dungeon.big_key() == Some(item) {
+                big_key_flag(dungeon)
+            } else if dungeon.small_keys().contains(&item) || dungeon.key_ring() == Some(item) {
+                Some(small_keys_flag(dungeon))
+            } else {
+                None
+            }
+        })
+        .collect::<Vec<_>>();
+    flags.dedup();
+    flags
+}
+
+/// The flag set when picking up the `item`, if it is a dungeon item that may be found outside its own dungeon.
+pub(crate) fn pickup_flag(settings: &Settings, item: Randomizable) -> Option<Flag> {
+    let Randomizable::Item(item) = item else {
+        return None;
+    };
+    if !dungeons::needs_pickup_flag(settings, item) {
+        return None;
+    }
+
+    let dungeon = Dungeon::of_item(item)?;
+    if dungeon.big_key() == Some(item) {
+        big_key_flag(dungeon)
+    } else {
+        Some(small_keys_flag(dungeon))
+    }
+}
+
+fn big_key_flag(dungeon: Dungeon) -> Option<Flag> {
+    match dungeon {
+        Dungeon::EasternPalace => Some(Flag::EASTERN_BIG_KEY),
+        Dungeon::HouseOfGales => Some(Flag::GALES_BIG_KEY),
+        Dungeon::TowerOfHera => Some(Flag::HERA_BIG_KEY),
+        Dungeon::DarkPalace => Some(Flag::DARK_BIG_KEY),
+        Dungeon::SwampPalace => Some(Flag::SWAMP_BIG_KEY),
+        Dungeon::SkullWoods => Some(Flag::SKULL_BIG_KEY),
+        Dungeon::ThievesHideout => Some(Flag::THIEVES_BIG_KEY),
+        Dungeon::IceRuins => Some(Flag::ICE_BIG_KEY),
+        Dungeon::DesertPalace => Some(Flag::DESERT_BIG_KEY),
+        Dungeon::TurtleRock => Some(Flag::TURTLE_BIG_KEY),
+        Dungeon::HyruleSanctuary | Dungeon::LoruleSanctuary | Dungeon::LoruleCastle => None,
+    }
+}
+
+fn small_keys_flag(dungeon: Dungeon) -> Flag {
+    match dungeon {
+        Dungeon::HyruleSanctuary => Flag::HYRULE_SANCTUARY_SMALL_KEYS,
+        Dungeon::EasternPalace => Flag::EASTERN_SMALL_KEYS,
+        Dungeon::HouseOfGales => Flag::GALES_SMALL_KEYS,
+        Dungeon::TowerOfHera => Flag::HERA_SMALL_KEYS,
+        Dungeon::LoruleSanctuary => Flag::LORULE_SANCTUARY_SMALL_KEYS,
+        Dungeon::DarkPalace => Flag::DARK_SMALL_KEYS,
+        Dungeon::SwampPalace => Flag::SWAMP_SMALL_KEYS,
+        Dungeon::SkullWoods => Flag::SKULL_SMALL_KEYS,
+        Dungeon::ThievesHideout => Flag::THIEVES_SMALL_KEYS,
+        Dungeon::IceRuins => Flag::ICE_SMALL_KEYS,
+        Dungeon::DesertPalace => Flag::DESERT_SMALL_KEYS,
+        Dungeon::TurtleRock => Flag::TURTLE_SMALL_KEYS,
+        Dungeon::LoruleCastle => Flag::LORULE_CASTLE_SMALL_KEYS,
+    }
+}
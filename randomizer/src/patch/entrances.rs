@@ -0,0 +1,72 @@
+use crate::filler::dungeon_entrances::{self, DungeonEntrance};
+use crate::{patch::util::*, Error, Patcher, Result, SeedInfo};
+use game::Course;
+use log::info;
+use rom::scene::{Obj, SpawnPoint};
+
+/// The Objs of a scene to redirect, and how.
+type Redirect = (Course, u16, Vec<(u16, Box<dyn Fn(&mut Obj)>)>);
+
+/// Points every dungeon entrance door at the dungeon it leads to, and every dungeon's main exit back out in front of
+/// that door.
+pub(crate) fn patch_dungeon_entrances(patcher: &mut Patcher, seed_info: &SeedInfo) -> Result<()> {
+    if dungeon_entrances::is_vanilla(&seed_info.dungeon_entrance_map) {
+        return Ok(());
+    }
+
+    info!("Patching Dungeon Entrances...");
+    let transitions = seed_info.transitions();
+
+    // The hard-coded Spawn Points must exist, or the redirected warps would softlock the player
+    for dungeon in DungeonEntrance::ALL {
+        check_spawn_point(patcher, dungeon.get_entry_spawn_point())?;
+        check_spawn_point(patcher, dungeon.get_exit_spawn_point())?;
+    }
+
+    // Find every warp first, so that no warp is redirected twice when two doors share a scene
+    let mut redirects = Vec::new();
+    for door in DungeonEntrance::ALL {
+        let (course, scene) = door.get_door_scene();
+        let dungeon = transitions.dungeon_behind(door);
+        let entry = dungeon.get_entry_spawn_point();
+        redirects.push(find_redirect(patcher, course, scene, door.get_entry_spawn_point(), entry)?);
+
+        let SpawnPoint { course, scene, .. } = dungeon.get_entry_spawn_point();
+        let exit = transitions.door_to(dungeon).get_exit_spawn_point();
+        redirects.push(find_redirect(patcher, course, scene as u16, dungeon.get_exit_spawn_point(), exit)?);
+    }
+
+    for (course, scene, actions) in redirects {
+        patcher.modify_objs(course, scene, actions);
+    }
+
+    Ok(())
+}
+
+/// Checks that the scene of the Spawn Point `sp` actually has it.
+fn check_spawn_point(patcher: &mut Patcher, sp: SpawnPoint) -> Result<()> {
+    if patcher.scene(sp.course, sp.scene as u16 - 1)?.stage().get().has_spawn_point(sp.spawn) {
+        Ok(())
+    } else {
+        Err(Error::game(format!("Could not find Spawn Point {:?} {} [{}]", sp.course, sp.scene, sp.spawn)))
+    }
+}
+
+/// Redirects the Objs of a scene that lead to the `vanilla` Spawn Point to the `shuffled` one instead.
+fn find_redirect(
+    patcher: &mut Patcher, course: Course, scene: u16, vanilla: SpawnPoint, shuffled: SpawnPoint,
+) -> Result<Redirect> {
+    let unqs = patcher.scene(course, scene - 1)?.stage().get().find_objs_leading_to(vanilla);
+    if unqs.is_empty() {
+        return Err(Error::game(format!(
+            "Could not find a warp to {:?} {} [{}] in {}{}",
+            vanilla.course,
+            vanilla.scene,
+            vanilla.spawn,
+            course.as_str(),
+            scene
+        )));
+    }
+
+    Ok((course, scene, unqs.into_iter().map(|unq| redirect(unq, shuffled)).collect()))
+}
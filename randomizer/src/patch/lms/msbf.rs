@@ -1,10 +1,12 @@
 use crate::patch::Patcher;
-use crate::{Result, SeedInfo};
+use crate::{Error, Result, SeedInfo};
 use game::Course;
 use log::info;
 use modinfo::settings::keysy::Keysy;
 use modinfo::Settings;
-use rom::string_constants;
+use rom::flag::Flag;
+use rom::flow::{Action, Step, StepMut};
+use rom::{string_constants, File};
 
 pub fn patch(patcher: &mut Patcher, seed_info: &SeedInfo) -> Result<()> {
     info!("Patching MSBF Files...");
@@ -54,11 +56,11 @@ where
             file?.get().research();
         }
     } else {
-        macros::fail!(
+        return Err(Error::internal(format!(
             "File not found: US{}.szs -> World/Flow/{}.msbf",
             if course.is_some() { "_English/".to_owned() + course.unwrap().as_str() } else { "/RegionBoot".to_owned() },
             file_name
-        );
+        )));
     };
 
     info!("Finished MSBF Research");
@@ -369,6 +371,8 @@ fn patch_hyrule_castle_zelda(patcher: &mut Patcher) -> Result<()> {
     Ok(())
 }
 
+/// Sets the number of Portraits each Sage's flowchart compares against before setting Flag 670, which opens Lorule
+/// Castle, and adds a second comparison against the Yuga Ganon Requirement, which opens the Throne Room.
 fn patch_lorule_castle_requirements(patcher: &mut Patcher, settings: &Settings) -> Result<()> {
     let lc_requirement = settings.lc_requirement as u32;
 
@@ -389,6 +393,93 @@ fn patch_lorule_castle_requirements(patcher: &mut Patcher, settings: &Settings)
         DungeonIce/Ice     {[3 into_branch] value(lc_requirement),},
     );
 
+    let yuganon_requirement = settings.yuganon_requirement as u32;
+    add_yuganon_requirement(patcher, Course::DungeonDark, "Dark", 2, yuganon_requirement)?;
+    add_yuganon_requirement(patcher, Course::DungeonWater, "Water", 3, yuganon_requirement)?;
+    add_yuganon_requirement(patcher, Course::FieldDark, "Dokuro", 3, yuganon_requirement)?;
+    add_yuganon_requirement(patcher, Course::IndoorDark, "Hagure", 4, yuganon_requirement)?;
+    add_yuganon_requirement(patcher, None, "Kame", 3, yuganon_requirement)?;
+    add_yuganon_requirement(patcher, Course::FieldDark, "Sand", 3, yuganon_requirement)?;
+    add_yuganon_requirement(patcher, Course::DungeonIce, "Ice", 3, yuganon_requirement)?;
+
+    Ok(())
+}
+
+/// Appends a copy of a Sage flowchart's Lorule Castle comparison at `branch` to both of its outcomes, comparing the
+/// Portraits against the Yuga Ganon Requirement instead and setting [`Flag::YUGANON_REQUIREMENT`] when it's met.
+fn add_yuganon_requirement<C>(
+    patcher: &mut Patcher, course: C, name: &str, branch: u16, yuganon_requirement: u32,
+) -> Result<()>
+where
+    C: Into<Option<Course>>,
+{
+    /// Flag 670, as flowcharts refer to it through the game's flag lookup table
+    const LC_FLAG: u32 = 629;
+
+    // Flag 828 is left unchanged by the lookup table
+    let yuganon_flag = Flag::YUGANON_REQUIREMENT.get_value() as u32;
+
+    let course = course.into();
+    let language = patcher.language(course)?;
+    let not_found = || Error::game(format!("Could not find the Lorule Castle Requirement in '{}'.", name));
+
+    // Find which outcome of the comparison sets Flag 670, and where each outcome continues afterwards
+    let (met, set_flag, met_next, unmet_next, (step_ct, branch_ct)) = {
+        let flow = language.flow();
+        let flow = flow.get(name).ok_or_else(|| Error::game("File not found."))??;
+        let flow = flow.get();
+        let Some(Step::Branch { branches, .. }) = flow.steps().get(branch).transpose()? else {
+            return Err(not_found());
+        };
+        let [first, second] = branches[..] else {
+            return Err(not_found());
+        };
+        let sets_flag = |to: Option<u16>| match flow.steps().get(to?)? {
+            Ok(Step::Action { command: Action::Other(_, LC_FLAG), next }) => Some(Ok((to?, next))),
+            Ok(_) => None,
+            Err(err) => Some(Err(err)),
+        };
+        match (sets_flag(first).transpose()?, sets_flag(second).transpose()?) {
+            (Some((set_flag, next)), None) => (0, set_flag, next, second, flow.counts()),
+            (None, Some((set_flag, next))) => (1, set_flag, next, first, flow.counts()),
+            _ => return Err(not_found()),
+        }
+    };
+
+    let path = format!("World/Flow/{}.msbf", name);
+    let file = language.flow().extract(&path).ok_or_else(|| Error::game("File not found."))?;
+    let file = rom::flow::append_steps(file.get(), &[branch, set_flag, branch, set_flag], 4)?;
+    language.update(File::new(path, file))?;
+
+    let (met_branch, met_set_flag, unmet_branch, unmet_set_flag) = (step_ct, step_ct + 1, step_ct + 2, step_ct + 3);
+    let branch_index = |offset: u16| {
+        u8::try_from(branch_ct + offset).map_err(|_| Error::game(format!("Too many branches in '{}'.", name)))
+    };
+    let (met_branches, unmet_branches) = (branch_index(0)?, branch_index(2)?);
+
+    let mut flow = patcher.flow(course)?;
+    let mut flow = flow.get_mut(name).ok_or_else(|| Error::game("File not found."))??;
+    let flow = flow.get_mut();
+
+    // Both outcomes of the Lorule Castle comparison continue into their own Yuga Ganon comparison
+    let mut comparison = flow.get_mut(branch).and_then(StepMut::into_branch).ok_or_else(not_found)?;
+    comparison.set_branch(1 - met, unmet_branch)?;
+    flow.get_mut(set_flag).and_then(StepMut::into_action).ok_or_else(not_found)?.set_next(met_branch);
+
+    for (copy, copy_branches, set_flag, next) in
+        [(met_branch, met_branches, met_set_flag, met_next), (unmet_branch, unmet_branches, unmet_set_flag, unmet_next)]
+    {
+        let mut copy =
+            flow.get_mut(copy).and_then(|step| step.convert_into_branch(2, copy_branches)).ok_or_else(not_found)?;
+        copy.set_value(yuganon_requirement);
+        copy.set_branch(met, set_flag)?;
+        copy.set_branch(1 - met, next)?;
+
+        let mut set_flag = flow.get_mut(set_flag).and_then(StepMut::into_action).ok_or_else(not_found)?;
+        set_flag.set_value(yuganon_flag);
+        set_flag.set_next(next);
+    }
+
     Ok(())
 }
 
@@ -3,7 +3,7 @@ use crate::filler::filler_item::Item::{
     SageRosso, SageSeres,
 };
 use crate::{
-    hints::{formatting::*, Hint},
+    hints::{behind_door, formatting::*, Hint},
     patch::messages::{hint_ghosts::HintGhost, msbt::load_msbt},
     regions, Patcher, Result, SeedInfo,
 };
@@ -166,7 +166,7 @@ fn patch_actions(patcher: &mut Patcher) -> Result<()> {
     Ok(())
 }
 
-/// Ravio
+/// Ravio gives out the locations of the Sages, and how many are needed for Lorule Castle and Yuganon
 fn patch_ravio(patcher: &mut Patcher, seed_info: &SeedInfo) -> Result<()> {
     let (gulley, _) = seed_info.layout.find_single(SageGulley).unwrap();
     let (oren, _) = seed_info.layout.find_single(SageOren).unwrap();
@@ -176,16 +176,22 @@ fn patch_ravio(patcher: &mut Patcher, seed_info: &SeedInfo) -> Result<()> {
     let (irene, _) = seed_info.layout.find_single(SageIrene).unwrap();
     let (rosso, _) = seed_info.layout.find_single(SageRosso).unwrap();
 
-    let first_intro = &format!("What's that? You're looking for the\n{}?", name("Seven Sages"));
-    let second_intro = &format!("Yeah...if you're looking for those\n{}?", name("Seven Sages"));
+    let SeedInfo { settings, .. } = seed_info;
+    let requirements = format!(
+        "\nLorule Castle needs {}, Yuganon {}.",
+        name(&settings.lc_requirement.to_string()),
+        name(&settings.yuganon_requirement.to_string())
+    );
+    let first_intro = &format!("What's that? You're looking for the\n{}?{}", name("Seven Sages"), requirements);
+    let second_intro = &format!("Yeah...if you're looking for those\n{}?{}", name("Seven Sages"), requirements);
 
-    let gulley = &format!("\n{} is in the {}.", green("Gulley"), green(gulley));
-    let oren = &format!("\n{} is in the {}.", beige("Queen Oren"), beige(oren));
-    let seres = &format!("\n{} is in the {}.", blue("Seres"), blue(seres));
-    let osfala = &format!("\n{} is in the {}.", beige("Osfala"), beige(osfala));
-    let impa = &format!("\n{} is in the {}.", purple("Lady Impa"), purple(impa));
-    let irene = &format!("\n{} is in the {}.", name("Irene"), name(irene));
-    let rosso = &format!("\n{} is in the {}.", attention("Rosso"), attention(rosso));
+    let gulley = &format!("\n{} is in the {}{}.", green("Gulley"), green(gulley), entered_from(seed_info, gulley));
+    let oren = &format!("\n{} is in the {}{}.", beige("Queen Oren"), beige(oren), entered_from(seed_info, oren));
+    let seres = &format!("\n{} is in the {}{}.", blue("Seres"), blue(seres), entered_from(seed_info, seres));
+    let osfala = &format!("\n{} is in the {}{}.", beige("Osfala"), beige(osfala), entered_from(seed_info, osfala));
+    let impa = &format!("\n{} is in the {}{}.", purple("Lady Impa"), purple(impa), entered_from(seed_info, impa));
+    let irene = &format!("\n{} is in the {}{}.", name("Irene"), name(irene), entered_from(seed_info, irene));
+    let rosso = &format!("\n{} is in the {}{}.", attention("Rosso"), attention(rosso), entered_from(seed_info, rosso));
 
     let mut ravio_shop = load_msbt(patcher, IndoorLight, "FieldLight_2C")?;
 
@@ -328,18 +334,22 @@ fn patch_sahasrahla(patcher: &mut Patcher, seed_info: &SeedInfo) -> Result<()> {
     let (power, _) = seed_info.layout.find_single(PendantOfPower).unwrap();
     let (wisdom, _) = seed_info.layout.find_single(PendantOfWisdom).unwrap();
     let (courage, _) = seed_info.layout.find_single(PendantOfCourage).unwrap();
+    let (power_door, wisdom_door, courage_door) =
+        (entered_from(seed_info, power), entered_from(seed_info, wisdom), entered_from(seed_info, courage));
 
     let mut sahasrahla = load_msbt(patcher, IndoorLight, "FieldLight_18")?;
 
     sahasrahla.set(
         "lgt_Sahasrahla_first_03",
-        &format!("The {} is in the\n{}.", attention("Pendant of Power"), attention(power)),
+        &format!("The {} is in the\n{}{}.", attention("Pendant of Power"), attention(power), power_door),
+    );
+    sahasrahla.set(
+        "lgt_Sahasrahla_first_12",
+        &format!("The {} is in the\n{}{}.", name("Pendant of Wisdom"), name(wisdom), wisdom_door),
     );
-    sahasrahla
-        .set("lgt_Sahasrahla_first_12", &format!("The {} is in the\n{}.", name("Pendant of Wisdom"), name(wisdom)));
     sahasrahla.set(
         "lgt_Sahasrahla_first_10",
-        &format!("And the {} is in the\n{}.", green("Pendant of Courage"), green(courage)),
+        &format!("And the {} is in the\n{}{}.", green("Pendant of Courage"), green(courage), courage_door),
     );
 
     patcher.update(sahasrahla.dump())?;
@@ -347,6 +357,11 @@ fn patch_sahasrahla(patcher: &mut Patcher, seed_info: &SeedInfo) -> Result<()> {
     Ok(())
 }
 
+/// Which door leads to the dungeon `region` when Dungeon Entrances are shuffled, to follow the dungeon's name in a hint.
+fn entered_from(seed_info: &SeedInfo, region: &str) -> String {
+    behind_door(seed_info.transitions().shuffled_door_to(region), "\n")
+}
+
 fn patch_hint_ghosts(patcher: &mut Patcher, seed_info: &SeedInfo) -> Result<()> {
     if seed_info.hints.always_hints.is_empty() {
         info!("No Ghost Hints generated.");
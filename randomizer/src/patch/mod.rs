@@ -7,8 +7,7 @@ use game::{
     Course::{self as CourseId, *},
     Item, World,
 };
-use log::{debug, error, info};
-use macros::fail;
+use log::{debug, info};
 use modinfo::settings::cracksanity::Cracksanity;
 use modinfo::settings::weather_vanes::WeatherVanes::*;
 use path_absolutize::*;
@@ -30,6 +29,8 @@ mod actors;
 mod byaml;
 mod code;
 mod demo;
+mod dungeon_items;
+mod entrances;
 pub mod lms;
 mod messages;
 mod prizes;
@@ -264,6 +265,29 @@ impl Patcher {
     where
         F: Into<Option<Randomizable>> + Clone,
     {
+        // Dungeon items outside their own dungeon record their pickup in the active flag of the object holding them
+        let item = filler_item.clone().into();
+        if let Some(flag) = item.and_then(|item| dungeon_items::pickup_flag(&seed_info.settings, item)) {
+            match &patch {
+                Patch::Chest { course, stage: scene, unq }
+                | Patch::BigChest { course, stage: scene, unq }
+                | Patch::Heart { course, scene, unq }
+                | Patch::Key { course, scene, unq }
+                | Patch::SilverRupee { course, scene, unq }
+                | Patch::GoldRupee { course, scene, unq } => {
+                    self.modify_objs(*course, scene + 1, [set_46_args(*unq, flag)]);
+                },
+                Patch::Multi(_) => {},
+                patch => {
+                    return Err(Error::invalid_settings(format!(
+                        "{} must be placed in a chest or freestanding to open its dungeon's doors, found on: {:?}",
+                        item.unwrap().as_str(),
+                        patch
+                    )));
+                },
+            }
+        }
+
         match patch {
             Patch::Chest { course, stage, unq } => {
                 self.prep_chest(filler_item.into().unwrap(), course, stage, unq, false, seed_info)?;
@@ -613,6 +637,7 @@ impl Patcher {
         messages::patch_messages(&mut self, seed_info)?;
         let prizes = get_dungeon_prizes(&seed_info.layout);
         prizes::patch_dungeon_prizes(&mut self, &prizes);
+        entrances::patch_dungeon_entrances(&mut self, seed_info)?;
         // byaml::get_item::patch(&mut self)?;
         byaml::course::patch(&mut self, &prizes, seed_info);
         byaml::stage::patch(&mut self, seed_info)?;
@@ -683,14 +708,12 @@ impl Patcher {
 #[deprecated]
 pub fn research_msbf_msbt<C>(
     patcher: &mut Patcher, msbf_course: C, msbf_file: &str, msbt_course: game::Course, msbt_file: &str, edotor: bool,
-) where
+) -> Result<()>
+where
     C: Into<Option<game::Course>>,
 {
     let labels = messages::research(patcher, msbt_course, msbt_file, edotor);
-    lms::msbf::research(patcher, msbf_course, msbf_file, labels, edotor);
-
-    info!("Early Debug Exit");
-    std::process::exit(0);
+    lms::msbf::research(patcher, msbf_course, msbf_file, labels, edotor)
 }
 
 #[derive(Debug)]
@@ -724,6 +747,21 @@ impl Patch {
     {
         patcher.apply(self, seed_info, filler_item.into())
     }
+
+    /// Whether this places its item in objects that record their pickup in their active flag, i.e. chests and
+    /// freestanding items.
+    pub fn has_pickup_flag(&self) -> bool {
+        match self {
+            Patch::Chest { .. }
+            | Patch::BigChest { .. }
+            | Patch::Heart { .. }
+            | Patch::Key { .. }
+            | Patch::SilverRupee { .. }
+            | Patch::GoldRupee { .. } => true,
+            Patch::Multi(patches) => !patches.is_empty() && patches.iter().all(Patch::has_pickup_flag),
+            _ => false,
+        }
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -756,15 +794,15 @@ impl Patches {
         println!();
         info!("Writing Patch Files to:         {}\\{:016X}", &path.absolutize()?.display(), self.game.id());
 
-        match fs_extra::copy_items(&[moddir], path, &CopyOptions { overwrite: true, ..Default::default() })
-            .map_err(Error::io)
-        {
+        match fs_extra::copy_items(&[moddir], path, &CopyOptions { overwrite: true, ..Default::default() }) {
             Ok(_) => Ok(()),
-            Err(_) => {
-                error!("Couldn't write to:              {}", path.display());
-                error!("Please check that config.json points to a valid output destination.");
-                fail!();
-            },
+            Err(err) => Err(Error::output_not_writable(format!(
+                "Couldn't write to: {}\n\
+                Please check that config.json points to a valid output destination.\n\
+                Full Error: {}",
+                path.display(),
+                err
+            ))),
         }
     }
 }
@@ -8,7 +8,6 @@ use crate::{
 };
 use game::Course::{self, *};
 use log::info;
-use macros::fail;
 use rom::flag::Flag;
 use rom::{
     byaml,
@@ -778,7 +777,7 @@ impl PrizePatchData {
             Item(PendantOfPower) => Self::new(173, Flag::Event(372), 0.0, 0, 0, 0),
             Item(PendantOfWisdom) => Self::new(173, Flag::Event(342), 0.0, 1, 0, 0),
             Item(PendantOfCourage) => Self::new(173, Flag::Event(251), 0.0, 2, 0, 0),
-            _ => fail!("\"{}\" is not a dungeon prize.", prize.as_str()),
+            _ => unreachable!("\"{}\" is not a dungeon prize.", prize.as_str()),
         }
     }
 }
@@ -794,7 +793,7 @@ fn reroute_sage_warp(patcher: &mut Patcher, prize: Randomizable, sp: SpawnPoint)
         Item(SageIrene) => Some(70),
         Item(SageImpa) => Some(68),
         Item(PendantOfPower) | Item(PendantOfWisdom) | Item(PendantOfCourage) => None,
-        _ => fail!("\"{}\" is not a dungeon prize.", prize.as_str()),
+        _ => unreachable!("\"{}\" is not a dungeon prize.", prize.as_str()),
     };
 
     // Reroute
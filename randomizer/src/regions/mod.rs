@@ -136,6 +136,89 @@ pub(crate) fn patch(patcher: &mut Patcher, seed_info: &SeedInfo) -> crate::Resul
     Ok(())
 }
 
+/// The [`Patch`] of every location, keyed by check name.
+///
+/// [`Patch`]: crate::patch::Patch
+pub(crate) fn patches() -> Vec<(&'static str, crate::patch::Patch)> {
+    [
+        dungeons::dark::patches(),
+        dungeons::desert::patches(),
+        dungeons::eastern::patches(),
+        dungeons::house::patches(),
+        dungeons::hyrule::patches(),
+        dungeons::ice::patches(),
+        dungeons::lorule::patches(),
+        dungeons::skull::patches(),
+        dungeons::swamp::patches(),
+        dungeons::thieves::patches(),
+        dungeons::tower::patches(),
+        dungeons::turtle::patches(),
+
+        hyrule::death::patches(),
+        hyrule::desert::patches(),
+        hyrule::eastern::patches(),
+        hyrule::field::patches(),
+        hyrule::irene::patches(),
+        hyrule::kakariko::patches(),
+        hyrule::lake::patches(),
+        hyrule::lost::patches(),
+        hyrule::ravio::patches(),
+        hyrule::river::patches(),
+        hyrule::southern::patches(),
+
+        lorule::dark::patches(),
+        lorule::death::patches(),
+        lorule::field::patches(),
+        lorule::graveyard::patches(),
+        lorule::lake::patches(),
+        lorule::misery::patches(),
+        lorule::skull::patches(),
+    ]
+    .concat()
+}
+
+/// Finds a [`Subregion`] by its path within this module, e.g. `dungeons::eastern::palace`.
+pub fn find_subregion(path: &str) -> Option<&'static Subregion> {
+    let (region, id) = path.rsplit_once("::")?;
+    let subregions = match region {
+        "dungeons::dark" => dungeons::dark::SUBREGIONS,
+        "dungeons::desert" => dungeons::desert::SUBREGIONS,
+        "dungeons::eastern" => dungeons::eastern::SUBREGIONS,
+        "dungeons::house" => dungeons::house::SUBREGIONS,
+        "dungeons::hyrule" => dungeons::hyrule::SUBREGIONS,
+        "dungeons::ice" => dungeons::ice::SUBREGIONS,
+        "dungeons::lorule" => dungeons::lorule::SUBREGIONS,
+        "dungeons::skull" => dungeons::skull::SUBREGIONS,
+        "dungeons::swamp" => dungeons::swamp::SUBREGIONS,
+        "dungeons::thieves" => dungeons::thieves::SUBREGIONS,
+        "dungeons::tower" => dungeons::tower::SUBREGIONS,
+        "dungeons::turtle" => dungeons::turtle::SUBREGIONS,
+
+        "hyrule::death" => hyrule::death::SUBREGIONS,
+        "hyrule::desert" => hyrule::desert::SUBREGIONS,
+        "hyrule::eastern" => hyrule::eastern::SUBREGIONS,
+        "hyrule::field" => hyrule::field::SUBREGIONS,
+        "hyrule::irene" => hyrule::irene::SUBREGIONS,
+        "hyrule::kakariko" => hyrule::kakariko::SUBREGIONS,
+        "hyrule::lake" => hyrule::lake::SUBREGIONS,
+        "hyrule::lost" => hyrule::lost::SUBREGIONS,
+        "hyrule::ravio" => hyrule::ravio::SUBREGIONS,
+        "hyrule::river" => hyrule::river::SUBREGIONS,
+        "hyrule::southern" => hyrule::southern::SUBREGIONS,
+
+        "lorule::dark" => lorule::dark::SUBREGIONS,
+        "lorule::death" => lorule::death::SUBREGIONS,
+        "lorule::field" => lorule::field::SUBREGIONS,
+        "lorule::graveyard" => lorule::graveyard::SUBREGIONS,
+        "lorule::lake" => lorule::lake::SUBREGIONS,
+        "lorule::misery" => lorule::misery::SUBREGIONS,
+        "lorule::skull" => lorule::skull::SUBREGIONS,
+        _ => return None,
+    };
+
+    subregions.iter().find(|(subregion_id, _)| *subregion_id == id).map(|(_, subregion)| *subregion)
+}
+
 #[doc(hidden)]
 #[macro_export]
 macro_rules! region {
@@ -154,9 +237,20 @@ macro_rules! region {
             Ok(())
         }
 
+        /// The [`Patch`] of every location in this region, keyed by check name.
+        ///
+        /// [`Patch`]: $crate::patch::Patch
+        pub fn patches() -> Vec<(&'static str, $crate::patch::Patch)> {
+            [$start::patches(), $($id::patches(),)*].concat()
+        }
+
         $crate::subregion!($start $start_props);
         $($crate::subregion!($id $props);)*
 
+        /// Every Subregion of this region, keyed by its id.
+        pub const SUBREGIONS: &[(&str, &$crate::regions::Subregion)] =
+            &[(stringify!($start), $start::SUBREGION), $((stringify!($id), $id::SUBREGION),)*];
+
         pub const NAME: &str = $name;
         pub const COLOR: $crate::hints::hint_color::HintColor = $crate::hints::hint_color::HintColor::$color;
         #[allow(unused)]
@@ -202,6 +296,15 @@ macro_rules! subregion {
                 )?;)*)?
                 Ok(())
             }
+
+            #[allow(unused)]
+            pub fn patches() -> Vec<(&'static str, $crate::patch::Patch)> {
+                #[allow(unused_mut)]
+                let mut patches = vec![];
+                $(use $crate::patch::Patch;
+                $(patches.push(($key, $crate::patch!($variant $props)));)*)?
+                patches
+            }
         }
     };
 }
@@ -0,0 +1,225 @@
+//! Upgrades presets written by older versions of the randomizer to the current preset format.
+//!
+//! Each [`Migration`] rewrites the raw JSON of a preset's `settings` before it is deserialized, so that old presets
+//! keep working across releases. Only the migrations introduced after the version that wrote a preset are run on it.
+
+use crate::constants::VERSION;
+use log::warn;
+use serde_json::{Map, Value};
+
+/// A change to the preset format.
+struct Migration {
+    /// The first release writing presets in the new format. Presets from this version onward are left alone.
+    version: Version,
+    /// Rewrites the `settings` object, returning a warning describing each change it made.
+    migrate: fn(&mut Map<String, Value>) -> Warnings,
+}
+
+/// A warning describing each change made by a [`Migration`], or why the preset couldn't be converted.
+type Warnings = Result<Vec<String>, String>;
+
+/// All known changes to the preset format, oldest first. Everything up to v0.3.1 used the same format.
+const MIGRATIONS: &[Migration] = &[
+    Migration { version: Version(0, 4, 0), migrate: flatten_settings },
+    Migration { version: Version(0, 4, 0), migrate: rename_fields },
+    Migration { version: Version(0, 4, 0), migrate: ped_requirement_name },
+    Migration { version: Version(0, 4, 0), migrate: ravios_shop_not_configurable },
+];
+
+/// Upgrades the given preset JSON to the current [`VERSION`], logging a warning for each change made.
+///
+/// Fails if the preset's version can't be read, or it was made by a newer version of the randomizer.
+pub(crate) fn migrate(preset: &mut Value) -> Result<(), String> {
+    let preset = preset.as_object_mut().ok_or("Preset is not a JSON object")?;
+
+    let preset_version = match preset.get("version") {
+        Some(Value::String(version)) if version == VERSION => return Ok(()),
+        Some(Value::String(version)) => version.clone(),
+        Some(_) => return Err("Preset \"version\" is not a string".to_owned()),
+        None => return Err("Preset has no \"version\"".to_owned()),
+    };
+
+    let from = Version::parse(&preset_version)
+        .ok_or_else(|| format!("Unrecognized Preset version: \"{}\"", preset_version))?;
+    let current = Version::parse(VERSION).expect("Invalid VERSION");
+    if from > current {
+        return Err(format!(
+            "Preset version \"{}\" is newer than this Randomizer (\"{}\") and cannot be converted.",
+            preset_version, VERSION
+        ));
+    }
+
+    warn!("Converting Preset from version \"{}\" to \"{}\"", preset_version, VERSION);
+
+    if let Some(settings) = preset.get_mut("settings") {
+        let settings = settings.as_object_mut().ok_or("Preset \"settings\" is not a JSON object")?;
+        for migration in MIGRATIONS.iter().filter(|migration| migration.version > from) {
+            for warning in (migration.migrate)(settings)? {
+                warn!("{}", warning);
+            }
+        }
+    }
+
+    preset.insert("version".to_owned(), Value::String(VERSION.to_owned()));
+
+    Ok(())
+}
+
+/// v0.4.0: Settings are no longer split into `logic` and `options` groups.
+fn flatten_settings(settings: &mut Map<String, Value>) -> Warnings {
+    let mut warnings = Vec::new();
+
+    for group in ["logic", "options"] {
+        match settings.remove(group) {
+            None => {},
+            Some(Value::Object(fields)) => {
+                for (key, value) in fields {
+                    if settings.contains_key(&key) {
+                        return Err(format!("\"{}\" is defined both inside and outside of \"{}\"", key, group));
+                    }
+                    settings.insert(key, value);
+                }
+                warnings.push(format!("Settings in \"{}\" were moved up into \"settings\"", group));
+            },
+            Some(_) => return Err(format!("\"{}\" is not a JSON object", group)),
+        }
+    }
+
+    Ok(warnings)
+}
+
+/// v0.4.0: Several settings were renamed.
+fn rename_fields(settings: &mut Map<String, Value>) -> Warnings {
+    const RENAMED: [(&str, &str); 2] =
+        [("lorule_castle_requirement", "lc_requirement"), ("randomize_dungeon_prizes", "dungeon_prize_shuffle")];
+
+    let mut warnings = Vec::new();
+
+    for (old, new) in RENAMED {
+        if let Some(value) = settings.remove(old) {
+            if settings.contains_key(new) {
+                return Err(format!("Both \"{}\" and its replacement \"{}\" are defined", old, new));
+            }
+            settings.insert(new.to_owned(), value);
+            warnings.push(format!("\"{}\" was renamed to \"{}\"", old, new));
+        }
+    }
+
+    Ok(warnings)
+}
+
+/// v0.4.0: `ped_requirement` is named instead of being the number of Pendants required.
+fn ped_requirement_name(settings: &mut Map<String, Value>) -> Warnings {
+    let Some(value) = settings.get_mut("ped_requirement") else {
+        return Ok(vec![]);
+    };
+
+    let name = match value.as_u64() {
+        None => return Ok(vec![]),
+        Some(2) => "Vanilla",
+        Some(3) => "Standard",
+        Some(n) => return Err(format!("Invalid \"ped_requirement\": {}", n)),
+    };
+
+    let warning = format!("\"ped_requirement\": {} is now written as \"{}\"", value, name);
+    *value = Value::String(name.to_owned());
+
+    Ok(vec![warning])
+}
+
+/// v0.4.0: `ravios_shop` can no longer be set from a preset, the shop always starts open.
+fn ravios_shop_not_configurable(settings: &mut Map<String, Value>) -> Warnings {
+    Ok(match settings.remove("ravios_shop") {
+        Some(Value::String(value)) if value == "Open" => vec![],
+        Some(value) => vec![format!("\"ravios_shop\": {} is no longer supported, Ravio's Shop will be Open", value)],
+        None => vec![],
+    })
+}
+
+/// A release version number, ignoring any suffix such as " - SNAPSHOT".
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+struct Version(u16, u16, u16);
+
+impl Version {
+    fn parse(version: &str) -> Option<Self> {
+        let version = version.trim();
+        let number = version.strip_prefix('v').unwrap_or(version).split_whitespace().next()?;
+        let mut parts = number.split('.').map(str::parse);
+        let version = Self(parts.next()?.ok()?, parts.next()?.ok()?, parts.next().unwrap_or(Ok(0)).ok()?);
+
+        parts.next().is_none().then_some(version)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn parses_versions() {
+        assert_eq!(Version::parse("v0.4.0 - SNAPSHOT"), Some(Version(0, 4, 0)));
+        assert_eq!(Version::parse("0.3.1"), Some(Version(0, 3, 1)));
+        assert_eq!(Version::parse("v0.3"), Some(Version(0, 3, 0)));
+        assert_eq!(Version::parse("v0.3.1.2"), None);
+        assert_eq!(Version::parse("latest"), None);
+    }
+
+    #[test]
+    fn migrates_old_preset() {
+        let mut preset = json!({
+            "version": "v0.3.1",
+            "settings": {
+                "logic": {
+                    "lorule_castle_requirement": 5,
+                    "randomize_dungeon_prizes": true,
+                    "ped_requirement": 2,
+                    "yuganon_requirement": 6,
+                },
+                "options": {
+                    "ravios_shop": "Closed",
+                    "night_mode": true,
+                },
+            },
+        });
+
+        migrate(&mut preset).unwrap();
+
+        assert_eq!(
+            preset,
+            json!({
+                "version": VERSION,
+                "settings": {
+                    "lc_requirement": 5,
+                    "dungeon_prize_shuffle": true,
+                    "ped_requirement": "Vanilla",
+                    "yuganon_requirement": 6,
+                    "night_mode": true,
+                },
+            })
+        );
+    }
+
+    #[test]
+    fn skips_migrations_already_applied() {
+        let settings = json!({ "ped_requirement": 2, "ravios_shop": "Closed" });
+        let mut preset = json!({ "version": "v0.4.0", "settings": settings.clone() });
+
+        migrate(&mut preset).unwrap();
+
+        assert_eq!(preset, json!({ "version": VERSION, "settings": settings }));
+    }
+
+    #[test]
+    fn rejects_unconvertible_presets() {
+        for preset in [
+            json!({ "settings": {} }),
+            json!({ "version": "v99.0.0", "settings": {} }),
+            json!({ "version": "v0.3.1", "settings": { "ped_requirement": 4 } }),
+            json!({ "version": "v0.3.1", "settings": { "lc_requirement": 7, "lorule_castle_requirement": 7 } }),
+            json!({ "version": "v0.3.1", "settings": { "logic": { "night_mode": true }, "night_mode": true } }),
+        ] {
+            assert!(migrate(&mut preset.clone()).is_err(), "{}", preset);
+        }
+    }
+}
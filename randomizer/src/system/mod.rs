@@ -0,0 +1,296 @@
+use std::{
+    error::Error as StdError,
+    fmt::{self, Display, Formatter},
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use json_comments::StripComments;
+use log::info;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::constants::CONFIG_FILE_NAME;
+use crate::SeedInfo;
+use modinfo::settings::mystery::MysteryWeights;
+use modinfo::Settings;
+
+mod migration;
+
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+#[derive(Debug)]
+pub struct Error {
+    inner: Box<dyn StdError + Send + Sync + 'static>,
+}
+
+impl Error {
+    pub fn new<E>(err: E) -> Self
+    where
+        E: Into<Box<dyn StdError + Send + Sync + 'static>>,
+    {
+        Self { inner: err.into() }
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.inner.fmt(f)
+    }
+}
+
+impl StdError for Error {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        Some(self.inner.as_ref())
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(err: io::Error) -> Self {
+        Self { inner: err.into() }
+    }
+}
+
+pub struct System;
+
+impl System {
+    /// Loads a preset, either by name from the `presets` directory or from a path to any preset file.
+    ///
+    /// A preset may inherit from a parent preset with an `"extends"` key, which is resolved the same way but relative
+    /// to the child preset's directory. The child is deep-merged over its parent, and their `user_exclusions` and
+    /// `user_priorities` are combined.
+    ///
+    /// Each preset is upgraded separately if it was made by an older version of the randomizer. A preset that extends
+    /// another may omit its `"version"`, in which case it is assumed to match its parent.
+    pub fn load_preset(preset: &str) -> Result<SeedInfo> {
+        let file = Self::preset_path(preset, Path::new("presets"), Path::new(""));
+        let (preset, _) = Self::load_preset_json(&file, &mut Vec::new())?;
+        println!();
+
+        serde_json::from_value(preset).map_err(Error::new)
+    }
+
+    /// Treats `preset` as a path relative to `dir` if it has a file extension or directory, otherwise as the name of a
+    /// preset in `presets_dir`.
+    fn preset_path(preset: &str, presets_dir: &Path, dir: &Path) -> PathBuf {
+        let path = Path::new(preset);
+        if path.extension().is_some() || path.components().count() > 1 {
+            dir.join(path)
+        } else {
+            presets_dir.join(format!("{}.json", preset))
+        }
+    }
+
+    /// Loads and upgrades a preset's JSON merged over all of its parents, along with the preset's original version.
+    fn load_preset_json(file: &Path, chain: &mut Vec<PathBuf>) -> Result<(Value, Option<String>)> {
+        info!("Loading preset from:            {}", file.display());
+
+        let canonical = fs::canonicalize(file)
+            .map_err(|err| Error::new(format!("Couldn't open preset {}: {}", file.display(), err)))?;
+        if chain.contains(&canonical) {
+            return Err(Error::new(format!("Preset {} extends itself", file.display())));
+        }
+        chain.push(canonical);
+
+        let mut preset: Value = Self::load_json(file.to_path_buf())
+            .map_err(|err| Error::new(format!("Couldn't parse preset {}: {}", file.display(), err)))?;
+        let object = preset
+            .as_object_mut()
+            .ok_or_else(|| Error::new(format!("Preset {} is not a JSON object", file.display())))?;
+
+        let parent = match object.remove("extends") {
+            None => None,
+            Some(Value::String(parent)) => {
+                let dir = file.parent().unwrap_or(Path::new(""));
+                Some(Self::load_preset_json(&Self::preset_path(&parent, dir, dir), chain)?)
+            },
+            Some(_) => return Err(Error::new(format!("\"extends\" in preset {} is not a string", file.display()))),
+        };
+
+        if let Some((_, Some(parent_version))) = &parent {
+            object.entry("version").or_insert_with(|| Value::String(parent_version.clone()));
+        }
+        let version = object.get("version").and_then(Value::as_str).map(str::to_owned);
+
+        migration::migrate(&mut preset).map_err(|err| Error::new(format!("{}: {}", file.display(), err)))?;
+
+        Ok(match parent {
+            Some((mut parent, _)) => {
+                merge(&mut parent, preset);
+                (parent, version)
+            },
+            None => (preset, version),
+        })
+    }
+
+    /// Loads a bare [`Settings`] object (i.e. just the `settings` portion of a preset) from the given file.
+    pub fn load_settings(file: &Path) -> Result<Settings> {
+        info!("Loading settings from:          {}\n", file.display());
+        Self::load_json(file.to_path_buf())
+    }
+
+    /// Loads a [`MysteryWeights`] file for rolling Mystery Settings.
+    pub fn load_mystery_weights(file: &Path) -> Result<MysteryWeights> {
+        info!("Loading mystery weights from:   {}\n", file.display());
+        Self::load_json(file.to_path_buf())
+    }
+
+    pub fn load_config<T: DeserializeOwned>() -> Result<T> {
+        let file = PathBuf::from(CONFIG_FILE_NAME);
+        if file.exists() {
+            Self::load_json(file)
+        } else {
+            Err(Error::new(format!("No config file found at {}", file.display())))
+        }
+    }
+
+    fn load_json<T: DeserializeOwned>(file: PathBuf) -> Result<T> {
+        let file = fs::read_to_string(file)?;
+        let stripped = StripComments::new(file.as_bytes());
+        let paths = serde_json::from_reader(stripped).map_err(Error::new)?;
+        Ok(paths)
+    }
+}
+
+/// Lists of check names that a child preset adds to its parent's, rather than replacing them.
+const COMBINED_LISTS: [&str; 2] = ["user_exclusions", "user_priorities"];
+
+/// Deep-merges a child preset over its parent. Objects are merged key by key and the lists in [`COMBINED_LISTS`] are
+/// combined, any other value in the child replaces the parent's.
+fn merge(parent: &mut Value, child: Value) {
+    match (parent, child) {
+        (Value::Object(parent), Value::Object(child)) => {
+            for (key, child) in child {
+                match (parent.get_mut(&key), child) {
+                    (Some(Value::Array(parent)), Value::Array(child)) if COMBINED_LISTS.contains(&key.as_str()) => {
+                        for check in child {
+                            if !parent.contains(&check) {
+                                parent.push(check);
+                            }
+                        }
+                    },
+                    (Some(parent), child) => merge(parent, child),
+                    (None, child) => {
+                        parent.insert(key, child);
+                    },
+                }
+            }
+        },
+        (parent, child) => *parent = child,
+    }
+}
+
+/// Paths to the game ROM and output directories.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UserConfig {
+    rom: PathBuf,
+    output: PathBuf,
+}
+
+impl UserConfig {
+    /// Generates new paths with the specified ROM and output directory.
+    pub fn new(rom: PathBuf, output: PathBuf) -> Self {
+        Self { rom, output }
+    }
+
+    /// Gets the path of the ROM file.
+    pub fn rom(&self) -> &Path {
+        &self.rom
+    }
+
+    /// Gets the output directory.
+    pub fn output(&self) -> &Path {
+        &self.output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::VERSION;
+    use serde_json::json;
+    use tempfile::tempdir;
+
+    fn write(dir: &Path, name: &str, preset: Value) -> PathBuf {
+        let file = dir.join(name);
+        fs::write(&file, preset.to_string()).unwrap();
+        file
+    }
+
+    #[test]
+    fn merges_child_over_parent() {
+        let dir = tempdir().unwrap();
+        write(
+            dir.path(),
+            "parent.json",
+            json!({
+                "version": VERSION,
+                "settings": {
+                    "logic": { "logic_mode": "Normal", "swordless_mode": true },
+                    "options": { "chest_size_matches_contents": true },
+                },
+                "user_exclusions": ["A", "B"],
+            }),
+        );
+        let child = write(
+            dir.path(),
+            "child.json",
+            json!({
+                "extends": "parent.json",
+                "settings": { "logic": { "logic_mode": "Hard" } },
+                "user_exclusions": ["B", "C"],
+            }),
+        );
+
+        let (preset, version) = System::load_preset_json(&child, &mut Vec::new()).unwrap();
+        assert_eq!(version.as_deref(), Some(VERSION));
+        assert_eq!(
+            preset,
+            json!({
+                "version": VERSION,
+                "settings": {
+                    "logic": { "logic_mode": "Hard", "swordless_mode": true },
+                    "options": { "chest_size_matches_contents": true },
+                },
+                "user_exclusions": ["A", "B", "C"],
+            })
+        );
+    }
+
+    #[test]
+    fn applies_each_level_in_order() {
+        let dir = tempdir().unwrap();
+        write(dir.path(), "base.json", json!({ "version": VERSION, "settings": { "a": 1, "b": 1, "c": 1 } }));
+        fs::create_dir(dir.path().join("nested")).unwrap();
+        write(dir.path(), "nested/middle.json", json!({ "extends": "../base.json", "settings": { "b": 2, "c": 2 } }));
+        let leaf = write(dir.path(), "leaf.json", json!({ "extends": "nested/middle.json", "settings": { "c": 3 } }));
+
+        let (preset, _) = System::load_preset_json(&leaf, &mut Vec::new()).unwrap();
+        assert_eq!(preset["settings"], json!({ "a": 1, "b": 2, "c": 3 }));
+    }
+
+    #[test]
+    fn rejects_cycles() {
+        let dir = tempdir().unwrap();
+        let own = write(dir.path(), "own.json", json!({ "version": VERSION, "extends": "own.json" }));
+        let err = System::load_preset_json(&own, &mut Vec::new()).unwrap_err();
+        assert!(err.to_string().contains("extends itself"), "{}", err);
+
+        let first = write(dir.path(), "first.json", json!({ "version": VERSION, "extends": "second.json" }));
+        write(dir.path(), "second.json", json!({ "version": VERSION, "extends": "first.json" }));
+        let err = System::load_preset_json(&first, &mut Vec::new()).unwrap_err();
+        assert!(err.to_string().contains("extends itself"), "{}", err);
+    }
+
+    #[test]
+    fn rejects_bad_extends() {
+        let dir = tempdir().unwrap();
+        let number = write(dir.path(), "number.json", json!({ "version": VERSION, "extends": 1 }));
+        let err = System::load_preset_json(&number, &mut Vec::new()).unwrap_err();
+        assert!(err.to_string().contains("is not a string"), "{}", err);
+
+        let missing = write(dir.path(), "missing.json", json!({ "version": VERSION, "extends": "nowhere.json" }));
+        let err = System::load_preset_json(&missing, &mut Vec::new()).unwrap_err();
+        assert!(err.to_string().contains("Couldn't open preset"), "{}", err);
+    }
+}
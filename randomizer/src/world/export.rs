@@ -0,0 +1,224 @@
+//! Exports of the [`WorldGraph`] for visualizing and auditing logic, as Graphviz DOT or as structured JSON.
+
+use crate::filler::{location::Location, location_node::LocationNode, logic::Logic, path::Destination};
+use crate::world::{tricks, Transitions, WorldGraph};
+use modinfo::settings::logic::LogicMode;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+/// The World Graph of a seed, in a form meant to be read rather than searched.
+#[derive(Serialize)]
+pub struct GraphExport<'g> {
+    /// Every node with the edges leading out of it, keyed by [`Location`]. Shuffled transitions are resolved.
+    nodes: BTreeMap<String, NodeExport<'g>>,
+    /// Randomized checks, grouped by Subregion name.
+    subregions: BTreeMap<&'static str, Vec<CheckExport<'g>>>,
+    /// Checks that aren't randomized: quest goals, fixed items and Hint Ghosts.
+    quests: Vec<CheckExport<'g>>,
+    /// The logic tier of every trick used by the graph.
+    tricks: BTreeMap<&'static str, LogicMode>,
+}
+
+impl GraphExport<'_> {
+    fn add_tricks(&mut self, logic: &Logic) {
+        let known_tricks = tricks::tricks().expect("World Graphs are only built once the tricks are loaded");
+        for (trick, _) in &logic.tricks {
+            self.tricks.insert(trick, known_tricks[*trick].tier);
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct NodeExport<'g> {
+    edges: Vec<EdgeExport<'g>>,
+}
+
+#[derive(Serialize)]
+struct EdgeExport<'g> {
+    to: Location,
+    /// The shuffled transition the edge goes through, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    via: Option<Destination>,
+    logic: &'g Logic,
+}
+
+#[derive(Serialize)]
+struct CheckExport<'g> {
+    name: &'static str,
+    location: Location,
+    logic: &'g Logic,
+}
+
+impl WorldGraph {
+    /// The nodes, edges and checks of the graph along with their requirements, ready to be serialized.
+    pub fn export(&self, transitions: &Transitions) -> GraphExport<'_> {
+        let mut export = GraphExport {
+            nodes: BTreeMap::new(),
+            subregions: BTreeMap::new(),
+            quests: Vec::new(),
+            tricks: BTreeMap::new(),
+        };
+
+        for (location, node) in self.sorted_nodes() {
+            let mut edges = Vec::new();
+            for path in node.get_paths().iter().flatten() {
+                export.add_tricks(path.get_logic());
+                let destination = path.get_destination();
+                let via = destination.fixed().is_none().then_some(destination);
+                edges.push(EdgeExport { to: transitions.resolve(destination), via, logic: path.get_logic() });
+            }
+            export.nodes.insert(format!("{:?}", location), NodeExport { edges });
+
+            for check in node.get_checks().iter().flatten() {
+                export.add_tricks(check.get_logic());
+                let check_export = CheckExport { name: check.get_name(), location, logic: check.get_logic() };
+                match check.get_location_info() {
+                    Some(info) => export.subregions.entry(info.region()).or_default().push(check_export),
+                    None => export.quests.push(check_export),
+                }
+            }
+        }
+
+        export
+    }
+
+    /// Renders the graph as a Graphviz DOT digraph. Nodes are Locations, checks hang off their Location and are
+    /// clustered by Subregion, and every edge is labeled with its requirements.
+    pub fn to_dot(&self, transitions: &Transitions) -> String {
+        let export = self.export(transitions);
+        let mut dot = String::from("digraph WorldGraph {\n    rankdir=LR;\n    node [shape=box];\n\n");
+
+        for (location, node) in &export.nodes {
+            writeln!(dot, "    {};", quote(location)).unwrap();
+            for edge in &node.edges {
+                let to = quote(&format!("{:?}", edge.to));
+                let label = label_attribute(edge.via, edge.logic);
+                writeln!(dot, "    {} -> {}{};", quote(location), to, label).unwrap();
+            }
+        }
+
+        let clusters = export.subregions.iter().map(|(subregion, checks)| (*subregion, checks));
+        for (i, (cluster, checks)) in clusters.chain([("Quests", &export.quests)]).enumerate() {
+            writeln!(dot, "\n    subgraph cluster_{} {{\n        label={};", i, quote(cluster)).unwrap();
+            for check in checks {
+                writeln!(dot, "        {} [shape=ellipse];", quote(check.name)).unwrap();
+            }
+            dot.push_str("    }\n");
+            for check in checks {
+                let (location, label) = (quote(&format!("{:?}", check.location)), label_attribute(None, check.logic));
+                writeln!(dot, "    {} -> {}{};", location, quote(check.name), label).unwrap();
+            }
+        }
+
+        dot.push_str("}\n");
+        dot
+    }
+
+    /// The nodes of the graph, sorted by Location name so exports are stable.
+    fn sorted_nodes(&self) -> Vec<(Location, &LocationNode)> {
+        let mut nodes = self.iter().map(|(&location, node)| (location, node)).collect::<Vec<_>>();
+        nodes.sort_by_cached_key(|(location, _)| format!("{:?}", location));
+        nodes
+    }
+}
+
+/// DOT attributes labeling an edge with the transition it goes `via` and the requirements of the `logic`, one per line.
+/// Free rules have no label, and rules without any requirement in logic are dotted.
+fn label_attribute(via: Option<Destination>, logic: &Logic) -> String {
+    let style = if logic.normal.is_none() && logic.tricks.is_empty() { ", style=dotted" } else { "" };
+
+    let via = via.map(|via| format!("via {}", via));
+    let normal = logic.normal.iter().map(|requirement| format!("normal: {}", requirement));
+    let tricks = logic.tricks.iter().map(|(trick, requirement)| format!("{}: {}", trick, requirement));
+    let lines = via.into_iter().chain(normal).chain(tricks).filter(|line| line != "normal: true").collect::<Vec<_>>();
+    match (lines.is_empty(), style.is_empty()) {
+        (true, true) => String::new(),
+        (true, false) => String::from(" [style=dotted]"),
+        _ => format!(" [label={}{}]", quote(&lines.join("\n")), style),
+    }
+}
+
+/// Quotes a DOT ID, escaping quotes and turning newlines into centered line breaks.
+fn quote(id: &str) -> String {
+    format!("\"{}\"", id.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use modinfo::settings::{Cracksanity, DungeonEntrances, Settings};
+    use std::collections::BTreeSet;
+
+    fn shuffled_seed() -> crate::SeedInfo {
+        let settings = Settings {
+            cracksanity: Cracksanity::AnyWorldPairs,
+            dungeon_entrances: DungeonEntrances::AnyWorld,
+            ..Default::default()
+        };
+        crate::generate_world_graph(1, settings).unwrap()
+    }
+
+    #[test]
+    fn exports_every_edge_and_check_as_json() {
+        let seed_info = shuffled_seed();
+        let transitions = seed_info.transitions();
+        let export = serde_json::to_value(seed_info.world_graph.export(&transitions)).unwrap();
+
+        let nodes = export["nodes"].as_object().unwrap();
+        assert_eq!(nodes.len(), seed_info.world_graph.len());
+        let mut checks = 0;
+        for (location, node) in seed_info.world_graph.iter() {
+            let edges = nodes[&format!("{:?}", location)]["edges"].as_array().unwrap();
+            let paths = node.get_paths().iter().flatten().collect::<Vec<_>>();
+            assert_eq!(edges.len(), paths.len());
+            for (edge, path) in edges.iter().zip(paths) {
+                let destination = path.get_destination();
+                let to: Location = serde_json::from_value(edge["to"].clone()).unwrap();
+                assert_eq!(to, transitions.resolve(destination));
+                match destination.fixed() {
+                    Some(_) => assert!(edge.get("via").is_none()),
+                    None => assert_eq!(edge["via"], destination.to_string()),
+                }
+            }
+            checks += node.get_checks().iter().flatten().count();
+        }
+
+        let subregions = export["subregions"].as_object().unwrap().values();
+        let exported = subregions.chain([&export["quests"]]).flat_map(|checks| checks.as_array().unwrap());
+        assert_eq!(exported.count(), checks);
+        assert!(export["tricks"].as_object().unwrap().values().all(|tier| tier.is_string()));
+    }
+
+    #[test]
+    fn renders_every_edge_and_check_as_dot() {
+        let seed_info = shuffled_seed();
+        let transitions = seed_info.transitions();
+        let dot = seed_info.world_graph.to_dot(&transitions);
+
+        assert!(dot.starts_with("digraph WorldGraph {\n") && dot.ends_with("}\n"));
+        assert_eq!(dot.matches('{').count(), dot.matches('}').count());
+        let lines = dot.lines().map(str::trim).collect::<BTreeSet<_>>();
+        for (location, node) in seed_info.world_graph.iter() {
+            let from = quote(&format!("{:?}", location));
+            assert!(lines.contains(format!("{};", from).as_str()));
+            for path in node.get_paths().iter().flatten() {
+                let to = transitions.resolve(path.get_destination());
+                let edge = format!("{} -> {}", from, quote(&format!("{:?}", to)));
+                assert!(lines.iter().any(|line| line.starts_with(&edge)), "{}", edge);
+            }
+            for check in node.get_checks().iter().flatten() {
+                let edge = format!("{} -> {}", from, quote(check.get_name()));
+                assert!(lines.iter().any(|line| line.starts_with(&edge)), "{}", edge);
+                assert!(lines.contains(format!("{} [shape=ellipse];", quote(check.get_name())).as_str()));
+            }
+        }
+    }
+
+    #[test]
+    fn quotes_dot_ids() {
+        assert_eq!(quote("Hyrule Field"), "\"Hyrule Field\"");
+        assert_eq!(quote("[HC] \"Chest\"\\"), "\"[HC] \\\"Chest\\\"\\\\\"");
+        assert_eq!(quote("via crack_left Zaganaga\nnormal: true"), "\"via crack_left Zaganaga\\nnormal: true\"");
+    }
+}
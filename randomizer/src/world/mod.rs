@@ -1,29 +1,68 @@
-use crate::regions::Subregion;
-use crate::{
-    filler::filler_item::Randomizable,
-    filler::{check::Check, location::Location, location_node::LocationNode, logic::Logic, progress::Progress},
-    hints::hint_ghost_name,
-    CrackMap, DashMap, LocationInfo,
+//! The World Graph, loaded from the logic files in `logic`.
+//!
+//! Each logic file is a JSON object (comments allowed) mapping [`Location`] names to nodes:
+//!
+//! ```json
+//! "EasternPalace1F": {
+//!   "name": "Eastern Palace 1F",
+//!   "checks": [
+//!     { "name": "[EP] (1F) Popo Room", "subregion": "dungeons::eastern::palace", "normal": "can_attack" }
+//!   ],
+//!   "edges": [
+//!     { "to": "EasternPalaceMiniboss", "normal": "has_eastern_keys(1)" }
+//!   ]
+//! }
+//! ```
+//!
+//! A check has a `name` and exactly one of a `subregion` (a randomized check), a `goal` or `item` (a fixed quest), or
+//! instead of a name a hint `ghost`. An edge leads either `to` a Location, through the left or right side of a Crack
+//! (`crack_left`/`crack_right`), whose destination depends on the [`CrackMap`], or in or out of a dungeon's main
+//! entrance (`dungeon_entrance`/`dungeon_exit`), whose destination depends on the [`DungeonEntranceMap`].
+//!
+//! The graph itself doesn't depend on the seed: edges through Cracks and dungeon entrances are kept as dynamic
+//! [`Destination`]s, laid over the graph by the seed's [`Transitions`] when searching it. This lets one graph be shared
+//! by every seed with the same logic, see [`build_world_graph`].
+//!
+//! Rules have a `normal` [`Requirement`] expression, and an expression per named trick that offers another way through,
+//! e.g. `"tricks": { "enemy_clips": "has_boomerang" }`. Trick expressions only count if the trick is in logic, see
+//! [`tricks`]. A rule without any expression is free, unless it is marked `"out_of_logic": true`. Named helpers shared
+//! by every file are defined in `helpers.json`, and any of it may be changed from a preset with [`LogicOverrides`].
+//!
+//! The logic files and `helpers.json` are read from the `logic` directory at runtime, see [`LOGIC_DIR`], so they can
+//! be edited without rebuilding the randomizer. Any file missing from it falls back to the copy bundled at build time,
+//! and where each file came from is logged.
+
+use crate::filler::{
+    check::Check,
+    cracks::Crack,
+    dungeon_entrances::DungeonEntrance,
+    filler_item::{Goal, Item, Randomizable},
+    location::Location,
+    location_node::LocationNode,
+    logic::Logic,
+    path::{Destination, Path},
+    requirement::{Helper, Requirement},
 };
+use crate::{hints::hint_ghost_name, regions, CrackMap, DashMap, DungeonEntranceMap, LocationInfo};
 use game::ghosts::HintGhost;
+use json_comments::StripComments;
+use lazy_static::lazy_static;
 use log::info;
-use serde::Serialize;
+use modinfo::settings::{
+    logic_overrides::{LogicOverrides, RuleOverride},
+    Settings,
+};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fmt::{self, Display, Formatter};
 use std::ops::{Deref, DerefMut};
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::{fs, io};
+use tricks::Trick;
 
-mod dark;
-mod desert;
-mod eastern;
-mod gales;
-mod hera;
-mod hyrule;
-mod hyrule_castle;
-mod ice;
-mod lorule;
-mod lorule_castle;
-mod skull;
-mod swamp;
-mod thieves;
-mod turtle;
+pub mod export;
+pub mod tricks;
 
 #[derive(Default, Debug, Serialize)]
 pub struct WorldGraph {
@@ -50,209 +89,509 @@ impl DerefMut for WorldGraph {
     }
 }
 
-/// Build the World Graph
-/// FIXME shouldn't take crack_map as argument, map should be independent of that randomization
-pub fn build_world_graph(crack_map: &CrackMap) -> WorldGraph {
-    info!("Building World Graph...");
-    let mut world = WorldGraph::new();
+/// The shuffled transitions of a seed, laid over the [`WorldGraph`] to resolve where its dynamic edges lead.
+#[derive(Clone, Copy)]
+pub struct Transitions<'s> {
+    crack_map: &'s CrackMap,
+    dungeon_entrance_map: &'s DungeonEntranceMap,
+}
 
-    world.extend(hyrule::graph(crack_map));
-    world.extend(lorule::graph(crack_map));
+impl<'s> Transitions<'s> {
+    pub fn new(crack_map: &'s CrackMap, dungeon_entrance_map: &'s DungeonEntranceMap) -> Self {
+        Self { crack_map, dungeon_entrance_map }
+    }
 
-    world.extend(eastern::graph());
-    world.extend(gales::graph());
-    world.extend(hera::graph());
+    /// The Location that the `destination` leads to in this seed.
+    pub fn resolve(&self, destination: Destination) -> Location {
+        match destination {
+            Destination::Location(location) => location,
+            Destination::CrackLeft(crack) => self.crack_destinations(crack).1,
+            Destination::CrackRight(crack) => self.crack_destinations(crack).0,
+            Destination::DungeonEntrance(door) => self.dungeon_behind(door).get_foyer(),
+            Destination::DungeonExit(dungeon) => self.door_to(dungeon).get_outside(),
+        }
+    }
 
-    world.extend(hyrule_castle::graph(crack_map));
+    /// The dungeon that the `door` leads to. Doors missing from the map lead to their vanilla dungeon.
+    pub fn dungeon_behind(&self, door: DungeonEntrance) -> DungeonEntrance {
+        self.dungeon_entrance_map.get(&door).copied().unwrap_or(door)
+    }
 
-    world.extend(dark::graph());
-    world.extend(swamp::graph());
-    world.extend(skull::graph());
-    world.extend(thieves::graph());
-    world.extend(ice::graph());
-    world.extend(desert::graph(crack_map));
-    world.extend(turtle::graph());
+    /// The door that leads to the `dungeon`.
+    pub fn door_to(&self, dungeon: DungeonEntrance) -> DungeonEntrance {
+        let mut doors = self.dungeon_entrance_map.iter().filter(|(_, &behind)| behind == dungeon);
+        doors.next().map_or(dungeon, |(&door, _)| door)
+    }
 
-    world.extend(lorule_castle::graph(crack_map));
+    /// The door that leads to the dungeon named `region`, if it isn't the dungeon's own.
+    pub fn shuffled_door_to(&self, region: &str) -> Option<DungeonEntrance> {
+        let dungeon = DungeonEntrance::ALL.into_iter().find(|dungeon| dungeon.as_str() == region)?;
+        Some(self.door_to(dungeon)).filter(|&door| door != dungeon)
+    }
 
-    world
+    /// The (left, right) Locations of the Crack that the `crack` leads to.
+    fn crack_destinations(&self, crack: Crack) -> (Location, Location) {
+        let dest_crack = self.crack_map.get(&crack).unwrap_or_else(|| panic!("CrackMap missing Crack: {:?}", crack));
+        dest_crack.get_left_right_locations()
+    }
 }
 
-/// Check convenience macros
-macro_rules! check {
-    ($loc_name:expr, $loc_region:expr) => {
-        Check::new($loc_name, Logic::free(), None, Some(LocationInfo::new($loc_name, $loc_region)))
-    };
-    ($loc_name:expr, $loc_region:expr, $normal:expr) => {
-        Check::new($loc_name, *Logic::new()
-            .normal($normal), None, Some(LocationInfo::new($loc_name, $loc_region)))
-    };
-    ($loc_name:expr, $loc_region:expr => {
-        $(normal: $normal:expr,)?
-        $(hard: $hard:expr,)?
-        $(glitched: $glitched:expr,)?
-        $(adv_glitched: $adv_glitched:expr,)?
-        $(hell: $hell:expr,)?
-    }) => (
-        Check::new($loc_name, *Logic::new()
-            $(.normal($normal))?
-            $(.hard($hard))?
-            $(.glitched($glitched))?
-            $(.adv_glitched($adv_glitched))?
-            $(.hell($hell))?, None, Some(LocationInfo::new($loc_name, $loc_region)))
-    );
+/// Where logic files are read from, relative to the working directory like the `presets` directory.
+pub const LOGIC_DIR: &str = "logic";
+
+/// The logic files making up the World Graph, with the contents bundled at build time.
+const LOGIC_FILES: &[(&str, &str)] = &[
+    ("hyrule.json", include_str!("../../../logic/hyrule.json")),
+    ("lorule.json", include_str!("../../../logic/lorule.json")),
+    ("eastern.json", include_str!("../../../logic/eastern.json")),
+    ("gales.json", include_str!("../../../logic/gales.json")),
+    ("hera.json", include_str!("../../../logic/hera.json")),
+    ("hyrule_castle.json", include_str!("../../../logic/hyrule_castle.json")),
+    ("dark.json", include_str!("../../../logic/dark.json")),
+    ("swamp.json", include_str!("../../../logic/swamp.json")),
+    ("skull.json", include_str!("../../../logic/skull.json")),
+    ("thieves.json", include_str!("../../../logic/thieves.json")),
+    ("ice.json", include_str!("../../../logic/ice.json")),
+    ("desert.json", include_str!("../../../logic/desert.json")),
+    ("turtle.json", include_str!("../../../logic/turtle.json")),
+    ("lorule_castle.json", include_str!("../../../logic/lorule_castle.json")),
+];
+
+/// Everything a World Graph depends on: the tricks in logic and the logic overrides.
+type GraphKey = (BTreeSet<&'static str>, LogicOverrides);
+
+lazy_static! {
+    /// World Graphs already built, see [`build_world_graph`].
+    static ref GRAPHS: Mutex<HashMap<GraphKey, Arc<WorldGraph>>> = Default::default();
+    static ref HELPERS: Result<BTreeMap<String, String>, LogicFileError> =
+        load_file("helpers.json", include_str!("../../../logic/helpers.json"));
+    static ref NODES: Result<Vec<(Location, NodeData)>, LogicFileError> = load_nodes();
 }
 
-/// Weather Vane convenience macros
-// macro_rules! vane {
-//     ($loc_name:expr, $vane:expr) => {
-//         Check::new($loc_name, Logic::free(), Some($vane.into()), None)
-//     };
-//     ($loc_name:expr, $vane:expr, $normal:expr) => {
-//         Check::new($loc_name, *Logic::new()
-//             .normal($normal), Some($vane.into()), None)
-//     };
-//     ($loc_name:expr, $vane:expr => {
-//         $(normal: $normal:expr,)?
-//         $(hard: $hard:expr,)?
-//         $(glitched: $glitched:expr,)?
-//         $(adv_glitched: $adv_glitched:expr,)?
-//         $(hell: $hell:expr,)?
-//     }) => (
-//         Check::new($loc_name, *Logic::new()
-//             $(.normal($normal))?
-//             $(.hard($hard))?
-//             $(.glitched($glitched))?
-//             $(.adv_glitched($adv_glitched))?
-//             $(.hell($hell))?, Some($vane.into()), None)
-//     );
-// }
-
-/// Goal convenience macros
-macro_rules! goal {
-    ($loc_name:expr, $goal:expr) => {
-        Check::new($loc_name, Logic::free(), Some($goal.into()), None)
-    };
-    ($loc_name:expr, $goal:expr, $normal:expr) => {
-        Check::new($loc_name, *Logic::new()
-            .normal($normal), Some($goal.into()), None)
-    };
-    ($loc_name:expr, $goal:expr => {
-        $(normal: $normal:expr,)?
-        $(hard: $hard:expr,)?
-        $(glitched: $glitched:expr,)?
-        $(adv_glitched: $adv_glitched:expr,)?
-        $(hell: $hell:expr,)?
-    }) => (
-        Check::new($loc_name, *Logic::new()
-            $(.normal($normal))?
-            $(.hard($hard))?
-            $(.glitched($glitched))?
-            $(.adv_glitched($adv_glitched))?
-            $(.hell($hell))?, Some($goal.into()), None)
-    );
+fn load_nodes() -> Result<Vec<(Location, NodeData)>, LogicFileError> {
+    let mut nodes = Vec::new();
+    for (file, bundled) in LOGIC_FILES {
+        let file_nodes: DashMap<Location, NodeData> = load_file(file, bundled)?;
+        for (location, node) in file_nodes {
+            if nodes.iter().any(|(other, _)| *other == location) {
+                return Err(LogicFileError::new(file, format!("Redefines {:?}", location)));
+            }
+            nodes.push((location, node));
+        }
+    }
+    Ok(nodes)
 }
 
-/// Path convenience macros
-/// Rust won't let me call it a Path so I'm using the dumb math name >:(
-macro_rules! edge {
-    ($dest:ident) => (
-        Path::new(Location::$dest, Logic::free())
-    );
-    ($dest:ident, $normal:expr) => (
-        Path::new(Location::$dest, *Logic::new()
-            .normal($normal))
-    );
-    ($dest:ident => {
-        $(normal: $normal:expr,)?
-        $(hard: $hard:expr,)?
-        $(glitched: $glitched:expr,)?
-        $(adv_glitched: $adv_glitched:expr,)?
-        $(hell: $hell:expr,)?
-    }) => (
-        Path::new(Location::$dest,
-            *Logic::new()
-            $(.normal($normal))?
-            $(.hard($hard))?
-            $(.glitched($glitched))?
-            $(.adv_glitched($adv_glitched))?
-            $(.hell($hell))?
-        )
-    );
+/// Reads and parses the logic `file` from [`LOGIC_DIR`], or parses its `bundled` contents if there is no such file.
+fn load_file<T: DeserializeOwned>(file: &str, bundled: &str) -> Result<T, LogicFileError> {
+    load_file_from(&PathBuf::from(LOGIC_DIR), file, bundled)
 }
 
-use crate::filler::cracks::Crack;
-use crate::filler::path::Path;
-pub(crate) use check;
-pub(crate) use edge;
-pub(crate) use goal;
-
-fn location<C, P>(name: &'static str, checks: C, paths: P) -> LocationNode
-where
-    C: Into<Option<Vec<Check>>>,
-    P: Into<Option<Vec<Path>>>,
-{
-    LocationNode::new(name, checks.into(), paths.into())
+fn load_file_from<T: DeserializeOwned>(dir: &std::path::Path, file: &str, bundled: &str) -> Result<T, LogicFileError> {
+    let path = dir.join(file);
+    match fs::read_to_string(&path) {
+        Ok(contents) => {
+            info!("Loading logic file {}", path.display());
+            parse_file(file, &contents)
+        }
+        Err(err) if err.kind() == io::ErrorKind::NotFound => {
+            info!("Loading built-in logic file {} ({} not found)", file, path.display());
+            parse_file(file, bundled)
+        }
+        Err(err) => Err(LogicFileError::new(file, err)),
+    }
 }
 
-// TODO REMOVE
-// #[deprecated]
-fn old_check(
-    location_info: LocationInfo, normal: Option<fn(&Progress) -> bool>, hard: Option<fn(&Progress) -> bool>,
-    glitched: Option<fn(&Progress) -> bool>, adv_glitched: Option<fn(&Progress) -> bool>,
-    hell: Option<fn(&Progress) -> bool>,
-) -> Check {
-    Check::new(location_info.name, Logic::config(normal, hard, glitched, adv_glitched, hell), None, Some(location_info))
+fn parse_file<T: DeserializeOwned>(file: &str, contents: &str) -> Result<T, LogicFileError> {
+    serde_json::from_reader(StripComments::new(contents.as_bytes())).map_err(|err| LogicFileError::new(file, err))
 }
 
-// todo REMOVE
-// #[deprecated]
-fn old_path(
-    default: Location, normal: Option<fn(&Progress) -> bool>, hard: Option<fn(&Progress) -> bool>,
-    glitched: Option<fn(&Progress) -> bool>, adv_glitched: Option<fn(&Progress) -> bool>,
-    hell: Option<fn(&Progress) -> bool>,
-) -> Path {
-    Path::new(default, Logic::config(normal, hard, glitched, adv_glitched, hell))
+/// A logic file that couldn't be read or parsed.
+#[derive(Clone, Debug)]
+pub struct LogicFileError {
+    file: String,
+    message: String,
 }
 
-/// Used for checks that the Randomizer should be aware of existing, but are not considered part of any logic.
-/// Most things that use this are typically not in logic *yet*
-fn out_of_logic(name: &'static str, subregion: &'static Subregion) -> Check {
-    Check::new(
-        name,
-        Logic { normal: None, hard: None, glitched: None, adv_glitched: None, hell: None },
-        None,
-        Some(LocationInfo::new(name, subregion)),
-    )
+impl LogicFileError {
+    fn new(file: &str, err: impl Display) -> Self {
+        Self { file: file.to_owned(), message: err.to_string() }
+    }
 }
 
-fn crack_left(crack: Crack, crack_map: &CrackMap, is_hc: bool) -> Path {
-    let dest_crack = crack_map.get(&crack).unwrap_or_else(|| panic!("CrackMap missing Crack: {:?}", crack));
-    let (_left, right) = dest_crack.get_left_right_locations();
+impl Display for LogicFileError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "Invalid logic file {}: {}", self.file, self.message)
+    }
+}
 
-    let logic: fn(&Progress) -> bool = if is_hc { |p| p.can_merge() } else { |p| p.are_cracks_open() && p.can_merge() };
+impl std::error::Error for LogicFileError {}
 
-    Path::new(right, *Logic::new().normal(logic))
+/// Why a World Graph couldn't be built.
+#[derive(Debug)]
+pub enum WorldGraphError {
+    /// A logic file couldn't be read or parsed.
+    File(LogicFileError),
+    /// Every problem found compiling the logic, such as unknown helpers or [`LogicOverrides`] that don't apply.
+    Logic(Vec<String>),
 }
 
-fn crack_right(crack: Crack, crack_map: &CrackMap, is_hc: bool) -> Path {
-    let dest_crack = crack_map.get(&crack).expect("CrackMap should have all Cracks mapped");
-    let (left, _right) = dest_crack.get_left_right_locations();
+impl From<LogicFileError> for WorldGraphError {
+    fn from(err: LogicFileError) -> Self {
+        Self::File(err)
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct NodeData {
+    name: String,
+    #[serde(default)]
+    checks: Vec<CheckData>,
+    #[serde(default)]
+    edges: Vec<EdgeData>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct CheckData {
+    name: Option<String>,
+    subregion: Option<String>,
+    goal: Option<Goal>,
+    item: Option<Item>,
+    ghost: Option<HintGhost>,
+    #[serde(default)]
+    out_of_logic: bool,
+    normal: Option<String>,
+    #[serde(default)]
+    tricks: BTreeMap<String, String>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct EdgeData {
+    to: Option<Location>,
+    crack_left: Option<Crack>,
+    crack_right: Option<Crack>,
+    dungeon_entrance: Option<DungeonEntrance>,
+    dungeon_exit: Option<DungeonEntrance>,
+    normal: Option<String>,
+    #[serde(default)]
+    tricks: BTreeMap<String, String>,
+}
+
+/// The World Graph for the Settings, applying any [`LogicOverrides`] and leaving out tricks that aren't in logic.
+/// Returns every problem found if the logic can't be built.
+///
+/// Graphs are only built once per process for the same logic, and shared by every seed that uses them.
+pub fn build_world_graph(settings: &Settings) -> Result<Arc<WorldGraph>, WorldGraphError> {
+    let key = (tricks_in_logic(settings)?, settings.logic_overrides.clone());
+    if let Some(world) = GRAPHS.lock().unwrap().get(&key) {
+        return Ok(world.clone());
+    }
+
+    // Built without holding the lock, a graph built twice by racing threads is the same either way
+    let world = Arc::new(compile_world_graph(key.0.clone(), &key.1)?);
+    Ok(GRAPHS.lock().unwrap().entry(key).or_insert(world).clone())
+}
+
+/// The names of the tricks in logic with the Settings.
+fn tricks_in_logic(settings: &Settings) -> Result<BTreeSet<&'static str>, LogicFileError> {
+    Ok(tricks::tricks()?.keys().map(String::as_str).filter(|trick| tricks::is_in_logic(settings, trick)).collect())
+}
+
+fn compile_world_graph(
+    tricks: BTreeSet<&'static str>, overrides: &LogicOverrides,
+) -> Result<WorldGraph, WorldGraphError> {
+    info!("Building World Graph...");
+    let nodes = NODES.as_ref().map_err(Clone::clone)?;
+    let helpers = HELPERS.as_ref().map_err(Clone::clone)?;
+    let mut world = WorldGraph::new();
+    let mut compiler = Compiler::new(helpers, tricks::tricks()?, tricks, overrides);
+    let mut overridden_checks = BTreeSet::new();
+    let mut overridden_edges = BTreeSet::new();
+
+    for (location, node) in nodes {
+        let mut checks = Vec::new();
+        for check in &node.checks {
+            let (name, quest, location_info) =
+                match (&check.name, &check.subregion, check.goal, check.item, check.ghost) {
+                    (Some(name), Some(subregion), None, None, None) => match regions::find_subregion(subregion) {
+                        Some(subregion) => (name.as_str(), None, Some(LocationInfo::new(name, subregion))),
+                        None => {
+                            compiler.errors.push(format!("Check \"{}\" has unknown subregion \"{}\"", name, subregion));
+                            continue;
+                        },
+                    },
+                    (Some(name), None, Some(goal), None, None) => (name.as_str(), Some(goal.into()), None),
+                    (Some(name), None, None, Some(item), None) => (name.as_str(), Some(item.into()), None),
+                    (None, None, None, None, Some(ghost)) => {
+                        (hint_ghost_name(&ghost), Some(Randomizable::HintGhost(ghost)), None)
+                    },
+                    _ => {
+                        compiler.errors.push(format!(
+                            "Check in {:?} needs a name and exactly one of subregion, goal, item or ghost",
+                            location
+                        ));
+                        continue;
+                    },
+                };
+
+            let rule_override = overrides.checks.get(name);
+            if rule_override.is_some() {
+                overridden_checks.insert(name);
+            }
+            let context = format!("Check \"{}\"", name);
+            let logic = compiler.logic(&context, &check.normal, &check.tricks, check.out_of_logic, rule_override);
+            checks.push(Check::new(name, logic, quest, location_info));
+        }
+
+        let mut paths = Vec::new();
+        for edge in &node.edges {
+            let targets = (edge.to, edge.crack_left, edge.crack_right, edge.dungeon_entrance, edge.dungeon_exit);
+            let destination = match targets {
+                (Some(to), None, None, None, None) => Destination::Location(to),
+                (None, Some(crack), None, None, None) => Destination::CrackLeft(crack),
+                (None, None, Some(crack), None, None) => Destination::CrackRight(crack),
+                (None, None, None, Some(door), None) => Destination::DungeonEntrance(door),
+                (None, None, None, None, Some(dungeon)) => Destination::DungeonExit(dungeon),
+                _ => {
+                    compiler.errors.push(format!(
+                        "Edge from {:?} needs exactly one of to, crack_left, crack_right, dungeon_entrance or \
+                        dungeon_exit",
+                        location
+                    ));
+                    continue;
+                },
+            };
+
+            let key = format!("{:?} -> {}", location, destination);
+            let rule_override = overrides.edges.get_key_value(&key);
+            if let Some((key, _)) = rule_override {
+                overridden_edges.insert(key.as_str());
+            }
+            let context = format!("Edge \"{}\"", key);
+            let rule_override = rule_override.map(|(_, rule)| rule);
+            let logic = compiler.logic(&context, &edge.normal, &edge.tricks, false, rule_override);
+            paths.push(Path::new(destination, logic));
+        }
 
-    let logic: fn(&Progress) -> bool = if is_hc { |p| p.can_merge() } else { |p| p.are_cracks_open() && p.can_merge() };
+        let checks = if checks.is_empty() { None } else { Some(checks) };
+        let paths = if paths.is_empty() { None } else { Some(paths) };
+        world.insert(*location, LocationNode::new(&node.name, checks, paths));
+    }
+
+    let mut errors = compiler.finish();
+    for name in overrides.checks.keys().filter(|name| !overridden_checks.contains(name.as_str())) {
+        errors.push(format!("Unknown check \"{}\"", name));
+    }
+    for key in overrides.edges.keys().filter(|key| !overridden_edges.contains(key.as_str())) {
+        errors.push(format!("Unknown edge \"{}\"", key));
+    }
 
-    Path::new(left, *Logic::new().normal(logic))
+    if errors.is_empty() {
+        Ok(world)
+    } else {
+        Err(WorldGraphError::Logic(errors))
+    }
 }
 
-fn fast_travel_hyrule() -> Path {
-    edge!(HyruleBellTravel, |p| p.has_bell() && p.are_hyrule_vanes_active())
+/// Compiles the rules of the logic files into [`Logic`], collecting every problem along the way.
+struct Compiler<'a> {
+    /// Named helper definitions, with their overrides applied.
+    helpers: BTreeMap<&'a str, &'a str>,
+    /// Every trick that rules may name.
+    known_tricks: &'static BTreeMap<String, Trick>,
+    /// Tricks that are in logic, the rest are left out of the compiled [`Logic`].
+    tricks: BTreeSet<&'static str>,
+    compiled: HashMap<&'a str, Requirement>,
+    /// Helpers currently being compiled, to catch helpers that depend on themselves.
+    compiling: Vec<&'a str>,
+    errors: Vec<String>,
 }
 
-fn fast_travel_lorule() -> Path {
-    edge!(LoruleBellTravel, |p| p.has_bell() && p.are_lorule_vanes_active())
+impl<'a> Compiler<'a> {
+    fn new(
+        helpers: &'a BTreeMap<String, String>, known_tricks: &'static BTreeMap<String, Trick>,
+        tricks: BTreeSet<&'static str>, overrides: &'a LogicOverrides,
+    ) -> Self {
+        let mut compiler = Self {
+            helpers: BTreeMap::new(),
+            known_tricks,
+            tricks,
+            compiled: HashMap::new(),
+            compiling: vec![],
+            errors: vec![],
+        };
+
+        for (name, expression) in helpers.iter().chain(&overrides.helpers) {
+            if Helper::is_built_in(name) || matches!(name.as_str(), "true" | "false") {
+                compiler.errors.push(format!("Helper \"{}\" has the name of a built-in helper", name));
+            } else {
+                compiler.helpers.insert(name, expression);
+            }
+        }
+
+        compiler
+    }
+
+    /// Compiles the rule's requirements, with those of its override (if any) replacing the original ones.
+    fn logic(
+        &mut self, context: &str, normal: &'a Option<String>, tricks: &'a BTreeMap<String, String>, out_of_logic: bool,
+        rule_override: Option<&'a RuleOverride>,
+    ) -> Logic {
+        let mut normal = normal.as_deref();
+        if !out_of_logic && normal.is_none() && tricks.is_empty() {
+            normal = Some("true");
+        }
+        let mut tricks = tricks
+            .iter()
+            .map(|(trick, expression)| (trick.as_str(), Some(expression.as_str())))
+            .collect::<BTreeMap<_, _>>();
+        if let Some(rule_override) = rule_override {
+            if let Some(normal_override) = &rule_override.normal {
+                normal = normal_override.as_deref();
+            }
+            for (trick, trick_override) in &rule_override.tricks {
+                tricks.insert(trick, trick_override.as_deref());
+            }
+        }
+
+        let mut logic = Logic::new();
+        if let Some(expression) = normal {
+            match Requirement::parse(expression, &mut |name| self.helper(name)) {
+                Ok(parsed) => logic.normal = Some(parsed),
+                Err(err) => self.errors.push(format!("{} (normal): {}", context, err)),
+            }
+        }
+        for (trick, expression) in tricks {
+            let Some((trick, _)) = self.known_tricks.get_key_value(trick) else {
+                self.errors.push(format!("{}: Unknown trick \"{}\"", context, trick));
+                continue;
+            };
+            let Some(expression) = expression else {
+                continue;
+            };
+            match Requirement::parse(expression, &mut |name| self.helper(name)) {
+                Ok(parsed) if self.tricks.contains(trick.as_str()) => logic.tricks.push((trick, parsed)),
+                Ok(_) => {},
+                Err(err) => self.errors.push(format!("{} ({}): {}", context, trick, err)),
+            }
+        }
+        logic
+    }
+
+    /// Looks up a named helper, compiling it on first use. A helper that fails to compile is reported once and then
+    /// treated as never met.
+    fn helper(&mut self, name: &str) -> Result<Option<Requirement>, String> {
+        let Some((&name, &expression)) = self.helpers.get_key_value(name) else {
+            return Ok(None);
+        };
+        if let Some(requirement) = self.compiled.get(name) {
+            return Ok(Some(requirement.clone()));
+        }
+        if self.compiling.contains(&name) {
+            return Err(format!("Helper \"{}\" depends on itself", name));
+        }
+
+        self.compiling.push(name);
+        let requirement = Requirement::parse(expression, &mut |name| self.helper(name)).unwrap_or_else(|err| {
+            self.errors.push(format!("Helper \"{}\": {}", name, err));
+            Requirement::Never
+        });
+        self.compiling.pop();
+
+        self.compiled.insert(name, requirement.clone());
+        Ok(Some(requirement))
+    }
+
+    /// Compiles any helpers that weren't used, so they get checked too, and returns every problem found.
+    fn finish(mut self) -> Vec<String> {
+        for name in self.helpers.keys().copied().collect::<Vec<_>>() {
+            self.helper(name).expect("Top-level helpers can't depend on themselves");
+        }
+        self.errors
+    }
 }
 
-/// Hint Ghost checks
-fn ghost(ghost: HintGhost) -> Check {
-    Check::new(hint_ghost_name(&ghost), Logic::free(), Some(Randomizable::HintGhost(ghost)), None)
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reports_malformed_logic_files() {
+        let err = parse_file::<BTreeMap<String, String>>("helpers.json", "{ \"can_merge\": }").unwrap_err();
+        assert!(err.to_string().starts_with("Invalid logic file helpers.json: "), "{}", err);
+    }
+
+    #[test]
+    fn falls_back_to_bundled_logic_files() {
+        let dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("..").join(LOGIC_DIR);
+        let helpers: BTreeMap<String, String> = load_file_from(&dir, "helpers.json", "{}").unwrap();
+        assert!(!helpers.is_empty());
+
+        let bundled = "{ \"can_merge\": \"true\" }";
+        let missing: BTreeMap<String, String> = load_file_from(&dir, "missing.json", bundled).unwrap();
+        assert_eq!(missing["can_merge"], "true");
+    }
+
+    #[test]
+    fn names_only_the_shuffled_door_to_a_dungeon() {
+        use DungeonEntrance::*;
+        let crack_map = CrackMap::new();
+        let mut dungeon_entrance_map: DungeonEntranceMap = DungeonEntrance::ALL.into_iter().map(|d| (d, d)).collect();
+        dungeon_entrance_map.insert(SkullWoods, EasternPalace);
+        dungeon_entrance_map.insert(EasternPalace, SkullWoods);
+        let transitions = Transitions::new(&crack_map, &dungeon_entrance_map);
+
+        assert_eq!(transitions.shuffled_door_to("Eastern Palace"), Some(SkullWoods));
+        assert_eq!(transitions.shuffled_door_to("Tower of Hera"), None);
+        assert_eq!(transitions.shuffled_door_to("Kakariko Village"), None);
+    }
+
+    #[test]
+    fn lays_each_seeds_transitions_over_one_graph() {
+        use modinfo::settings::{Cracksanity, DungeonEntrances};
+        let shuffled = Settings {
+            cracksanity: Cracksanity::AnyWorldPairs,
+            dungeon_entrances: DungeonEntrances::AnyWorld,
+            ..Default::default()
+        };
+        let seeds = [(1, shuffled.clone()), (2, shuffled), (1, Settings::default())]
+            .map(|(seed, settings)| crate::generate_world_graph(seed, settings).unwrap());
+
+        assert!(seeds.iter().all(|seed_info| Arc::ptr_eq(&seed_info.world_graph, &seeds[0].world_graph)));
+        assert_ne!(seeds[0].crack_map, seeds[1].crack_map);
+        assert_ne!(seeds[0].dungeon_entrance_map, seeds[1].dungeon_entrance_map);
+
+        for seed_info in &seeds {
+            let transitions = seed_info.transitions();
+            let resolve = |location: Location| -> Vec<(Destination, Location)> {
+                let paths = seed_info.world_graph[&location].get_paths().iter().flatten();
+                paths.map(|path| (path.get_destination(), transitions.resolve(path.get_destination()))).collect()
+            };
+
+            for (&origin, _) in seed_info.world_graph.iter() {
+                for (destination, to) in resolve(origin) {
+                    match destination {
+                        Destination::Location(location) => assert_eq!(to, location),
+                        Destination::CrackLeft(crack) | Destination::CrackRight(crack) => {
+                            let paired = seed_info.crack_map[&crack];
+                            assert_eq!(seed_info.crack_map[&paired], crack);
+                            let (left, right) = paired.get_left_right_locations();
+                            assert!(to == left || to == right, "{:?} -> {} -> {:?}", origin, destination, to);
+                        },
+                        // Leaving the dungeon through its main entrance leads back out of the door that was entered
+                        Destination::DungeonEntrance(door) => {
+                            assert_eq!(to, transitions.dungeon_behind(door).get_foyer());
+                            let exits =
+                                resolve(to).into_iter().filter(|(exit, _)| matches!(exit, Destination::DungeonExit(_)));
+                            assert_eq!(exits.map(|(_, outside)| outside).collect::<Vec<_>>(), vec![door.get_outside()]);
+                        },
+                        Destination::DungeonExit(_) => {},
+                    }
+                }
+            }
+        }
+    }
 }
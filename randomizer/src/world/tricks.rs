@@ -0,0 +1,48 @@
+//! Named tricks, which logic rules may use on top of their `normal` requirement. Loaded from `tricks.json`.
+
+use crate::world::{load_file, LogicFileError};
+use lazy_static::lazy_static;
+use modinfo::settings::{logic::LogicMode, Settings};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct Trick {
+    /// The lowest [`LogicMode`] that includes the trick.
+    pub tier: LogicMode,
+    pub description: String,
+}
+
+lazy_static! {
+    static ref TRICKS: Result<BTreeMap<String, Trick>, LogicFileError> = load_tricks();
+}
+
+/// Every trick, keyed by the name used in logic files and `enabled_tricks`. Fails if `tricks.json` can't be loaded.
+pub fn tricks() -> Result<&'static BTreeMap<String, Trick>, LogicFileError> {
+    TRICKS.as_ref().map_err(Clone::clone)
+}
+
+fn load_tricks() -> Result<BTreeMap<String, Trick>, LogicFileError> {
+    let tricks: BTreeMap<String, Trick> = load_file("tricks.json", include_str!("../../../logic/tricks.json"))?;
+    for (name, trick) in &tricks {
+        if !(LogicMode::Hard..=LogicMode::Hell).contains(&trick.tier) {
+            let message = format!("Trick {} has invalid tier {:?}", name, trick.tier);
+            return Err(LogicFileError::new("tricks.json", message));
+        }
+    }
+    Ok(tricks)
+}
+
+/// Whether the trick is in logic with the given Settings, either because the [`LogicMode`] includes its tier or because
+/// it's one of the `enabled_tricks`. No Logic doesn't use tricks, as nothing is in logic to begin with. Tricks aren't
+/// in logic if `tricks.json` can't be loaded, which building the World Graph reports.
+pub fn is_in_logic(settings: &Settings, trick: &str) -> bool {
+    match settings.logic_mode {
+        LogicMode::NoLogic => false,
+        logic_mode => {
+            settings.enabled_tricks.contains(trick)
+                || tricks().is_ok_and(|tricks| tricks.get(trick).is_some_and(|trick| trick.tier <= logic_mode))
+        },
+    }
+}
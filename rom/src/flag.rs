@@ -1,4 +1,4 @@
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub enum Flag {
     Zero(u16),   // 0 - Reactions with system objects, not persisted
     One(u16),    // 1 - Flag persists until game is reset (I think?)
@@ -168,7 +168,7 @@ impl Flag {
         825: CRACK_FLOATING_ISLAND_HYRULE,
         826: CRACK_SWAMP_PILLAR_HYRULE,
         827: CRACK_ZORAS_DOMAIN,
-        // 828: ???,
+        828: YUGANON_REQUIREMENT, // Unused by the game, set by the randomizer once Yuga Ganon can be fought
         // 829: ???,
         // 830: ???,
         831: CRACK_THIEVES_TOWN,
@@ -228,6 +228,33 @@ impl Flag {
         956: TREACHEROUS_TOWER_BEGINNER_WON,
         957: TREACHEROUS_TOWER_INTERMEDIATE_WON,
         958: TREACHEROUS_TOWER_ADVANCED_WON,
+        // 966-988: Not used by the game as far as known (960, 964 and 965 are), set by the randomizer once a Small Key
+        // or Big Key of their dungeon is found
+        966: HYRULE_SANCTUARY_SMALL_KEYS,
+        967: EASTERN_SMALL_KEYS,
+        968: GALES_SMALL_KEYS,
+        969: HERA_SMALL_KEYS,
+        970: LORULE_SANCTUARY_SMALL_KEYS,
+        971: DARK_SMALL_KEYS,
+        972: SWAMP_SMALL_KEYS,
+        973: SKULL_SMALL_KEYS,
+        974: THIEVES_SMALL_KEYS,
+        975: ICE_SMALL_KEYS,
+        976: DESERT_SMALL_KEYS,
+        977: TURTLE_SMALL_KEYS,
+        978: LORULE_CASTLE_SMALL_KEYS,
+        979: EASTERN_BIG_KEY,
+        980: GALES_BIG_KEY,
+        981: HERA_BIG_KEY,
+        982: DARK_BIG_KEY,
+        983: SWAMP_BIG_KEY,
+        984: SKULL_BIG_KEY,
+        985: THIEVES_BIG_KEY,
+        986: ICE_BIG_KEY,
+        987: DESERT_BIG_KEY,
+        988: TURTLE_BIG_KEY,
+        // 989: Not used by the game as far as known, set by the randomizer once the starting items are granted
+        989: STARTING_ITEMS_GRANTED,
         // ...
         // 1017: LAST_FLAG,
     }
@@ -2,7 +2,7 @@ use crate::flag::Flag;
 use game::Item;
 use {
     crate::{
-        files::{msgbn::MsgBn, FromFile},
+        files::{align, msgbn, msgbn::MsgBn, FromFile},
         Error, Result,
     },
     bytey::*,
@@ -26,6 +26,11 @@ impl<'input> Flow<'input> {
     pub fn steps<'s>(&'s self) -> Steps<'s, 'input> {
         Steps(self)
     }
+
+    /// The number of steps and branches, which steps added with [`append_steps`] are numbered from.
+    pub fn counts(&self) -> (u16, u16) {
+        (self.steps.count, self.branches.0.count)
+    }
 }
 
 impl<'input> FromFile for Flow<'input> {
@@ -390,6 +395,57 @@ impl<'input> FromFile for FlowMut<'input> {
     }
 }
 
+/// Rebuilds an MSBF file with a copy of each of the `copies` steps appended to its steps, and `branches` unset branches
+/// appended to its branches.
+///
+/// The new steps and branches are numbered from the file's original step and branch counts, and can then be edited
+/// through [`FlowMut`] like any other step.
+pub fn append_steps(file: &[u8], copies: &[u16], branches: u16) -> Result<Vec<u8>> {
+    let sections = msgbn::sections::<2>(file, MSGFLWBN)?;
+    let (_, flw) = sections.iter().find(|(magic, _)| magic == FLW3).ok_or_else(|| Error::new("No FLW3"))?;
+    let (step_ct, branch_ct, index) = get_flw(&file[flw.clone()])?;
+
+    let new_step_ct = u16::try_from(copies.len()).ok().and_then(|copies| step_ct.checked_add(copies));
+    let new_branch_ct = branch_ct.checked_add(branches);
+    let (Some(new_step_ct), Some(new_branch_ct)) = (new_step_ct, new_branch_ct) else {
+        return Err(Error::new("Too many steps or branches."));
+    };
+
+    let (header, body) = file[flw.clone()].split_at(0x10);
+    let (steps, rest) = body.split_at(index);
+    let (old_branches, trailing) = rest.split_at(BRANCH_LEN * branch_ct as usize);
+
+    let mut section = Vec::with_capacity(flw.len() + STEP_LEN * copies.len() + BRANCH_LEN * branches as usize);
+    section.extend_from_slice(&new_step_ct.to_le_bytes());
+    section.extend_from_slice(&new_branch_ct.to_le_bytes());
+    section.extend_from_slice(&header[4..]);
+    section.extend_from_slice(steps);
+    for &copy in copies {
+        let start = STEP_LEN * copy as usize;
+        let step = steps
+            .get(start..start + STEP_LEN)
+            .ok_or_else(|| Error::new(format!("Could not find step {} to copy.", copy)))?;
+        section.extend_from_slice(step);
+    }
+    section.extend_from_slice(old_branches);
+    section.extend((0..branches).flat_map(|_| 0xFFFFu16.to_le_bytes()));
+    section.extend_from_slice(trailing);
+
+    // Everything before and after the FLW3 section is kept as-is, only its size and padding change
+    let section_header = flw.start - 0x10;
+    let section_end = flw.start + align::<0x10>(flw.len() as u32) as usize;
+    let mut rebuilt = file[..flw.start].to_vec();
+    rebuilt[section_header + 4..section_header + 8].copy_from_slice(&(section.len() as u32).to_le_bytes());
+    rebuilt.extend_from_slice(&section);
+    rebuilt.resize(align::<0x10>(rebuilt.len() as u32) as usize, 0xAB);
+    rebuilt.extend_from_slice(&file[section_end..]);
+
+    let size = rebuilt.len() as u32;
+    rebuilt[0x12..0x16].copy_from_slice(&size.to_le_bytes());
+
+    Ok(rebuilt)
+}
+
 #[derive(Debug)]
 pub struct StepMut<'flow, 'input> {
     flow: &'flow mut FlowMut<'input>,
@@ -722,3 +778,89 @@ const FLW3: &[u8; 4] = b"FLW3";
 const HEADER_LEN: usize = 0x4;
 const STEP_LEN: usize = 0x10;
 const BRANCH_LEN: usize = 2;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+
+    /// Builds an MSBF file from raw steps and branches, with a small FEN1 section after them.
+    fn msbf(steps: &[[u8; STEP_LEN]], branches: &[u16]) -> Vec<u8> {
+        fn section(file: &mut Vec<u8>, magic: &[u8; 4], data: &[u8]) {
+            file.extend_from_slice(magic);
+            file.extend_from_slice(&(data.len() as u32).to_le_bytes());
+            file.extend_from_slice(&[0; 8]);
+            file.extend_from_slice(data);
+            file.resize(align::<0x10>(file.len() as u32) as usize, 0xAB);
+        }
+
+        let mut flw = Vec::new();
+        flw.extend_from_slice(&(steps.len() as u16).to_le_bytes());
+        flw.extend_from_slice(&(branches.len() as u16).to_le_bytes());
+        flw.extend_from_slice(&[0; 12]);
+        steps.iter().for_each(|step| flw.extend_from_slice(step));
+        branches.iter().for_each(|branch| flw.extend_from_slice(&branch.to_le_bytes()));
+
+        let mut file = MSGFLWBN.to_vec();
+        file.extend_from_slice(&0xFEFFu16.to_le_bytes());
+        file.resize(0x20, 0);
+        file[0xE] = 2; // section count
+        section(&mut file, FLW3, &flw);
+        section(&mut file, b"FEN1", &[1, 2, 3, 4, 5, 6]);
+
+        let size = file.len() as u32;
+        file[0x12..0x16].copy_from_slice(&size.to_le_bytes());
+        file
+    }
+
+    fn step(kind: u8, value: u32, next: u16, command: u16, count: u16, branch: u16) -> [u8; STEP_LEN] {
+        let mut step = [0; STEP_LEN];
+        step[0] = kind;
+        step[4..8].copy_from_slice(&value.to_le_bytes());
+        step[8..0xA].copy_from_slice(&next.to_le_bytes());
+        step[0xA..0xC].copy_from_slice(&command.to_le_bytes());
+        step[0xC..0xE].copy_from_slice(&count.to_le_bytes());
+        step[0xE..0x10].copy_from_slice(&branch.to_le_bytes());
+        step
+    }
+
+    #[test]
+    fn append_steps_keeps_existing_steps() -> Result<()> {
+        let file = msbf(&[step(2, 7, 0xFFFF, 14, 2, 0), step(3, 629, 0xFFFF, 30, 0, 0)], &[0xFFFF, 1]);
+        let mut appended = append_steps(&file, &[0, 1], 2)?;
+
+        let fen = msgbn::sections::<2>(&appended, MSGFLWBN)?[1].clone();
+        assert_eq!(fen.0, *b"FEN1");
+        assert_eq!(appended[fen.1], [1, 2, 3, 4, 5, 6]);
+
+        {
+            let cell = RefCell::new(appended.clone());
+            let flow = Flow::from_file(std::cell::Ref::map(cell.borrow(), Vec::as_slice))?;
+            let steps = flow.steps().iter().collect::<Result<Vec<_>>>()?;
+            assert_eq!(steps.len(), 4);
+            assert!(matches!(&steps[0], Step::Branch { branches, .. } if branches == &[None, Some(1)]));
+            assert!(matches!(&steps[3], Step::Action { command: Action::Other(30, 629), next: None }));
+            assert_eq!(flow.branches.iter().collect::<Vec<_>>(), [0xFFFF, 1, 0xFFFF, 0xFFFF]);
+        }
+
+        let mut flow = FlowMut::from_file(&mut appended)?;
+        let mut branch = flow.get_mut(2).unwrap().convert_into_branch(2, 2).unwrap();
+        branch.set_value(3);
+        branch.set_branch(1, 3)?;
+
+        let cell = RefCell::new(appended.clone());
+        let flow = Flow::from_file(std::cell::Ref::map(cell.borrow(), Vec::as_slice))?;
+        assert!(matches!(
+            flow.steps().get(2).unwrap()?,
+            Step::Branch { command: Branch::Other(14, 3), branches } if branches == [None, Some(3)]
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn append_steps_rejects_missing_step() {
+        let file = msbf(&[step(4, 0, 0xFFFF, 0, 0, 0)], &[]);
+        assert!(append_steps(&file, &[1], 0).is_err());
+    }
+}
@@ -207,6 +207,16 @@ impl Stage {
         }
     }
 
+    /// Finds the UNQs of the Objs that load the given Spawn Point, i.e. the doors and loading zones leading to it
+    pub fn find_objs_leading_to(&self, sp: SpawnPoint) -> Vec<u16> {
+        self.objs.iter().filter(|obj| obj.leads_to(sp)).map(|obj| obj.unq).collect()
+    }
+
+    /// Whether the stage has a Spawn Point system object numbered `spawn`
+    pub fn has_spawn_point(&self, spawn: i32) -> bool {
+        self.system.iter().any(|obj| obj.id == 7 && obj.arg.0 == spawn)
+    }
+
     /// Finds the lowest currently unused Objs UNQ
     pub fn find_objs_unq(&self) -> u16 {
         self.objs.iter().fold(0, |max_unq, obj| if obj.unq > max_unq { obj.unq } else { max_unq }) + 1
@@ -624,6 +634,11 @@ impl Obj {
         self.arg.10 = sp.course as i32;
         self.arg.11 = sp.scene - 1;
     }
+
+    /// Whether this Obj loads the given Spawn Point, the inverse of [`Obj::redirect`]
+    pub fn leads_to(&self, sp: SpawnPoint) -> bool {
+        self.arg.0 == sp.spawn && self.arg.10 == sp.course as i32 && self.arg.11 == sp.scene - 1
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize, Copy, Clone)]
@@ -0,0 +1,260 @@
+use log::{info, LevelFilter};
+use modinfo::Settings;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use randomizer::{filler::filler_item::Item, system::UserConfig, Error, ErrorKind};
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use std::sync::Mutex;
+use std::thread;
+
+/// Result of one requested seed in a batch, after any retries.
+enum Outcome {
+    Generated { seed: u32, attempts: u32, spheres: usize, major_items: Vec<(usize, Item)> },
+    Failed { attempts: u32 },
+}
+
+/// Generates `count` seeds with the same Settings across `jobs` worker threads, writing a spoiler log for each one.
+///
+/// Every seed number is derived from `base_seed`, so the same batch can be reproduced later. Errors that would affect
+/// every seed in the batch (invalid settings, unwritable output) abort the whole batch; anything else is retried up to
+/// [`crate::MAX_RETRIES`] times before that seed is counted as failed.
+pub fn run(
+    settings: &Settings, user_config: &UserConfig, base_seed: u32, count: u32, jobs: usize, no_spoiler: bool,
+) -> Result<Report, Error> {
+    let next = AtomicU32::new(0);
+    let abort = AtomicBool::new(false);
+    let outcomes = Mutex::new(Vec::with_capacity(count as usize));
+    let error = Mutex::new(None);
+
+    // Per-seed logging is far too noisy to interleave across threads. The level is global, so put it back afterwards.
+    let max_level = log::max_level();
+    log::set_max_level(max_level.min(LevelFilter::Warn));
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.clamp(1, count.max(1) as usize) {
+            scope.spawn(|| {
+                while !abort.load(Ordering::Relaxed) {
+                    let index = next.fetch_add(1, Ordering::Relaxed);
+                    if index >= count {
+                        break;
+                    }
+
+                    match generate(settings, user_config, base_seed, index, no_spoiler) {
+                        Ok(outcome) => {
+                            let mut outcomes = outcomes.lock().unwrap();
+                            match &outcome {
+                                Outcome::Generated { seed, attempts, spheres, .. } => println!(
+                                    "[{:>4}/{}] Generated Seed {:0>10} in {} attempt(s): {} spheres",
+                                    outcomes.len() + 1,
+                                    count,
+                                    seed,
+                                    attempts,
+                                    spheres
+                                ),
+                                Outcome::Failed { attempts } => println!(
+                                    "[{:>4}/{}] Failed to generate a Seed in {} attempts",
+                                    outcomes.len() + 1,
+                                    count,
+                                    attempts
+                                ),
+                            }
+                            outcomes.push(outcome);
+                        },
+                        Err(err) => {
+                            abort.store(true, Ordering::Relaxed);
+                            error.lock().unwrap().get_or_insert(err);
+                        },
+                    }
+                }
+            });
+        }
+    });
+
+    log::set_max_level(max_level);
+
+    match error.into_inner().unwrap() {
+        Some(err) => Err(err),
+        None => Ok(Report::new(count, outcomes.into_inner().unwrap())),
+    }
+}
+
+/// Generates the seed at `index` in the batch, retrying with new seed numbers if it isn't completable.
+fn generate(
+    settings: &Settings, user_config: &UserConfig, base_seed: u32, index: u32, no_spoiler: bool,
+) -> Result<Outcome, Error> {
+    let rng = &mut StdRng::seed_from_u64(((base_seed as u64) << 32) | index as u64);
+
+    for attempt in 1..=crate::MAX_RETRIES as u32 {
+        let seed = rng.gen();
+        match randomizer::generate_seed_info(seed, settings.clone()) {
+            Ok(seed_info) => {
+                randomizer::patch_seed(&seed_info, user_config, true, no_spoiler)?;
+                return Ok(Outcome::Generated {
+                    seed,
+                    attempts: attempt,
+                    spheres: seed_info.metrics.spheres(),
+                    major_items: seed_info.metrics.major_items_by_sphere().collect(),
+                });
+            },
+            Err(err) => match err.kind() {
+                ErrorKind::InvalidSettings
+                | ErrorKind::UnknownCheck
+                | ErrorKind::InvalidLogic
+                | ErrorKind::Io
+                | ErrorKind::OutputNotWritable => {
+                    return Err(err);
+                },
+                _ => continue,
+            },
+        }
+    }
+
+    Ok(Outcome::Failed { attempts: crate::MAX_RETRIES as u32 })
+}
+
+/// Aggregate statistics for a batch of seeds.
+pub struct Report {
+    requested: u32,
+    generated: u32,
+    attempts: u32,
+    max_retries: u32,
+    total_spheres: usize,
+    /// Count of each major Item's appearances per Sphere index, keyed by the Item's display name.
+    item_spheres: BTreeMap<&'static str, BTreeMap<usize, u32>>,
+}
+
+impl Report {
+    fn new(requested: u32, outcomes: Vec<Outcome>) -> Self {
+        let mut report = Self {
+            requested,
+            generated: 0,
+            attempts: 0,
+            max_retries: 0,
+            total_spheres: 0,
+            item_spheres: Default::default(),
+        };
+
+        for outcome in outcomes {
+            match outcome {
+                Outcome::Generated { attempts, spheres, major_items, .. } => {
+                    report.generated += 1;
+                    report.attempts += attempts;
+                    report.max_retries = report.max_retries.max(attempts - 1);
+                    report.total_spheres += spheres;
+                    for (sphere, item) in major_items {
+                        *report.item_spheres.entry(item.as_str()).or_default().entry(sphere).or_default() += 1;
+                    }
+                },
+                Outcome::Failed { attempts } => {
+                    report.attempts += attempts;
+                },
+            }
+        }
+
+        report
+    }
+
+    /// Whether at least one seed was generated.
+    pub fn any_generated(&self) -> bool {
+        self.generated > 0
+    }
+
+    /// Retries for successful seeds only, as failed seeds always used every attempt.
+    fn retries(&self) -> u32 {
+        self.attempts - self.generated - (self.requested - self.generated) * crate::MAX_RETRIES as u32
+    }
+
+    /// Logs the summary of this batch.
+    pub fn log(&self) {
+        let failed_attempts = self.attempts - self.generated;
+
+        println!();
+        info!("Batch Summary");
+        info!("Seeds Generated:                {}/{}", self.generated, self.requested);
+        info!(
+            "Failed Attempts:                {}/{} ({:.1}%)",
+            failed_attempts,
+            self.attempts,
+            percent(failed_attempts, self.attempts)
+        );
+
+        if !self.any_generated() {
+            return;
+        }
+
+        info!(
+            "Retries per Seed:               {:.2} average, {} max",
+            self.retries() as f64 / self.generated as f64,
+            self.max_retries
+        );
+        info!("Average Spheres:                {:.2}", self.total_spheres as f64 / self.generated as f64);
+
+        println!();
+        info!("Major Item Sphere Distribution  (sphere: count)");
+        for (item, spheres) in &self.item_spheres {
+            let appearances: u32 = spheres.values().sum();
+            let average = spheres.iter().map(|(&sphere, &n)| sphere as u32 * n).sum::<u32>() as f64 / appearances as f64;
+            let distribution =
+                spheres.iter().map(|(sphere, n)| format!("{}: {}", sphere, n)).collect::<Vec<_>>().join(", ");
+            info!("{:<31} {:>5.2} avg | {}", format!("{}:", item), average, distribution);
+        }
+    }
+}
+
+fn percent(n: u32, total: u32) -> f64 {
+    if total == 0 {
+        0.0
+    } else {
+        100.0 * n as f64 / total as f64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn generated(attempts: u32, major_items: Vec<(usize, Item)>) -> Outcome {
+        Outcome::Generated { seed: 0, attempts, spheres: major_items.len() + 1, major_items }
+    }
+
+    #[test]
+    fn counts_outcomes() {
+        let max_retries = crate::MAX_RETRIES as u32;
+        let report = Report::new(
+            4,
+            vec![
+                generated(1, vec![(0, Item::Bow01), (1, Item::Hookshot01)]),
+                Outcome::Failed { attempts: max_retries },
+                generated(3, vec![(1, Item::Bow01)]),
+                generated(2, vec![]),
+            ],
+        );
+
+        assert!(report.any_generated());
+        assert_eq!(report.requested, 4);
+        assert_eq!(report.generated, 3);
+        assert_eq!(report.attempts, 6 + max_retries);
+        assert_eq!(report.retries(), 3);
+        assert_eq!(report.max_retries, 2);
+        assert_eq!(report.total_spheres, 3 + 2 + 1);
+        assert_eq!(
+            report.item_spheres,
+            BTreeMap::from([
+                (Item::Bow01.as_str(), BTreeMap::from([(0, 1), (1, 1)])),
+                (Item::Hookshot01.as_str(), BTreeMap::from([(1, 1)])),
+            ])
+        );
+    }
+
+    #[test]
+    fn counts_failed_batches() {
+        let max_retries = crate::MAX_RETRIES as u32;
+        let report =
+            Report::new(2, vec![Outcome::Failed { attempts: max_retries }, Outcome::Failed { attempts: max_retries }]);
+
+        assert!(!report.any_generated());
+        assert_eq!(report.attempts, 2 * max_retries);
+        assert_eq!(report.retries(), 0);
+        assert!(report.item_spheres.is_empty());
+    }
+}
@@ -1,11 +1,14 @@
 use log::{error, info, LevelFilter};
 use macros::fail;
+use modinfo::settings::DungeonEntrances;
+use modinfo::settings::DungeonItems;
+use modinfo::settings::ItemPool;
 use modinfo::settings::Keysy;
 use modinfo::settings::RaviosShop;
 use modinfo::settings::TrialsDoor;
 use modinfo::settings::WeatherVanes;
 use modinfo::settings::{Cracks, NiceItems};
-use modinfo::settings::{Cracksanity, LogicMode, PedestalSetting, Settings};
+use modinfo::settings::{settings_string, Cracksanity, LogicMode, PedestalSetting, Settings};
 use randomizer::filler::cracks::Crack;
 use randomizer::filler::filler_item::Item::*;
 use randomizer::filler::filler_item::Randomizable;
@@ -18,7 +21,7 @@ use randomizer::{
     CrackMap, Layout, SeedHash, SeedInfo, VaneMap,
 };
 use simplelog::SimpleLogger;
-use std::collections::BTreeSet;
+use std::collections::{BTreeMap, BTreeSet};
 use structopt::StructOpt;
 
 #[derive(Debug, StructOpt)]
@@ -56,11 +59,15 @@ fn main() {
         seed,
         version: String::from(VERSION),
         hash: SeedHash::new(seed, &settings),
+        settings_string: settings_string::encode(&settings),
         settings,
         full_exclusions: Default::default(),
+        full_priorities: Default::default(),
+        junk_counts: Default::default(),
         treacherous_tower_floors: vec![],
         vane_map: get_plando_weather_vane_map(),
         crack_map: get_plando_crack_map(),
+        dungeon_entrance_map: Default::default(),
         layout: build_layout(),
         metrics: Default::default(),
         text: Default::default(),
@@ -182,24 +189,32 @@ fn plando_settings() -> Settings {
         yuganon_requirement: 7,
         ped_requirement: PedestalSetting::Standard,
         logic_mode: LogicMode::Normal,
+        enabled_tricks: BTreeSet::default(),
         dark_rooms_lampless: false,
         dungeon_prize_shuffle: true,
         maiamai_limit: 50,
         maiamai_madness: false,
         nice_items: NiceItems::Off,
         super_items: false,
+        item_pool: ItemPool::Balanced,
+        junk_weights: Default::default(),
         lamp_and_net_as_weapons: false,
         cracks: Cracks::Open,
         cracksanity: Cracksanity::AnyWorldPairs,
+        dungeon_entrances: DungeonEntrances::Off,
         weather_vanes: WeatherVanes::Hyrule,
         ravios_shop: RaviosShop::Open,
         bow_of_light_in_castle: false,
         no_progression_enemies: false,
         keysy: Keysy::Off,
+        small_key_shuffle: DungeonItems::OwnDungeon,
+        big_key_shuffle: DungeonItems::OwnDungeon,
+        key_rings: false,
         progressive_bow_of_light: false,
         swordless_mode: false,
         start_with_merge: true,
         start_with_pouch: true,
+        starting_items: BTreeMap::default(),
         bell_in_shop: false,
         sword_in_shop: false,
         boots_in_shop: false,
@@ -212,6 +227,9 @@ fn plando_settings() -> Settings {
         purple_potion_bottles: true,
         night_mode: false,
         user_exclusions: BTreeSet::default(),
+        user_priorities: BTreeSet::default(),
+        plando: Default::default(),
+        logic_overrides: Default::default(),
     }
 }
 
@@ -1,5 +1,9 @@
 use log::warn;
-use randomizer::SeedInfo;
+use randomizer::{ErrorKind, SeedInfo};
+use modinfo::settings::{metadata, mystery::MysteryWeights, settings_string, validation::Problem, Settings};
+use rand::{rngs::StdRng, SeedableRng};
+use settings_opt::SettingsOpt;
+use std::path::PathBuf;
 use {
     log::{error, info},
     macros::fail,
@@ -7,46 +11,239 @@ use {
         constants::VERSION,
         system::{System, UserConfig},
     },
-    simplelog::{LevelFilter, SimpleLogger},
-    structopt::StructOpt,
+    simplelog::{LevelFilter, SimpleLogger, WriteLogger},
+    structopt::{clap::ErrorKind as ClapErrorKind, StructOpt},
 };
 
+mod batch;
+mod settings_opt;
+
+/// Exit code for settings that could not be loaded, parsed, or validated.
+const EXIT_BAD_SETTINGS: i32 = 2;
+/// Exit code for settings that could not produce a completable seed.
+const EXIT_UNBEATABLE: i32 = 3;
+/// Exit code for failures reading the ROM or writing output files.
+const EXIT_ROM_IO: i32 = 4;
+/// Exit code for any other failure while patching the game.
+const EXIT_PATCH_FAILURE: i32 = 5;
+
+/// Maximum number of attempts to generate a completable seed.
+const MAX_RETRIES: u16 = 100;
+
 #[derive(Debug, StructOpt, Clone)]
 struct Opt {
     #[structopt(long)]
     seed: Option<u32>,
 
+    /// Name of a preset in the `presets` directory, or a path to any preset file.
     #[structopt(long)]
     preset: Option<String>,
 
+    /// Path to a JSON file containing only Settings (the `settings` portion of a preset).
+    #[structopt(long, parse(from_os_str), conflicts_with = "preset")]
+    settings: Option<PathBuf>,
+
+    /// A compact settings string, as printed by the randomizer and included in spoiler logs.
+    #[structopt(long, conflicts_with_all = &["preset", "settings"])]
+    settings_string: Option<String>,
+
+    /// Never wait on user input: no Seed Settings prompts and no pausing before exit.
+    #[structopt(long, alias = "non-interactive")]
+    headless: bool,
+
+    /// Check the Settings for problems, then exit without generating a seed. Exits with code 2 if any errors are found.
+    #[structopt(long)]
+    validate: bool,
+
+    /// Print the results of `--validate` as JSON.
+    #[structopt(long, requires = "validate")]
+    json: bool,
+
+    /// Print the Settings resolved from all presets and command line options, then exit without generating a seed.
+    #[structopt(long)]
+    print_effective_settings: bool,
+
+    /// Path to a Mystery weights file. Settings are rolled from the seed, starting from the preset or settings given.
+    #[structopt(long, parse(from_os_str), conflicts_with = "count")]
+    mystery: Option<PathBuf>,
+
+    /// Don't print the Settings or settings string to the console. They are still written to the spoiler log.
+    #[structopt(long)]
+    hide_settings: bool,
+
     #[structopt(long)]
     no_patch: bool,
 
     #[structopt(long)]
     no_spoiler: bool,
+
+    /// Only write the spoiler log, without patching the ROM. Equivalent to `--no-patch`.
+    #[structopt(long)]
+    spoiler_only: bool,
+
+    /// Generate this many seeds in batch mode, then print a summary of the results. Requires `--spoiler-only`.
+    #[structopt(long)]
+    count: Option<u32>,
+
+    /// Number of worker threads to use in batch mode. Defaults to the number of available CPUs.
+    #[structopt(long, requires = "count")]
+    jobs: Option<usize>,
+
+    #[structopt(flatten)]
+    settings_opt: SettingsOpt,
+
+    #[structopt(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(Debug, StructOpt, Clone)]
+enum Command {
+    /// Print a JSON Schema describing every setting (types, allowed values, defaults and help text), then exit.
+    Schema,
+    /// Print every trick that can be added to `enabled_tricks`, with its logic tier and description, then exit.
+    Tricks,
+    /// Export the World Graph of the Settings and seed, with the requirements of every edge and check, then exit.
+    Graph {
+        /// `dot` for Graphviz, or `json`.
+        #[structopt(long, possible_values = &["dot", "json"], default_value = "dot")]
+        format: String,
+
+        /// File to write the graph to, instead of printing it.
+        #[structopt(long, parse(from_os_str))]
+        output: Option<PathBuf>,
+    },
 }
 
 /// THE LEGEND OF ZELDA: A LINK BETWEEN WORLDS RANDOMIZER
 fn main() {
-    let opt = Opt::from_args();
+    let opt = Opt::from_args_safe().unwrap_or_else(|err| match err.kind {
+        ClapErrorKind::HelpDisplayed | ClapErrorKind::VersionDisplayed => err.exit(),
+        _ => {
+            eprintln!("{}", err.message);
+            std::process::exit(EXIT_BAD_SETTINGS);
+        },
+    });
+
+    match opt.command {
+        Some(Command::Schema) => {
+            println!(
+                "{}",
+                serde_json::to_string_pretty(&metadata::json_schema()).expect("Could not serialize JSON Schema.")
+            );
+            return;
+        },
+        Some(Command::Tricks) => {
+            let tricks = randomizer::tricks().unwrap_or_else(|err| {
+                eprintln!("{}", err);
+                std::process::exit(EXIT_BAD_SETTINGS);
+            });
+            println!("{}", serde_json::to_string_pretty(tricks).expect("Could not serialize Tricks."));
+            return;
+        },
+        Some(Command::Graph { .. }) | None => {},
+    }
 
-    SimpleLogger::init(LevelFilter::Info, Default::default()).expect("Could not initialize logger.");
+    cli::set_interactive(!opt.headless);
+
+    if opt.json || matches!(opt.command, Some(Command::Graph { output: None, .. })) {
+        // Keep stdout clean for the JSON report or graph
+        WriteLogger::init(LevelFilter::Info, Default::default(), std::io::stderr())
+    } else {
+        SimpleLogger::init(LevelFilter::Info, Default::default())
+    }
+    .expect("Could not initialize logger.");
 
     info!("Initializing ALBW Randomizer...");
 
-    let (preset_name, seeded, SeedInfo { mut seed, mut settings, .. }) = determine_settings(opt.preset, opt.seed);
+    let (preset_name, seeded, SeedInfo { mut seed, mut settings, .. }) = determine_settings(&opt);
 
-    settings.yuganon_requirement = settings.lc_requirement; // FIXME Temporary: Force Yuganon Requirement to be equal to LC Requirement
+    let mystery = opt.mystery.as_ref().map(|path| {
+        let weights = System::load_mystery_weights(path).unwrap_or_else(|err| {
+            fail!(exit_code: EXIT_BAD_SETTINGS, "Failed to load mystery weights: {}\nError: {}", path.display(), err);
+        });
+        (weights, settings.clone())
+    });
+    if let Some((weights, base)) = &mystery {
+        settings = roll_mystery_settings(weights, base, seed);
+    }
+
+    if opt.print_effective_settings {
+        println!("{}", serde_json::to_string_pretty(&settings).expect("Could not serialize Settings."));
+        return;
+    }
+
+    let problems = randomizer::settings_problems(&settings).unwrap_or_else(|err| {
+        fail!(exit_code: EXIT_BAD_SETTINGS, "{}", err);
+    });
+    let valid = !problems.iter().any(Problem::is_error);
+
+    if opt.validate {
+        if opt.json {
+            let report = serde_json::json!({ "valid": valid, "problems": problems });
+            println!("{}", serde_json::to_string_pretty(&report).expect("Could not serialize Problems."));
+        } else {
+            log_problems(&problems);
+            if problems.is_empty() {
+                info!("No problems found.");
+            }
+        }
+        std::process::exit(if valid { 0 } else { EXIT_BAD_SETTINGS });
+    }
+
+    log_problems(&problems);
+    if !valid {
+        fail!(exit_code: EXIT_BAD_SETTINGS, "Invalid Settings.");
+    }
+
+    if let Some(Command::Graph { format, output }) = &opt.command {
+        export_world_graph(seed, settings, format, output.as_ref());
+        return;
+    }
 
     // Load User Config
     let user_config: UserConfig = System::load_config().unwrap_or_else(|error| {
-        fail!("Failed to parse configuration file: config.json\n\
+        fail!(exit_code: EXIT_ROM_IO, "Failed to parse configuration file: config.json\n\
                 Commonly Fixed By: Replace any single backslash characters '\\' with a forward slash '/' or double backslash '\\\\'.\n\
                 Full Error: {}\n", error);
     });
 
+    let no_patch = opt.no_patch || opt.spoiler_only;
+
+    if let Some(count) = opt.count {
+        if !no_patch {
+            fail!(
+                exit_code: EXIT_BAD_SETTINGS,
+                "Batch mode only writes spoiler logs, as every patch would overwrite the same output. Add --spoiler-only."
+            );
+        }
+
+        let jobs = opt.jobs.unwrap_or_else(|| std::thread::available_parallelism().map_or(1, |n| n.get()));
+        log_settings_string(&settings, opt.hide_settings);
+        info!("Generating {} Seeds with {} worker(s) from Base Seed {:0>10}...\n", count, jobs, seed);
+
+        match batch::run(&settings, &user_config, seed, count, jobs, opt.no_spoiler) {
+            Ok(report) => {
+                report.log();
+                if !report.any_generated() {
+                    fail!(exit_code: EXIT_UNBEATABLE, "No Seeds could be generated with the given settings.");
+                }
+            },
+            Err(err) => {
+                let exit_code = match err.kind() {
+                    ErrorKind::InvalidSettings | ErrorKind::UnknownCheck | ErrorKind::InvalidLogic => EXIT_BAD_SETTINGS,
+                    ErrorKind::Io | ErrorKind::OutputNotWritable => EXIT_ROM_IO,
+                    _ => EXIT_PATCH_FAILURE,
+                };
+                fail!(exit_code: exit_code, "Batch aborted: {}", err);
+            },
+        }
+
+        println!();
+        cli::pause();
+        return;
+    }
+
     // Generate Seed in a retryable manner
-    const MAX_RETRIES: u16 = 100;
     for x in 0..MAX_RETRIES {
         info!("Attempt:                        #{}", x + 1);
         info!(
@@ -55,10 +252,22 @@ fn main() {
         );
         info!("Version:                        {}", VERSION);
         info!("Seed:                           {:0>10}", seed);
+        log_settings_string(&settings, opt.hide_settings);
+        if mystery.is_some() && !opt.hide_settings {
+            settings.log_settings();
+        }
 
         let stopwatch = std::time::Instant::now();
-        match randomizer::generate_seed(seed, settings.clone(), &user_config, opt.no_patch, opt.no_spoiler) {
-            Ok(_) => {
+        match randomizer::generate_seed_info(seed, settings.clone()) {
+            Ok(seed_info) => {
+                if let Err(err) = randomizer::patch_seed(&seed_info, &user_config, no_patch, opt.no_spoiler) {
+                    let exit_code = match err.kind() {
+                        ErrorKind::Io | ErrorKind::OutputNotWritable => EXIT_ROM_IO,
+                        _ => EXIT_PATCH_FAILURE,
+                    };
+                    fail!(exit_code: exit_code, "Failed to patch Seed: {}", err);
+                }
+
                 println!();
                 info!("Successfully Generated ALBWR Seed {} in {} seconds! :D", seed, stopwatch.elapsed().as_secs());
                 println!();
@@ -68,16 +277,33 @@ fn main() {
                 break;
             },
             Err(err) => {
-                error!("{:?}", err);
-                if x < MAX_RETRIES {
+                match err.kind() {
+                    ErrorKind::InvalidSettings | ErrorKind::UnknownCheck => {
+                        fail!(exit_code: EXIT_BAD_SETTINGS, "Invalid Settings: {}", err);
+                    },
+                    ErrorKind::InvalidLogic => fail!(exit_code: EXIT_BAD_SETTINGS, "{}", err),
+                    ErrorKind::Io => fail!(exit_code: EXIT_ROM_IO, "{}", err),
+                    _ => error!("{}", err),
+                }
+                // Only the report of the attempt that gives up is kept, retries roll a new seed anyway
+                let is_last_attempt = seeded || x == MAX_RETRIES - 1;
+                if is_last_attempt {
+                    if let Err(err) = randomizer::write_failure_report(&err, &user_config, opt.no_spoiler) {
+                        error!("Could not write Failure Report: {}", err);
+                    }
+                }
+                if x < MAX_RETRIES - 1 {
                     if !seeded {
                         info!("Seed was not completable. Retrying...\n");
                         seed = rand::random();
+                        if let Some((weights, base)) = &mystery {
+                            settings = roll_mystery_settings(weights, base, seed);
+                        }
                     } else {
-                        fail!("Couldn't generate Seed: \"{}\" with the given settings.", seed);
+                        fail!(exit_code: EXIT_UNBEATABLE, "Couldn't generate Seed: \"{}\" with the given settings.", seed);
                     }
                 } else {
-                    fail!("Too many retry attempts have failed. Aborting...");
+                    fail!(exit_code: EXIT_UNBEATABLE, "Too many retry attempts have failed. Aborting...");
                 }
             },
         }
@@ -87,55 +313,105 @@ fn main() {
     cli::pause();
 }
 
-/// Get Settings, either from a preset or the CLI
-fn determine_settings(opt_preset: Option<String>, opt_seed: Option<u32>) -> (Option<String>, bool, SeedInfo) {
-    if let Some(preset_name) = opt_preset {
-        let preset_name = preset_name.clone();
-        let mut seed_info = System::load_preset(&(preset_name.clone())).unwrap_or_else(|err| {
-            fail!("Failed to load preset: {}\nError: {}", preset_name, err);
-        });
+/// Roll Mystery Settings from the seed, so the same seed and weights always produce the same Settings
+fn roll_mystery_settings(weights: &MysteryWeights, base: &Settings, seed: u32) -> Settings {
+    weights.roll(base, &mut StdRng::seed_from_u64(seed as u64)).unwrap_or_else(|err| {
+        fail!(exit_code: EXIT_BAD_SETTINGS, "Failed to roll mystery settings: {}", err);
+    })
+}
 
-        if seed_info.version != VERSION {
-            fail!(
-                "There is a Version mismatch between the Randomizer and the Preset file.\n\
-                Randomizer Version: \"{}\"\n\
-                Preset Version:     \"{}\"",
-                VERSION,
-                seed_info.version
-            );
-        }
+/// Build the World Graph the seed would use and write it out in the given format
+fn export_world_graph(seed: u32, settings: Settings, format: &str, output: Option<&PathBuf>) {
+    info!("Seed:                           {:0>10}", seed);
 
-        let mut seeded = false;
-        if let Some(seed) = opt_seed {
-            if seed_info.seed != 0 {
-                println!();
-                warn!("Two seed numbers provided! Defaulting to the command line argument.\n");
-            }
+    let seed_info = randomizer::generate_world_graph(seed, settings).unwrap_or_else(|err| {
+        fail!(exit_code: EXIT_BAD_SETTINGS, "Failed to build World Graph: {}", err);
+    });
+    let (world_graph, transitions) = (&seed_info.world_graph, seed_info.transitions());
+    let exported = match format {
+        "json" => {
+            serde_json::to_string_pretty(&world_graph.export(&transitions)).expect("Could not serialize World Graph.")
+        },
+        _ => world_graph.to_dot(&transitions),
+    };
+
+    match output {
+        Some(path) => {
+            std::fs::write(path, exported).unwrap_or_else(|err| {
+                fail!(exit_code: EXIT_ROM_IO, "Failed to write World Graph: {}\nError: {}", path.display(), err);
+            });
+            info!("Wrote World Graph to:           {}", path.display());
+        },
+        None => println!("{}", exported),
+    }
+}
 
-            seed_info.seed = seed;
-            seeded = true;
-        } else if seed_info.seed != 0 {
-            seeded = true;
+/// Log the settings string, unless Settings are hidden from the console
+fn log_settings_string(settings: &Settings, hidden: bool) {
+    if hidden {
+        info!("Settings String:                <hidden>");
+    } else {
+        info!("Settings String:                {}", settings_string::encode(settings));
+    }
+}
+
+/// Log each Settings problem at a level matching its severity
+fn log_problems(problems: &[Problem]) {
+    for problem in problems {
+        if problem.is_error() {
+            error!("{}", problem);
         } else {
-            seed_info.seed = rand::random();
+            warn!("{}", problem);
         }
+    }
+}
 
-        (Some(preset_name), seeded, seed_info)
+/// Get Settings, either from a preset, a settings file, the command line, or the interactive CLI
+fn determine_settings(opt: &Opt) -> (Option<String>, bool, SeedInfo) {
+    let (preset_name, mut seed_info) = if let Some(preset_name) = &opt.preset {
+        let seed_info = System::load_preset(preset_name).unwrap_or_else(|err| {
+            fail!(exit_code: EXIT_BAD_SETTINGS, "Failed to load preset: {}\nError: {}", preset_name, err);
+        });
+
+        (Some(preset_name.clone()), seed_info)
+    } else if let Some(path) = &opt.settings {
+        let settings = System::load_settings(path).unwrap_or_else(|err| {
+            fail!(exit_code: EXIT_BAD_SETTINGS, "Failed to load settings: {}\nError: {}", path.display(), err);
+        });
+
+        (None, SeedInfo { version: VERSION.to_owned(), settings, ..Default::default() })
+    } else if let Some(string) = &opt.settings_string {
+        let settings = settings_string::decode(string).unwrap_or_else(|err| {
+            fail!(exit_code: EXIT_BAD_SETTINGS, "Failed to decode settings string: {}", err);
+        });
+
+        (None, SeedInfo { version: VERSION.to_owned(), settings, ..Default::default() })
+    } else if !cli::is_interactive() || !opt.settings_opt.is_empty() {
+        (None, SeedInfo { version: VERSION.to_owned(), ..Default::default() })
     } else {
-        let (seeded, seed): (bool, u32) =
-            if let Some(seed) = opt_seed { (true, seed) } else { (false, rand::random()) };
-
-        (
-            None,
-            seeded,
-            SeedInfo {
-                seed,
-                version: VERSION.to_owned(),
-                settings: cli::get_seed_settings().unwrap_or_else(|err| {
-                    fail!("Failed to create Settings: {}", err);
-                }),
-                ..Default::default()
-            },
-        )
+        let settings = cli::get_seed_settings().unwrap_or_else(|err| {
+            fail!(exit_code: EXIT_BAD_SETTINGS, "Failed to create Settings: {}", err);
+        });
+
+        (None, SeedInfo { version: VERSION.to_owned(), settings, ..Default::default() })
+    };
+
+    opt.settings_opt.apply(&mut seed_info.settings);
+
+    let mut seeded = false;
+    if let Some(seed) = opt.seed {
+        if seed_info.seed != 0 {
+            println!();
+            warn!("Two seed numbers provided! Defaulting to the command line argument.\n");
+        }
+
+        seed_info.seed = seed;
+        seeded = true;
+    } else if seed_info.seed != 0 {
+        seeded = true;
+    } else {
+        seed_info.seed = rand::random();
     }
+
+    (preset_name, seeded, seed_info)
 }
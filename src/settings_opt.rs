@@ -0,0 +1,258 @@
+use modinfo::settings::{
+    cracks::Cracks, cracksanity::Cracksanity, dungeon_entrances::DungeonEntrances, dungeon_items::DungeonItems,
+    item_pool::ItemPool, junk_items::JunkItem, keysy::Keysy, logic::LogicMode, nice_items::NiceItems,
+    pedestal::PedestalSetting, ravios_shop::RaviosShop, starting_items::StartingItem, trials_door::TrialsDoor,
+    weather_vanes::WeatherVanes, Settings,
+};
+use serde::de::DeserializeOwned;
+use structopt::StructOpt;
+
+/// Command line overrides for every [`Settings`] field.
+///
+/// Each option is applied on top of the preset or settings file (if any), so a headless run can fully express a seed's
+/// settings without any interactive prompts. Enum values use the same names as preset files, e.g. `--keysy AllKeysy`.
+#[derive(Debug, StructOpt, Clone, Default)]
+pub struct SettingsOpt {
+    #[structopt(long)]
+    dev_mode: Option<bool>,
+
+    #[structopt(long)]
+    lc_requirement: Option<u8>,
+
+    #[structopt(long)]
+    yuganon_requirement: Option<u8>,
+
+    #[structopt(long, parse(try_from_str = parse_variant))]
+    ped_requirement: Option<PedestalSetting>,
+
+    #[structopt(long, parse(try_from_str = parse_variant))]
+    logic_mode: Option<LogicMode>,
+
+    #[structopt(long)]
+    dark_rooms_lampless: Option<bool>,
+
+    #[structopt(long)]
+    dungeon_prize_shuffle: Option<bool>,
+
+    #[structopt(long)]
+    maiamai_limit: Option<usize>,
+
+    #[structopt(long)]
+    maiamai_madness: Option<bool>,
+
+    #[structopt(long, parse(try_from_str = parse_variant))]
+    nice_items: Option<NiceItems>,
+
+    #[structopt(long)]
+    super_items: Option<bool>,
+
+    #[structopt(long, parse(try_from_str = parse_variant))]
+    item_pool: Option<ItemPool>,
+
+    #[structopt(long)]
+    lamp_and_net_as_weapons: Option<bool>,
+
+    #[structopt(long, parse(try_from_str = parse_variant))]
+    cracks: Option<Cracks>,
+
+    #[structopt(long, parse(try_from_str = parse_variant))]
+    cracksanity: Option<Cracksanity>,
+
+    #[structopt(long, parse(try_from_str = parse_variant))]
+    dungeon_entrances: Option<DungeonEntrances>,
+
+    #[structopt(long, parse(try_from_str = parse_variant))]
+    weather_vanes: Option<WeatherVanes>,
+
+    #[structopt(long, parse(try_from_str = parse_variant))]
+    ravios_shop: Option<RaviosShop>,
+
+    #[structopt(long)]
+    bow_of_light_in_castle: Option<bool>,
+
+    #[structopt(long)]
+    no_progression_enemies: Option<bool>,
+
+    #[structopt(long, parse(try_from_str = parse_variant))]
+    keysy: Option<Keysy>,
+
+    #[structopt(long, parse(try_from_str = parse_variant))]
+    small_key_shuffle: Option<DungeonItems>,
+
+    #[structopt(long, parse(try_from_str = parse_variant))]
+    big_key_shuffle: Option<DungeonItems>,
+
+    #[structopt(long)]
+    key_rings: Option<bool>,
+
+    #[structopt(long)]
+    progressive_bow_of_light: Option<bool>,
+
+    #[structopt(long)]
+    swordless_mode: Option<bool>,
+
+    #[structopt(long)]
+    start_with_merge: Option<bool>,
+
+    #[structopt(long)]
+    start_with_pouch: Option<bool>,
+
+    #[structopt(long)]
+    bell_in_shop: Option<bool>,
+
+    #[structopt(long)]
+    sword_in_shop: Option<bool>,
+
+    #[structopt(long)]
+    boots_in_shop: Option<bool>,
+
+    #[structopt(long)]
+    assured_weapon: Option<bool>,
+
+    #[structopt(long)]
+    chest_size_matches_contents: Option<bool>,
+
+    #[structopt(long)]
+    minigames_excluded: Option<bool>,
+
+    #[structopt(long)]
+    skip_big_bomb_flower: Option<bool>,
+
+    #[structopt(long, parse(try_from_str = parse_variant))]
+    trials_door: Option<TrialsDoor>,
+
+    #[structopt(long)]
+    treacherous_tower_floors: Option<usize>,
+
+    #[structopt(long)]
+    purple_potion_bottles: Option<bool>,
+
+    #[structopt(long)]
+    night_mode: Option<bool>,
+
+    /// Removes all user exclusions inherited from the preset or settings file before adding any `--exclude` checks.
+    #[structopt(long)]
+    clear_exclusions: bool,
+
+    /// Excludes the named check from having progression. May be repeated.
+    #[structopt(long = "exclude", number_of_values = 1)]
+    user_exclusions: Vec<String>,
+
+    /// Guarantees the named check, or every check in the named region, will have progression. May be repeated.
+    #[structopt(long = "prioritize", number_of_values = 1)]
+    user_priorities: Vec<String>,
+
+    /// Starts the game with one more copy of the given item, e.g. `--start-with HeartContainer`. May be repeated.
+    #[structopt(long = "start-with", number_of_values = 1, parse(try_from_str = parse_variant))]
+    starting_items: Vec<StartingItem>,
+
+    /// Includes the named trick in logic, on top of those of the logic mode, e.g. `--trick enemy_clips`. May be repeated.
+    #[structopt(long = "trick", number_of_values = 1)]
+    enabled_tricks: Vec<String>,
+
+    /// Draws the given junk item with a relative weight, e.g. `--junk-weight RupeeGreen=3`. May be repeated.
+    #[structopt(long = "junk-weight", number_of_values = 1, parse(try_from_str = parse_junk_weight))]
+    junk_weights: Vec<(JunkItem, usize)>,
+}
+
+/// Invokes the given macro with the name of every optional field in [`SettingsOpt`].
+macro_rules! with_fields {
+    ($m:ident) => {
+        $m!(
+            dev_mode,
+            lc_requirement,
+            yuganon_requirement,
+            ped_requirement,
+            logic_mode,
+            dark_rooms_lampless,
+            dungeon_prize_shuffle,
+            maiamai_limit,
+            maiamai_madness,
+            nice_items,
+            super_items,
+            item_pool,
+            lamp_and_net_as_weapons,
+            cracks,
+            cracksanity,
+            dungeon_entrances,
+            weather_vanes,
+            ravios_shop,
+            bow_of_light_in_castle,
+            no_progression_enemies,
+            keysy,
+            small_key_shuffle,
+            big_key_shuffle,
+            key_rings,
+            progressive_bow_of_light,
+            swordless_mode,
+            start_with_merge,
+            start_with_pouch,
+            bell_in_shop,
+            sword_in_shop,
+            boots_in_shop,
+            assured_weapon,
+            chest_size_matches_contents,
+            minigames_excluded,
+            skip_big_bomb_flower,
+            trials_door,
+            treacherous_tower_floors,
+            purple_potion_bottles,
+            night_mode
+        )
+    };
+}
+
+impl SettingsOpt {
+    /// Whether any setting has been provided on the command line.
+    pub fn is_empty(&self) -> bool {
+        macro_rules! none {
+            ($($field:ident),+) => {
+                $(self.$field.is_none())&&+
+            };
+        }
+
+        with_fields!(none)
+            && !self.clear_exclusions
+            && self.user_exclusions.is_empty()
+            && self.user_priorities.is_empty()
+            && self.starting_items.is_empty()
+            && self.enabled_tricks.is_empty()
+            && self.junk_weights.is_empty()
+    }
+
+    /// Overwrites each field of `settings` that was provided on the command line.
+    pub fn apply(&self, settings: &mut Settings) {
+        macro_rules! apply {
+            ($($field:ident),+) => {
+                $(if let Some(value) = self.$field {
+                    settings.$field = value;
+                })+
+            };
+        }
+
+        with_fields!(apply);
+
+        if self.clear_exclusions {
+            settings.user_exclusions.clear();
+        }
+        settings.user_exclusions.extend(self.user_exclusions.iter().cloned());
+        settings.user_priorities.extend(self.user_priorities.iter().cloned());
+        for &item in &self.starting_items {
+            let count = settings.starting_items.entry(item).or_default();
+            *count = count.saturating_add(1);
+        }
+        settings.enabled_tricks.extend(self.enabled_tricks.iter().cloned());
+        settings.junk_weights.extend(self.junk_weights.iter().copied());
+    }
+}
+
+/// Parses a setting's enum variant by the same name used in preset files.
+fn parse_variant<T: DeserializeOwned>(value: &str) -> Result<T, String> {
+    serde_json::from_value(serde_json::Value::String(value.to_owned())).map_err(|err| err.to_string())
+}
+
+/// Parses a `JunkItem=weight` pair.
+fn parse_junk_weight(value: &str) -> Result<(JunkItem, usize), String> {
+    let (item, weight) = value.split_once('=').ok_or_else(|| format!("Expected JunkItem=weight, got \"{}\"", value))?;
+    Ok((parse_variant(item)?, weight.parse().map_err(|err| format!("Invalid junk weight \"{}\": {}", weight, err))?))
+}